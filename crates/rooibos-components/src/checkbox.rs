@@ -0,0 +1,206 @@
+use std::marker::PhantomData;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Widget;
+use rooibos_dom::events::KeyEventProps;
+use rooibos_dom::widgets::{Role, WidgetRole};
+use rooibos_dom::{KeyCode, MeasureNode};
+use rooibos_reactive::dom::div::taffy;
+use rooibos_reactive::dom::layout::checked;
+use rooibos_reactive::dom::{LayoutProps, Render, UpdateLayoutProps};
+use rooibos_reactive::graph::IntoReactiveValue;
+use rooibos_reactive::graph::owner::StoredValue;
+use rooibos_reactive::graph::signal::RwSignal;
+use rooibos_reactive::graph::traits::{Get, GetUntracked, Set, UpdateValue};
+use rooibos_reactive::graph::wrappers::read::Signal;
+use rooibos_reactive::{StateProp, use_state_prop, wgt};
+use rooibos_theme::Style;
+
+use crate::ColorThemeColorTheme;
+
+pub(crate) trait CheckableRole: 'static {
+    const ROLE: Role;
+}
+
+pub(crate) struct CheckBoxRole;
+
+impl CheckableRole for CheckBoxRole {
+    const ROLE: Role = Role::CheckBox;
+}
+
+/// A line of text with a checked state, used to render checkboxes, radio buttons and toggles.
+pub(crate) struct CheckableWidget<R> {
+    line: Line<'static>,
+    _role: PhantomData<R>,
+}
+
+impl<R> CheckableWidget<R> {
+    pub(crate) fn new(line: Line<'static>) -> Self {
+        Self {
+            line,
+            _role: PhantomData,
+        }
+    }
+}
+
+impl<R> WidgetRole for CheckableWidget<R>
+where
+    R: CheckableRole,
+{
+    fn widget_role() -> Option<Role> {
+        Some(R::ROLE)
+    }
+}
+
+impl<R> Widget for &CheckableWidget<R> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self.line).render(area, buf);
+    }
+}
+
+impl<R> MeasureNode for CheckableWidget<R> {
+    fn measure(
+        &self,
+        known_dimensions: taffy::Size<Option<f32>>,
+        available_space: taffy::Size<taffy::AvailableSpace>,
+        style: &taffy::Style,
+    ) -> taffy::Size<f32> {
+        self.line.measure(known_dimensions, available_space, style)
+    }
+
+    fn estimate_size(&self) -> taffy::Size<f32> {
+        self.line.estimate_size()
+    }
+}
+
+/// Style shared by checkable components.
+pub(crate) fn default_checkable_style() -> StateProp<Style> {
+    StateProp::new(Style::default().fg_text_primary())
+        .focused(|s: Style| s.fg_border_focused().get())
+        .hovered(|s: Style| s.underlined())
+        .disabled(|s: Style| s.fg_disabled_light().get())
+}
+
+/// Returns `true` for the keys that should activate a checkable component.
+pub(crate) fn is_activation_key(props: &KeyEventProps) -> bool {
+    props.event.modifiers.is_empty()
+        && matches!(props.event.code, KeyCode::Enter | KeyCode::Char(' '))
+}
+
+pub struct Checkbox {
+    checked: Option<RwSignal<bool>>,
+    on_change: Box<dyn FnMut(bool)>,
+    style: StateProp<Style>,
+    checked_symbol: Signal<&'static str>,
+    unchecked_symbol: Signal<&'static str>,
+    layout_props: LayoutProps,
+}
+
+impl Default for Checkbox {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UpdateLayoutProps for Checkbox {
+    fn layout_props(&self) -> LayoutProps {
+        self.layout_props.clone()
+    }
+
+    fn update_props(mut self, props: LayoutProps) -> Self {
+        self.layout_props = props;
+        self
+    }
+}
+
+impl Checkbox {
+    pub fn new() -> Self {
+        Self {
+            checked: None,
+            on_change: Box::new(|_| {}),
+            style: default_checkable_style(),
+            checked_symbol: "[x]".into(),
+            unchecked_symbol: "[ ]".into(),
+            layout_props: LayoutProps::default(),
+        }
+    }
+
+    /// Signal holding the checked state. This is updated when the checkbox is toggled and can be
+    /// set to change the state programmatically.
+    pub fn checked(mut self, checked: RwSignal<bool>) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
+    pub fn on_change(mut self, on_change: impl FnMut(bool) + 'static) -> Self {
+        self.on_change = Box::new(on_change);
+        self
+    }
+
+    pub fn style(mut self, style: StateProp<Style>) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn checked_symbol<M>(
+        mut self,
+        checked_symbol: impl IntoReactiveValue<Signal<&'static str>, M>,
+    ) -> Self {
+        self.checked_symbol = checked_symbol.into_reactive_value();
+        self
+    }
+
+    pub fn unchecked_symbol<M>(
+        mut self,
+        unchecked_symbol: impl IntoReactiveValue<Signal<&'static str>, M>,
+    ) -> Self {
+        self.unchecked_symbol = unchecked_symbol.into_reactive_value();
+        self
+    }
+
+    pub fn render<M>(self, label: impl IntoReactiveValue<Signal<Line<'static>>, M>) -> impl Render {
+        let Self {
+            checked: is_checked,
+            on_change,
+            style,
+            checked_symbol,
+            unchecked_symbol,
+            layout_props,
+        } = self;
+        let label: Signal<Line<'static>> = label.into_reactive_value();
+        let is_checked = is_checked.unwrap_or_else(|| RwSignal::new(false));
+        let (style, set_style_state) = use_state_prop(style);
+
+        let on_change = StoredValue::new_local(on_change);
+
+        let toggle = move || {
+            let value = !is_checked.get_untracked();
+            is_checked.set(value);
+            on_change.update_value(|on_change| on_change(value));
+        };
+
+        let content = move || {
+            let symbol = if is_checked.get() {
+                checked_symbol.get()
+            } else {
+                unchecked_symbol.get()
+            };
+            let mut line = label.get();
+            line.spans.insert(0, Span::raw(format!("{symbol} ")));
+            CheckableWidget::<CheckBoxRole>::new(line.patch_style(style.get()))
+        };
+
+        wgt!(style(checked(is_checked)), content())
+            .layout_props(layout_props)
+            .on_state_change(set_style_state)
+            .on_click(move |_| toggle())
+            .on_key_down(move |props: KeyEventProps| {
+                if is_activation_key(&props) {
+                    props.handle.stop_propagation();
+                    toggle();
+                }
+            })
+    }
+}
@@ -0,0 +1,526 @@
+use std::future::Future;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+
+use ratatui::text::{Line, Text};
+use rooibos_dom::events::{ClickEventProps, KeyEventProps};
+use rooibos_dom::{
+    BorderType, DomNodeKey, KeyCode, focused_key, restore_focus, text, try_focus_first_child,
+};
+use rooibos_reactive::dom::layout::{
+    Borders, Dimension, IntoDimensionSignal, align_items, auto, borders, center, clear, contain,
+    focusable, full, gap, height, justify_content, max_height, max_width, padding_x, width,
+    z_index,
+};
+use rooibos_reactive::dom::{ChildrenFn, IntoChildrenFn, NodeId, Render, after_render};
+use rooibos_reactive::graph::IntoReactiveValue;
+use rooibos_reactive::graph::owner::{provide_context, use_context};
+use rooibos_reactive::graph::signal::RwSignal;
+use rooibos_reactive::graph::traits::{Get, Update, UpdateUntracked, With, WithUntracked};
+use rooibos_reactive::graph::wrappers::read::Signal;
+use rooibos_reactive::{IntoSignal, col, focus_scope, for_each, row, wgt};
+use tokio::sync::oneshot;
+
+use crate::{BorderPropertiesBorderTypeExt, Button, ColorThemeColorTheme};
+
+/// Dialogs are mounted as their own roots starting at this z-index so they're drawn on top of
+/// the rest of the app.
+pub const DIALOG_Z_INDEX: i32 = 100;
+
+static DIALOG_ID: AtomicU32 = AtomicU32::new(1);
+
+type OnCloseFn = dyn Fn() + Send + Sync;
+
+#[derive(Clone)]
+struct OpenDialog {
+    id: u32,
+    z_index: i32,
+    dialog: Dialog,
+    restore_focus: Option<DomNodeKey>,
+}
+
+#[derive(Clone, Copy)]
+struct DialogContext {
+    dialogs: RwSignal<Vec<OpenDialog>>,
+}
+
+impl DialogContext {
+    fn new() -> Self {
+        Self {
+            dialogs: RwSignal::new(Vec::new()),
+        }
+    }
+
+    fn open(&self, id: u32, dialog: Dialog) {
+        if self.is_open_untracked(id) {
+            return;
+        }
+        self.dialogs.update(|dialogs| {
+            let z_index = DIALOG_Z_INDEX + dialogs.len() as i32;
+            dialogs.push(OpenDialog {
+                id,
+                z_index,
+                dialog,
+                restore_focus: None,
+            });
+        });
+        let dialogs = self.dialogs;
+        // Event handlers may be called while the DOM is borrowed, so we need to wait until after
+        // the next render to move the focus
+        after_render(move || {
+            let focused = focused_key();
+            let mut is_open = false;
+            dialogs.update_untracked(|dialogs| {
+                if let Some(dialog) = dialogs.iter_mut().find(|d| d.id == id) {
+                    dialog.restore_focus = focused;
+                    is_open = true;
+                }
+            });
+            if is_open {
+                let _ = try_focus_first_child(dialog_node_id(id));
+            }
+        });
+    }
+
+    fn close(&self, id: u32) {
+        if !self.is_open_untracked(id) {
+            return;
+        }
+        let mut closed = None;
+        self.dialogs.update(|dialogs| {
+            let pos = dialogs.iter().position(|d| d.id == id).unwrap();
+            let removed = dialogs.remove(pos);
+            if let Some(above) = dialogs.get_mut(pos) {
+                // The dialog above this one was opened while focus was inside of this one,
+                // so it needs to send focus back to wherever this one would have
+                above.restore_focus = removed.restore_focus;
+                closed = Some((removed, false));
+            } else {
+                closed = Some((removed, true));
+            }
+        });
+        let Some((closed, was_top)) = closed else {
+            return;
+        };
+        if was_top {
+            let key = closed.restore_focus;
+            after_render(move || restore_focus(key));
+        }
+        if let Some(on_close) = closed.dialog.on_close {
+            on_close();
+        }
+    }
+
+    fn close_top(&self) {
+        let top = self.dialogs.with_untracked(|d| d.last().map(|d| d.id));
+        if let Some(top) = top {
+            self.close(top);
+        }
+    }
+
+    fn is_open_untracked(&self, id: u32) -> bool {
+        self.dialogs
+            .with_untracked(|d| d.iter().any(|d| d.id == id))
+    }
+
+    fn is_open(&self, id: u32) -> bool {
+        self.dialogs.with(|d| d.iter().any(|d| d.id == id))
+    }
+}
+
+fn use_dialog_context() -> DialogContext {
+    use_context::<DialogContext>().expect(
+        "dialog context not found. Make sure Dialogs::new() is called before creating any dialogs \
+         and that Dialogs is rendered somewhere in the app.",
+    )
+}
+
+fn dialog_node_id(id: u32) -> NodeId {
+    NodeId::new(format!("__rooibos_dialog_{id}"))
+}
+
+/// Handle used to open and close a dialog.
+/// Dialogs are displayed by [`Dialogs`], which must be rendered somewhere in the app.
+#[derive(Clone, Copy)]
+pub struct DialogRef {
+    id: u32,
+    context: DialogContext,
+}
+
+impl DialogRef {
+    /// Opens the dialog on top of any dialogs that are already open. Focus is moved to the first
+    /// focusable node inside of the dialog and will be restored when the dialog is closed.
+    pub fn open(&self, dialog: Dialog) {
+        self.context.open(self.id, dialog);
+    }
+
+    pub fn close(&self) {
+        self.context.close(self.id);
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.context.is_open(self.id)
+    }
+}
+
+#[derive(Clone)]
+pub struct Dialog {
+    content: ChildrenFn,
+    title: Option<Signal<Line<'static>>>,
+    width: Signal<Dimension>,
+    max_width: Signal<Dimension>,
+    max_height: Signal<Dimension>,
+    close_on_escape: bool,
+    close_on_backdrop_click: bool,
+    on_close: Option<Arc<OnCloseFn>>,
+}
+
+impl Dialog {
+    pub fn new(content: impl IntoChildrenFn) -> Self {
+        Self {
+            content: content.into_children_fn(),
+            title: None,
+            width: auto().into_dimension_signal(),
+            max_width: full().into_dimension_signal(),
+            max_height: full().into_dimension_signal(),
+            close_on_escape: true,
+            close_on_backdrop_click: true,
+            on_close: None,
+        }
+    }
+
+    pub fn get_ref() -> DialogRef {
+        DialogManager::new().create_ref()
+    }
+
+    pub fn title<M>(mut self, title: impl IntoReactiveValue<Signal<Line<'static>>, M>) -> Self {
+        self.title = Some(title.into_reactive_value());
+        self
+    }
+
+    pub fn width(mut self, width: impl IntoDimensionSignal) -> Self {
+        self.width = width.into_dimension_signal();
+        self
+    }
+
+    pub fn max_width(mut self, max_width: impl IntoDimensionSignal) -> Self {
+        self.max_width = max_width.into_dimension_signal();
+        self
+    }
+
+    pub fn max_height(mut self, max_height: impl IntoDimensionSignal) -> Self {
+        self.max_height = max_height.into_dimension_signal();
+        self
+    }
+
+    pub fn close_on_escape(mut self, close_on_escape: bool) -> Self {
+        self.close_on_escape = close_on_escape;
+        self
+    }
+
+    pub fn close_on_backdrop_click(mut self, close_on_backdrop_click: bool) -> Self {
+        self.close_on_backdrop_click = close_on_backdrop_click;
+        self
+    }
+
+    /// Called whenever the dialog is closed, including when it's dismissed with the escape key or
+    /// by clicking outside of it.
+    pub fn on_close(mut self, on_close: impl Fn() + Send + Sync + 'static) -> Self {
+        self.on_close = Some(Arc::new(on_close));
+        self
+    }
+}
+
+/// Renders the stack of open dialogs.
+/// This should be placed near the root of the app so the dialog context is available everywhere.
+pub struct Dialogs {
+    context: DialogContext,
+}
+
+impl Default for Dialogs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Dialogs {
+    /// Creates the dialog host and provides the dialog context to the rest of the component tree.
+    /// This must be called before creating a [`DialogManager`] or [`DialogRef`].
+    pub fn new() -> Self {
+        let context = DialogContext::new();
+        provide_context(context);
+        Self { context }
+    }
+
+    pub fn render(self) -> impl Render {
+        let Self { context } = self;
+
+        col![for_each(
+            move || context.dialogs.get(),
+            |d| d.id,
+            move |d| render_dialog(context, d)
+        )]
+    }
+}
+
+fn render_dialog(context: DialogContext, open_dialog: OpenDialog) -> impl Render {
+    let OpenDialog {
+        id,
+        z_index: dialog_z_index,
+        dialog,
+        ..
+    } = open_dialog;
+    let Dialog {
+        content,
+        title,
+        width: dialog_width,
+        max_width: dialog_max_width,
+        max_height: dialog_max_height,
+        close_on_escape,
+        close_on_backdrop_click,
+        ..
+    } = dialog;
+
+    let border = (move || {
+        let borders = Borders::all()
+            .border_type(BorderType::primary().get())
+            .fg_border_focused()
+            .get();
+        match title {
+            Some(title) => borders.title(title.get()),
+            None => borders,
+        }
+    })
+    .signal();
+
+    col![
+        style(
+            z_index(dialog_z_index),
+            width(full()),
+            height(full()),
+            align_items(center()),
+            justify_content(center()),
+            focusable(false),
+        ),
+        col![
+            style(
+                width(dialog_width),
+                max_width(dialog_max_width),
+                max_height(dialog_max_height),
+                padding_x(1),
+                clear(true),
+                borders(border),
+                focusable(false),
+            ),
+            focus_scope!(style(contain(true)), content())
+        ]
+        .id(dialog_node_id(id))
+        // Clicks inside of the dialog shouldn't reach the backdrop
+        .on_click(|props: ClickEventProps| props.handle.stop_propagation())
+    ]
+    .on_click(move |props: ClickEventProps| {
+        if close_on_backdrop_click && props.data.is_direct {
+            context.close(id);
+        }
+    })
+    .on_key_down(move |props: KeyEventProps| {
+        if close_on_escape && props.event.code == KeyCode::Esc {
+            props.handle.stop_propagation();
+            context.close(id);
+        }
+    })
+}
+
+/// Ensures the future is resolved exactly once, no matter how the dialog was closed.
+struct Responder<T>(Arc<Mutex<Option<oneshot::Sender<T>>>>);
+
+impl<T> Clone for Responder<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> Responder<T> {
+    fn new() -> (Self, oneshot::Receiver<T>) {
+        let (tx, rx) = oneshot::channel();
+        (Self(Arc::new(Mutex::new(Some(tx)))), rx)
+    }
+
+    fn respond(&self, value: T) {
+        if let Some(tx) = self.0.lock().unwrap().take() {
+            let _ = tx.send(value);
+        }
+    }
+}
+
+/// Opens dialogs from event handlers and async tasks.
+/// This needs to be created while rendering the component so it can find the dialog context.
+#[derive(Clone, Copy)]
+pub struct DialogManager {
+    context: DialogContext,
+}
+
+impl Default for DialogManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DialogManager {
+    pub fn new() -> Self {
+        Self {
+            context: use_dialog_context(),
+        }
+    }
+
+    pub fn create_ref(&self) -> DialogRef {
+        DialogRef {
+            id: DIALOG_ID.fetch_add(1, Ordering::SeqCst),
+            context: self.context,
+        }
+    }
+
+    pub fn open(&self, dialog: Dialog) -> DialogRef {
+        let dialog_ref = self.create_ref();
+        dialog_ref.open(dialog);
+        dialog_ref
+    }
+
+    /// Closes the dialog that's currently on top of the stack, if there is one.
+    pub fn close_top(&self) {
+        self.context.close_top();
+    }
+
+    /// Displays a message with an "OK" button. The future resolves once the dialog is closed.
+    pub fn alert(
+        self,
+        title: impl Into<Line<'static>>,
+        message: impl Into<Text<'static>>,
+    ) -> impl Future<Output = ()> {
+        let message = message.into();
+        let dialog_ref = self.create_ref();
+        let (responder, rx) = Responder::new();
+
+        dialog_ref.open(
+            Dialog::new(move || {
+                col![
+                    wgt!(message.clone()),
+                    row![
+                        style(justify_content(center())),
+                        Button::new()
+                            .on_click(move || dialog_ref.close())
+                            .render(text!("OK"))
+                    ]
+                ]
+            })
+            .title(title.into())
+            .on_close(move || responder.respond(())),
+        );
+
+        async move {
+            let _ = rx.await;
+        }
+    }
+
+    /// Asks the user to confirm an action. Resolves to `false` if the dialog is dismissed.
+    pub fn confirm(
+        self,
+        title: impl Into<Line<'static>>,
+        message: impl Into<Text<'static>>,
+    ) -> impl Future<Output = bool> {
+        let message = message.into();
+        let dialog_ref = self.create_ref();
+        let (responder, rx) = Responder::new();
+
+        dialog_ref.open(
+            Dialog::new({
+                let responder = responder.clone();
+                move || {
+                    let confirm = responder.clone();
+                    let cancel = responder.clone();
+                    col![
+                        wgt!(message.clone()),
+                        row![
+                            style(justify_content(center()), gap(2)),
+                            Button::new()
+                                .on_click(move || {
+                                    confirm.respond(true);
+                                    dialog_ref.close();
+                                })
+                                .render(text!("OK")),
+                            Button::new()
+                                .on_click(move || {
+                                    cancel.respond(false);
+                                    dialog_ref.close();
+                                })
+                                .render(text!("Cancel"))
+                        ]
+                    ]
+                }
+            })
+            .title(title.into())
+            .on_close(move || responder.respond(false)),
+        );
+
+        async move { rx.await.unwrap_or(false) }
+    }
+
+    /// Asks the user to enter a value. Resolves to `None` if the dialog is dismissed.
+    #[cfg(feature = "input")]
+    pub fn prompt(
+        self,
+        title: impl Into<Line<'static>>,
+        message: impl Into<Text<'static>>,
+        initial_value: impl Into<String>,
+    ) -> impl Future<Output = Option<String>> {
+        use rooibos_reactive::graph::traits::GetUntracked;
+
+        use crate::Input;
+
+        let message = message.into();
+        let initial_value = initial_value.into();
+        let dialog_ref = self.create_ref();
+        let (responder, rx) = Responder::new();
+
+        dialog_ref.open(
+            Dialog::new({
+                let responder = responder.clone();
+                move || {
+                    let input_ref = Input::get_ref();
+                    let submit = responder.clone();
+                    let confirm = responder.clone();
+                    let cancel = responder.clone();
+                    col![
+                        wgt!(message.clone()),
+                        Input::default()
+                            .initial_value(initial_value.clone())
+                            .on_submit(move |value| {
+                                submit.respond(Some(value));
+                                dialog_ref.close();
+                            })
+                            .render(input_ref),
+                        row![
+                            style(justify_content(center()), gap(2)),
+                            Button::new()
+                                .on_click(move || {
+                                    confirm.respond(Some(input_ref.text().get_untracked()));
+                                    dialog_ref.close();
+                                })
+                                .render(text!("OK")),
+                            Button::new()
+                                .on_click(move || {
+                                    cancel.respond(None);
+                                    dialog_ref.close();
+                                })
+                                .render(text!("Cancel"))
+                        ]
+                    ]
+                }
+            })
+            .title(title.into())
+            .on_close(move || responder.respond(None)),
+        );
+
+        async move { rx.await.unwrap_or(None) }
+    }
+}
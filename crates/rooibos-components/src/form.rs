@@ -0,0 +1,424 @@
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+
+use ratatui::text::Line;
+use rooibos_reactive::dom::layout::show;
+use rooibos_reactive::dom::{NodeId, Render, UpdateLayoutProps, try_focus_id};
+use rooibos_reactive::error::ErrorId;
+use rooibos_reactive::graph::IntoReactiveValue;
+use rooibos_reactive::graph::effect::Effect;
+use rooibos_reactive::graph::owner::{StoredValue, on_cleanup};
+use rooibos_reactive::graph::signal::{ArcRwSignal, RwSignal};
+use rooibos_reactive::graph::traits::{
+    Get, GetUntracked, GetValue, IsDisposed, Set, SetValue, Update, With, WithUntracked, WithValue,
+};
+use rooibos_reactive::graph::wrappers::read::Signal;
+use rooibos_reactive::{Errors, col, wgt};
+use rooibos_theme::Style;
+use wasm_compat::futures::spawn_local;
+
+use crate::{Input, InputRef};
+
+type SyncValidatorFn = dyn Fn(&str) -> Result<(), String> + Send + Sync;
+
+type AsyncValidatorFn =
+    dyn Fn(String) -> Pin<Box<dyn Future<Output = Result<(), String>>>> + Send + Sync;
+
+type OnSubmitFn = dyn Fn(FormValues) + Send + Sync;
+
+static FIELD_KEY: AtomicUsize = AtomicUsize::new(1);
+
+#[derive(Clone)]
+enum Validator {
+    Sync(Arc<SyncValidatorFn>),
+    Async(Arc<AsyncValidatorFn>),
+}
+
+/// A validation failure for a single form field.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+impl std::error::Error for FieldError {}
+
+/// The current value of each field in a form, in the order the fields were rendered.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FormValues(Vec<(String, String)>);
+
+impl FormValues {
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+}
+
+#[derive(Clone)]
+struct FieldState {
+    name: String,
+    // Unique key used to track the field's error in the form's [`Errors`]
+    key: usize,
+    id: NodeId,
+    input_ref: InputRef,
+    error: RwSignal<Option<String>>,
+    validators: Vec<Validator>,
+    // Incremented on each validation run so results from outdated async validators are ignored
+    generation: Arc<AtomicU32>,
+}
+
+impl FieldState {
+    async fn validate(&self) -> Result<(), String> {
+        let value = self.input_ref.text().get_untracked();
+        for validator in &self.validators {
+            match validator {
+                Validator::Sync(f) => f(&value)?,
+                Validator::Async(f) => f(value.clone()).await?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Tracks a set of named [`Input`] fields along with their validation state.
+///
+/// Fields are validated when they lose focus and again whenever their value changes after an
+/// error has been shown. Submitting the form validates every field and moves focus to the first
+/// invalid one.
+#[derive(Clone, Copy)]
+pub struct Form {
+    fields: RwSignal<Vec<FieldState>>,
+    on_submit: StoredValue<Option<Arc<OnSubmitFn>>>,
+    errors: StoredValue<ArcRwSignal<Errors>>,
+    submitting: RwSignal<bool>,
+}
+
+impl Default for Form {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Form {
+    pub fn new() -> Self {
+        Self {
+            fields: RwSignal::new(Vec::new()),
+            on_submit: StoredValue::new(None),
+            errors: StoredValue::new(ArcRwSignal::new(Errors::default())),
+            submitting: RwSignal::new(false),
+        }
+    }
+
+    /// Called with the values of each field once a submitted form passes validation.
+    pub fn on_submit(self, on_submit: impl Fn(FormValues) + Send + Sync + 'static) -> Self {
+        self.on_submit.set_value(Some(Arc::new(on_submit)));
+        self
+    }
+
+    /// Creates a new field. Call [`FormField::render`] to add it to the form.
+    pub fn field(&self, name: impl Into<String>) -> FormField {
+        FormField {
+            form: *self,
+            name: name.into(),
+            label: None,
+            validators: Vec::new(),
+            input: Input::default(),
+            error_style: Style::default().red().into(),
+        }
+    }
+
+    /// The current error for the given field, if any.
+    pub fn field_error(&self, name: impl Into<String>) -> Signal<Option<String>> {
+        let form = *self;
+        let name = name.into();
+        Signal::derive(move || form.find_field(&name).and_then(|f| f.error.get()))
+    }
+
+    /// The current value of the given field.
+    pub fn value(&self, name: impl Into<String>) -> Signal<String> {
+        let form = *self;
+        let name = name.into();
+        Signal::derive(move || {
+            form.find_field(&name)
+                .map(|f| f.input_ref.text().get())
+                .unwrap_or_default()
+        })
+    }
+
+    /// The errors of all fields in the form. This can be used with [`error_map`] or
+    /// [`fallback`] to show a summary of every problem.
+    ///
+    /// [`error_map`]: rooibos_reactive::error_map
+    /// [`fallback`]: rooibos_reactive::fallback
+    pub fn errors(&self) -> ArcRwSignal<Errors> {
+        self.errors.get_value()
+    }
+
+    /// Returns `true` while a submitted form is being validated.
+    pub fn is_submitting(&self) -> Signal<bool> {
+        self.submitting.into()
+    }
+
+    pub fn values(&self) -> FormValues {
+        FormValues(self.fields.with_untracked(|fields| {
+            fields
+                .iter()
+                .map(|f| (f.name.clone(), f.input_ref.text().get_untracked()))
+                .collect()
+        }))
+    }
+
+    /// Validates every field and calls the submit handler if they're all valid.
+    pub fn submit(&self) {
+        let form = *self;
+        spawn_local(async move {
+            if let Ok(values) = form.validate_all().await
+                && let Some(on_submit) = form.on_submit.get_value()
+            {
+                on_submit(values);
+            }
+        });
+    }
+
+    /// Validates every field, returning the form values if they're all valid.
+    /// Focus is moved to the first invalid field otherwise.
+    pub async fn validate_all(&self) -> Result<FormValues, Vec<FieldError>> {
+        self.submitting.set(true);
+        let fields = self.fields.get_untracked();
+        let mut errors = Vec::new();
+        let mut first_invalid = None;
+        for field in &fields {
+            if let Err(message) = self.run_validation(field).await {
+                first_invalid.get_or_insert(field.id);
+                errors.push(FieldError {
+                    field: field.name.clone(),
+                    message,
+                });
+            }
+        }
+        self.submitting.set(false);
+
+        match first_invalid {
+            Some(id) => {
+                // The field may have been removed while validation was pending
+                let _ = try_focus_id(id);
+                Err(errors)
+            }
+            None => Ok(self.values()),
+        }
+    }
+
+    /// Clears the errors of every field.
+    pub fn reset_errors(&self) {
+        self.fields.with_untracked(|fields| {
+            for field in fields {
+                field.error.set(None);
+            }
+        });
+        self.errors
+            .with_value(|errors| errors.set(Errors::default()));
+    }
+
+    fn find_field(&self, name: &str) -> Option<FieldState> {
+        self.fields
+            .with(|fields| fields.iter().find(|f| f.name == name).cloned())
+    }
+
+    fn validate_field(&self, name: &str) {
+        let form = *self;
+        let Some(field) = self
+            .fields
+            .with_untracked(|fields| fields.iter().find(|f| f.name == name).cloned())
+        else {
+            return;
+        };
+        spawn_local(async move {
+            let _ = form.run_validation(&field).await;
+        });
+    }
+
+    async fn run_validation(&self, field: &FieldState) -> Result<(), String> {
+        let generation = field.generation.fetch_add(1, Ordering::SeqCst) + 1;
+        let result = field.validate().await;
+        if field.generation.load(Ordering::SeqCst) != generation {
+            // A newer validation run was started while this one was pending
+            return result;
+        }
+        let error = result.clone().err();
+        if field.error.get_untracked() != error {
+            field.error.set(error.clone());
+        }
+
+        self.set_field_error(
+            field.key,
+            error.map(|message| FieldError {
+                field: field.name.clone(),
+                message,
+            }),
+        );
+        result
+    }
+
+    fn set_field_error(&self, key: usize, error: Option<FieldError>) {
+        let key = ErrorId::from(key);
+        self.errors.with_value(|errors| {
+            errors.update(|errors| {
+                errors.remove(&key);
+                if let Some(error) = error {
+                    errors.insert(key, error);
+                }
+            });
+        });
+    }
+}
+
+/// A named [`Input`] that's part of a [`Form`].
+pub struct FormField {
+    form: Form,
+    name: String,
+    label: Option<String>,
+    validators: Vec<Validator>,
+    input: Input,
+    error_style: Signal<Style>,
+}
+
+impl FormField {
+    /// Text shown above the input. The label is associated with the input, so tests can find the
+    /// input by its label.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// The input to render for this field. The input's `on_submit` and `on_direct_blur` handlers
+    /// are replaced by the form.
+    pub fn input(mut self, input: Input) -> Self {
+        self.input = input;
+        self
+    }
+
+    /// Adds a validator that's run whenever the field is validated. Validators run in the order
+    /// they were added and validation stops at the first error.
+    pub fn validate<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.validators.push(Validator::Sync(Arc::new(validator)));
+        self
+    }
+
+    /// Adds a validator that needs to do asynchronous work, like checking a value against a
+    /// remote service.
+    pub fn validate_async<F, Fut>(mut self, validator: F) -> Self
+    where
+        F: Fn(String) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + 'static,
+    {
+        self.validators
+            .push(Validator::Async(Arc::new(move |value| {
+                Box::pin(validator(value))
+            })));
+        self
+    }
+
+    pub fn error_style<M>(mut self, error_style: impl IntoReactiveValue<Signal<Style>, M>) -> Self {
+        self.error_style = error_style.into_reactive_value();
+        self
+    }
+
+    /// Renders the input with its label above it and its error shown beneath it.
+    pub fn render(self) -> impl Render {
+        let Self {
+            form,
+            name,
+            label,
+            validators,
+            input,
+            error_style,
+        } = self;
+        let input_ref = Input::get_ref();
+        let error = RwSignal::new(None::<String>);
+        let id = NodeId::new_auto();
+        let key = FIELD_KEY.fetch_add(1, Ordering::Relaxed);
+
+        form.fields.update(|fields| {
+            fields.retain(|f| f.name != name);
+            fields.push(FieldState {
+                name: name.clone(),
+                key,
+                id,
+                input_ref,
+                error,
+                validators,
+                generation: Arc::new(AtomicU32::new(0)),
+            });
+        });
+        on_cleanup(move || {
+            form.fields
+                .try_update(|fields| fields.retain(|f| f.key != key));
+            if !form.errors.is_disposed() {
+                form.set_field_error(key, None);
+            }
+        });
+
+        Effect::new({
+            let name = name.clone();
+            move |prev: Option<String>| {
+                let text = input_ref.text().get();
+                // Once an error is shown, re-validate on every change so it's cleared as soon as
+                // the value is fixed
+                if prev.is_some_and(|prev| prev != text) && error.get_untracked().is_some() {
+                    form.validate_field(&name);
+                }
+                text
+            }
+        });
+
+        let has_label = label.is_some();
+        col![
+            wgt!(
+                style(show(has_label)),
+                Line::raw(label.clone().unwrap_or_default())
+            )
+            .label_for(id),
+            input
+                .id(id)
+                .on_submit(move |_| form.submit())
+                .on_direct_blur(move |_, _, _| form.validate_field(&name))
+                .render(input_ref),
+            wgt!(
+                style(show(move || error.with(|e| e.is_some()))),
+                Line::styled(error.get().unwrap_or_default(), error_style.get())
+            )
+        ]
+    }
+}
+
+/// Validator that fails if the value is empty or only contains whitespace.
+pub fn required(
+    message: impl Into<String>,
+) -> impl Fn(&str) -> Result<(), String> + Send + Sync + 'static {
+    let message = message.into();
+    move |value| {
+        if value.trim().is_empty() {
+            Err(message.clone())
+        } else {
+            Ok(())
+        }
+    }
+}
@@ -1,9 +1,11 @@
+use std::marker::PhantomData;
+
 use ratatui::Frame;
 use ratatui::layout::{Alignment, Rect};
 use ratatui::widgets::Widget;
 use ratatui_textarea::{CursorMove, TextArea};
 use rooibos_dom::events::{
-    BlurEvent, EventData, EventHandle, FocusEvent, KeyEventProps, StateChangeEvent,
+    BlurEvent, EventData, EventHandle, FocusEvent, KeyEventProps, KeyHandler, StateChangeEvent,
 };
 use rooibos_dom::widgets::{Role, WidgetRole};
 use rooibos_dom::{
@@ -89,6 +91,16 @@ impl InputRef {
             .unwrap()
     }
 
+    /// Replaces the contents of the input and moves the cursor to the end.
+    pub fn set_text(&self, text: impl Into<String>) {
+        let text = text.into();
+        self.text_area.update(|t| {
+            t.move_cursor(CursorMove::End);
+            t.delete_line_by_head();
+            t.insert_str(text);
+        });
+    }
+
     pub fn delete_line(&self) -> bool {
         self.text_area
             .try_update(|t| {
@@ -136,6 +148,7 @@ pub struct Input {
     placeholder_style: Signal<Style>,
     placeholder_text: Signal<String>,
     on_submit: Box<dyn FnMut(String)>,
+    on_key_down: Box<dyn KeyHandler>,
     on_direct_focus: Box<dyn FnMut(FocusEvent, EventData, EventHandle)>,
     on_direct_blur: Box<dyn FnMut(BlurEvent, EventData, EventHandle)>,
     on_state_change: Box<dyn FnMut(StateChangeEvent, EventData, EventHandle)>,
@@ -152,6 +165,7 @@ impl Default for Input {
             placeholder_text: String::new().into(),
             style: Style::default().into(),
             on_submit: Box::new(|_| {}),
+            on_key_down: Box::new(|_| {}),
             on_direct_focus: Box::new(|_, _, _| {}),
             on_direct_blur: Box::new(|_, _, _| {}),
             on_state_change: Box::new(|_, _, _| {}),
@@ -185,6 +199,13 @@ impl Input {
         self
     }
 
+    /// Called before the input handles a key press.
+    /// If the handler stops propagation, the input won't process the key.
+    pub fn on_key_down(mut self, on_key_down: impl KeyHandler + 'static) -> Self {
+        self.on_key_down = Box::new(on_key_down);
+        self
+    }
+
     pub fn on_direct_focus(
         mut self,
         on_focus: impl FnMut(FocusEvent, EventData, EventHandle) + 'static,
@@ -223,6 +244,13 @@ impl Input {
     }
 
     pub fn render(self, input_ref: InputRef) -> impl Render {
+        self.render_with_role::<TextInputRole>(input_ref)
+    }
+
+    pub(crate) fn render_with_role<R>(self, input_ref: InputRef) -> impl Render
+    where
+        R: InputRole,
+    {
         let Self {
             layout_props,
             alignment,
@@ -231,6 +259,7 @@ impl Input {
             style,
             placeholder_text,
             mut on_submit,
+            mut on_key_down,
             mut on_direct_focus,
             mut on_direct_blur,
             on_state_change,
@@ -278,6 +307,10 @@ impl Input {
 
         let key_down = {
             move |mut props: KeyEventProps| {
+                on_key_down.handle(props.clone());
+                if props.handle.get_stop_propagation() {
+                    return;
+                }
                 let has_modifiers = !props.event.modifiers.is_empty();
                 if !has_modifiers {
                     // If the input widget is focused and there's no modifiers, we should consume
@@ -310,7 +343,10 @@ impl Input {
         // TODO: add on_scroll handler
         DomWidget::new(move || {
             text_area.track();
-            RenderInput { text_area }
+            RenderInput::<R> {
+                text_area,
+                _role: PhantomData,
+            }
         })
         .layout_props(layout_props)
         .on_key_down(key_down)
@@ -330,7 +366,7 @@ impl Input {
     }
 }
 
-fn to_input(event: Event) -> Option<ratatui_textarea::Input> {
+pub(crate) fn to_input(event: Event) -> Option<ratatui_textarea::Input> {
     let (key, modifiers) = to_key(event)?;
     Some(ratatui_textarea::Input {
         key,
@@ -379,23 +415,37 @@ fn to_key(event: Event) -> Option<(ratatui_textarea::Key, KeyModifiers)> {
     Some((key_code, key_event.modifiers))
 }
 
-struct RenderInput {
+pub(crate) trait InputRole: 'static {
+    const ROLE: Role;
+}
+
+pub(crate) struct TextInputRole;
+
+impl InputRole for TextInputRole {
+    const ROLE: Role = Role::TextInput;
+}
+
+struct RenderInput<R> {
     text_area: RwSignal<TextArea<'static>>,
+    _role: PhantomData<R>,
 }
 
-impl WidgetRole for RenderInput {
+impl<R> WidgetRole for RenderInput<R>
+where
+    R: InputRole,
+{
     fn widget_role() -> Option<Role> {
-        Some(Role::TextInput)
+        Some(R::ROLE)
     }
 }
 
-impl RenderNode for RenderInput {
+impl<R> RenderNode for RenderInput<R> {
     fn render(&mut self, area: Rect, frame: &mut Frame) {
         self.text_area.with(|t| t.render(area, frame.buffer_mut()));
     }
 }
 
-impl MeasureNode for RenderInput {
+impl<R> MeasureNode for RenderInput<R> {
     fn measure(
         &self,
         known_dimensions: rooibos_reactive::dom::div::taffy::Size<Option<f32>>,
@@ -1,32 +1,64 @@
 mod button;
+mod checkbox;
+mod dialog;
+#[cfg(feature = "input")]
+mod form;
 #[cfg(feature = "image")]
 mod image;
 #[cfg(feature = "input")]
 mod input;
 mod list_view;
+#[cfg(feature = "markdown")]
+mod markdown;
 mod notification;
+mod progress;
+mod radio_group;
+mod select;
 mod show;
 #[cfg(feature = "spinner")]
 pub mod spinner;
 mod tab_view;
+mod table_view;
 #[cfg(all(feature = "terminal-widget", not(target_arch = "wasm32")))]
 mod terminal;
+#[cfg(feature = "input")]
+mod text_editor;
+mod toggle;
+mod tree_view;
+mod virtual_list;
+mod virtual_table;
 mod wrapping_list;
 
 pub use button::*;
+pub use checkbox::*;
+pub use dialog::*;
 pub use either_of;
+#[cfg(feature = "input")]
+pub use form::*;
 #[cfg(feature = "image")]
 pub use image::*;
 #[cfg(feature = "input")]
 pub use input::*;
 pub use list_view::*;
+#[cfg(feature = "markdown")]
+pub use markdown::*;
 pub use notification::*;
+pub use progress::*;
+pub use radio_group::*;
 use rooibos_dom::BorderType;
-use rooibos_theme::{Color, Theme};
+use rooibos_theme::{Color, Style, Theme};
+pub use select::*;
 pub use show::*;
 pub use tab_view::*;
+pub use table_view::*;
 #[cfg(all(feature = "terminal-widget", not(target_arch = "wasm32")))]
 pub use terminal::*;
+#[cfg(feature = "input")]
+pub use text_editor::*;
+pub use toggle::*;
+pub use tree_view::*;
+pub use virtual_list::*;
+pub use virtual_table::*;
 pub use wrapping_list::*;
 
 #[derive(Theme, Clone, Copy, Default, Debug)]
@@ -48,12 +80,37 @@ pub struct BorderProperties {
     pub disabled: BorderType,
 }
 
+#[derive(Theme, Clone, Copy, Default, Debug)]
+pub struct SyntaxTheme {
+    pub keyword: Style,
+    pub string: Style,
+    pub number: Style,
+    pub comment: Style,
+}
+
+#[derive(Theme, Clone, Copy, Default, Debug)]
+pub struct MarkdownTheme {
+    pub heading: Style,
+    pub emphasis: Style,
+    pub strong: Style,
+    pub strikethrough: Style,
+    pub link: Style,
+    pub code: Style,
+    pub block_quote: Style,
+    pub list_marker: Style,
+    pub separator: Style,
+}
+
 #[derive(Theme, Clone, Copy, Default, Debug)]
 pub struct AppTheme {
     #[subtheme]
     pub color_theme: ColorTheme,
     #[subtheme]
     pub border_properties: BorderProperties,
+    #[subtheme]
+    pub syntax_theme: SyntaxTheme,
+    #[subtheme]
+    pub markdown_theme: MarkdownTheme,
 }
 
 pub fn default_theme() -> AppTheme {
@@ -73,5 +130,22 @@ pub fn default_theme() -> AppTheme {
             hovered: BorderType::Double,
             disabled: BorderType::Inner,
         },
+        syntax_theme: SyntaxTheme {
+            keyword: Style::new().magenta().bold(),
+            string: Style::new().green(),
+            number: Style::new().cyan(),
+            comment: Style::new().dark_gray().italic(),
+        },
+        markdown_theme: MarkdownTheme {
+            heading: Style::new().cyan().bold(),
+            emphasis: Style::new().italic(),
+            strong: Style::new().bold(),
+            strikethrough: Style::new().crossed_out(),
+            link: Style::new().blue().underlined(),
+            code: Style::new().yellow(),
+            block_quote: Style::new().gray(),
+            list_marker: Style::new().cyan(),
+            separator: Style::new().dark_gray(),
+        },
     }
 }
@@ -0,0 +1,828 @@
+use std::cell::RefCell;
+use std::ops::Range;
+use std::rc::Rc;
+
+use pulldown_cmark::{Alignment, Event, Options, Parser, Tag, TagEnd};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::Modifier;
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::Widget;
+use rooibos_dom::events::KeyEventProps;
+use rooibos_dom::widgets::{Role, WidgetRole};
+use rooibos_dom::wrap::{self, wrap_line_with_offsets};
+use rooibos_dom::{KeyCode, MeasureNode, RenderNode};
+use rooibos_reactive::dom::div::taffy;
+use rooibos_reactive::dom::layout::{
+    absolute, focusable, margin_left, margin_top, position, width,
+};
+use rooibos_reactive::dom::{DomWidget, LayoutProps, Render, UpdateLayoutProps};
+use rooibos_reactive::graph::IntoReactiveValue;
+use rooibos_reactive::graph::owner::StoredValue;
+use rooibos_reactive::graph::signal::RwSignal;
+use rooibos_reactive::graph::traits::{Get, Set, Update, With, WithUntracked, WithValue};
+use rooibos_reactive::graph::wrappers::read::Signal;
+use rooibos_reactive::{col, for_each, wgt};
+use rooibos_theme::Style;
+
+use crate::MarkdownTheme;
+
+/// Renders a CommonMark document.
+///
+/// Headings, emphasis, lists, block quotes, code blocks and tables are supported. Colors are taken
+/// from the active [`MarkdownTheme`]. Paragraphs are wrapped to the width of the widget.
+///
+/// Each link is its own focusable node, so links can be reached with <kbd>Tab</kbd> and opened
+/// with <kbd>Enter</kbd> or a mouse click. Opening a link calls [`Markdown::on_link_click`].
+pub struct Markdown {
+    style: Signal<Style>,
+    on_link_click: Rc<RefCell<dyn FnMut(String)>>,
+    layout_props: LayoutProps,
+}
+
+impl Default for Markdown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UpdateLayoutProps for Markdown {
+    fn layout_props(&self) -> LayoutProps {
+        self.layout_props.clone()
+    }
+
+    fn update_props(mut self, props: LayoutProps) -> Self {
+        self.layout_props = props;
+        self
+    }
+}
+
+impl Markdown {
+    pub fn new() -> Self {
+        Self {
+            style: Style::default().into(),
+            on_link_click: Rc::new(RefCell::new(|_| {})),
+            layout_props: LayoutProps::default(),
+        }
+    }
+
+    /// Base style applied to the whole widget.
+    pub fn style<M>(mut self, style: impl IntoReactiveValue<Signal<Style>, M>) -> Self {
+        self.style = style.into_reactive_value();
+        self
+    }
+
+    /// Called with the destination URL when a link is opened.
+    pub fn on_link_click(mut self, on_link_click: impl FnMut(String) + 'static) -> Self {
+        self.on_link_click = Rc::new(RefCell::new(on_link_click));
+        self
+    }
+
+    pub fn render<M>(self, source: impl IntoReactiveValue<Signal<String>, M>) -> impl Render {
+        let Self {
+            style,
+            on_link_click,
+            layout_props,
+        } = self;
+        let source: Signal<String> = source.into_reactive_value();
+        let on_link_click = StoredValue::new_local(on_link_click);
+
+        // Visible parts of each link from the last render
+        let links = RwSignal::new(Vec::<LinkArea>::new());
+        let focused_link = RwSignal::new(None::<usize>);
+
+        let link_node = move |area: LinkArea| {
+            let LinkArea {
+                link,
+                url,
+                area,
+                spans,
+                primary,
+            } = area;
+            let open = move || {
+                let url = url.clone();
+                on_link_click.with_value(|on_link_click| on_link_click.borrow_mut()(url));
+            };
+
+            // Links that wrap onto multiple rows are only focusable once, but every part of the
+            // link is highlighted while it has focus.
+            wgt!(
+                style(
+                    position(absolute()),
+                    margin_left(area.x as u32),
+                    margin_top(area.y as u32),
+                    width(area.width as u32),
+                    focusable(primary),
+                ),
+                {
+                    let line = Line::from(spans.clone());
+                    if focused_link.get() == Some(link) {
+                        line.patch_style(Modifier::REVERSED)
+                    } else {
+                        line
+                    }
+                }
+            )
+            .on_click({
+                let open = open.clone();
+                move |_| open()
+            })
+            .on_key_down(move |props: KeyEventProps| {
+                if props.event.code == KeyCode::Enter && props.event.modifiers.is_empty() {
+                    props.handle.stop_propagation();
+                    open();
+                }
+            })
+            .on_focus(move |_, _, _| focused_link.set(Some(link)))
+            .on_blur(move |_, _, _| {
+                focused_link.update(|focused| {
+                    if *focused == Some(link) {
+                        *focused = None;
+                    }
+                });
+            })
+        };
+
+        col![
+            style(layout_props),
+            DomWidget::new(move || {
+                let theme = MarkdownTheme {
+                    heading: MarkdownTheme::heading().get(),
+                    emphasis: MarkdownTheme::emphasis().get(),
+                    strong: MarkdownTheme::strong().get(),
+                    strikethrough: MarkdownTheme::strikethrough().get(),
+                    link: MarkdownTheme::link().get(),
+                    code: MarkdownTheme::code().get(),
+                    block_quote: MarkdownTheme::block_quote().get(),
+                    list_marker: MarkdownTheme::list_marker().get(),
+                    separator: MarkdownTheme::separator().get(),
+                };
+                RenderMarkdown {
+                    document: source.with(|source| parse(source, &theme)),
+                    separator_style: theme.separator,
+                    style: style.get(),
+                    links,
+                }
+            }),
+            for_each(move || links.get(), |area| area.clone(), link_node)
+        ]
+    }
+}
+
+/// Converts a CommonMark document into styled [`Text`] wrapped to `width` columns.
+pub fn markdown_to_text(source: &str, width: usize, theme: &MarkdownTheme) -> Text<'static> {
+    layout(&parse(source, theme).lines, width, theme.separator)
+        .into_iter()
+        .map(|row| row.line)
+        .collect()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum LineKind {
+    /// Regular text that gets wrapped to the available width
+    Text,
+    /// Code blocks and tables, which are never wrapped
+    Preformatted,
+    /// Horizontal rule that fills the available width
+    Rule,
+}
+
+#[derive(Clone, Debug)]
+struct MarkdownLine {
+    line: Line<'static>,
+    initial_indent: Vec<Span<'static>>,
+    subsequent_indent: Vec<Span<'static>>,
+    kind: LineKind,
+    // Character ranges within the line that belong to a link, along with the link's index
+    links: Vec<(Range<usize>, usize)>,
+}
+
+struct MarkdownRow {
+    line: Line<'static>,
+    links: Vec<LinkSegment>,
+}
+
+/// The part of a link that's shown on a single row.
+struct LinkSegment {
+    link: usize,
+    columns: Range<usize>,
+    spans: Vec<Span<'static>>,
+}
+
+/// A link segment positioned relative to the rendered widget.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct LinkArea {
+    link: usize,
+    url: String,
+    area: Rect,
+    spans: Vec<Span<'static>>,
+    // Only the first visible segment of each link can receive focus
+    primary: bool,
+}
+
+struct Document {
+    lines: Vec<MarkdownLine>,
+    // Destination of each link, indexed by the link's position in the document
+    urls: Vec<String>,
+}
+
+enum Container {
+    Quote,
+    Item {
+        marker: Option<Span<'static>>,
+        width: usize,
+    },
+}
+
+#[derive(Default)]
+struct Table {
+    alignments: Vec<Alignment>,
+    header_rows: usize,
+    rows: Vec<Vec<TableCell>>,
+    row: Vec<TableCell>,
+}
+
+#[derive(Clone, Default)]
+struct TableCell {
+    line: Line<'static>,
+    // Character ranges within the cell that belong to a link
+    links: Vec<(Range<usize>, usize)>,
+}
+
+struct MarkdownParser<'a> {
+    theme: &'a MarkdownTheme,
+    lines: Vec<MarkdownLine>,
+    containers: Vec<Container>,
+    // Ordered lists hold the number of the next item
+    lists: Vec<Option<u64>>,
+    styles: Vec<Style>,
+    spans: Vec<Span<'static>>,
+    links: Vec<(Range<usize>, usize)>,
+    urls: Vec<String>,
+    // Start of the open link within the current line and its index
+    link: Option<(usize, usize)>,
+    code_block: Option<String>,
+    table: Option<Table>,
+}
+
+fn parse(source: &str, theme: &MarkdownTheme) -> Document {
+    let mut parser = MarkdownParser {
+        theme,
+        lines: Vec::new(),
+        containers: Vec::new(),
+        lists: Vec::new(),
+        styles: Vec::new(),
+        spans: Vec::new(),
+        links: Vec::new(),
+        urls: Vec::new(),
+        link: None,
+        code_block: None,
+        table: None,
+    };
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    for event in Parser::new_ext(source, options) {
+        parser.handle_event(event);
+    }
+    parser.flush();
+    Document {
+        lines: parser.lines,
+        urls: parser.urls,
+    }
+}
+
+impl MarkdownParser<'_> {
+    fn handle_event(&mut self, event: Event<'_>) {
+        match event {
+            Event::Start(tag) => self.start_tag(tag),
+            Event::End(tag) => self.end_tag(tag),
+            Event::Text(text) => {
+                if let Some(code_block) = &mut self.code_block {
+                    code_block.push_str(&text);
+                } else {
+                    self.push_text(text.into_string(), Style::default());
+                }
+            }
+            Event::Code(code) => self.push_text(code.into_string(), self.theme.code),
+            Event::SoftBreak => self.push_text(" ".to_string(), Style::default()),
+            Event::HardBreak => self.flush(),
+            Event::Rule => {
+                self.start_block();
+                self.push_line(Line::default(), LineKind::Rule);
+            }
+            Event::TaskListMarker(checked) => {
+                let marker = if checked { "[x] " } else { "[ ] " };
+                self.push_text(marker.to_string(), self.theme.list_marker);
+            }
+            Event::FootnoteReference(label) => {
+                self.push_text(format!("[^{label}]"), Style::default());
+            }
+            Event::Html(_)
+            | Event::InlineHtml(_)
+            | Event::InlineMath(_)
+            | Event::DisplayMath(_) => {}
+        }
+    }
+
+    fn start_tag(&mut self, tag: Tag<'_>) {
+        match tag {
+            Tag::Paragraph => self.start_block(),
+            Tag::Heading { level, .. } => {
+                self.start_block();
+                self.styles.push(self.theme.heading);
+                self.push_text(format!("{} ", "#".repeat(level as usize)), Style::default());
+            }
+            Tag::BlockQuote(_) => {
+                self.start_block();
+                self.containers.push(Container::Quote);
+            }
+            Tag::CodeBlock(_) => {
+                self.start_block();
+                self.code_block = Some(String::new());
+            }
+            Tag::List(start) => {
+                // Nested lists in a tight list shouldn't be separated from their parent item
+                if matches!(self.containers.last(), Some(Container::Item { .. })) {
+                    self.flush();
+                } else {
+                    self.start_block();
+                }
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                self.flush();
+                let marker = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}. ", *number - 1)
+                    }
+                    _ => "• ".to_string(),
+                };
+                let width = Span::raw(marker.as_str()).width();
+                self.containers.push(Container::Item {
+                    marker: Some(Span::styled(marker, self.theme.list_marker)),
+                    width,
+                });
+            }
+            Tag::Table(alignments) => {
+                self.start_block();
+                self.table = Some(Table {
+                    alignments,
+                    ..Default::default()
+                });
+            }
+            Tag::TableHead | Tag::TableRow | Tag::TableCell => {}
+            Tag::Emphasis => self.styles.push(self.theme.emphasis),
+            Tag::Strong => self.styles.push(self.theme.strong),
+            Tag::Strikethrough => self.styles.push(self.theme.strikethrough),
+            Tag::Link { dest_url, .. } | Tag::Image { dest_url, .. } => {
+                self.styles.push(self.theme.link);
+                self.urls.push(dest_url.into_string());
+                self.link = Some((self.line_len(), self.urls.len() - 1));
+            }
+            _ => {}
+        }
+    }
+
+    fn end_tag(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => self.flush(),
+            TagEnd::Heading(_) => {
+                self.flush();
+                self.styles.pop();
+            }
+            TagEnd::BlockQuote(_) => {
+                self.flush();
+                self.containers.pop();
+            }
+            TagEnd::CodeBlock => {
+                let code = self.code_block.take().unwrap_or_default();
+                for line in code.lines() {
+                    self.push_line(
+                        Line::styled(line.to_string(), self.theme.code),
+                        LineKind::Preformatted,
+                    );
+                }
+            }
+            TagEnd::List(_) => {
+                self.flush();
+                self.lists.pop();
+            }
+            TagEnd::Item => {
+                self.flush();
+                self.containers.pop();
+            }
+            TagEnd::TableCell => {
+                let cell = TableCell {
+                    line: Line::from(std::mem::take(&mut self.spans)),
+                    links: std::mem::take(&mut self.links),
+                };
+                if let Some(table) = &mut self.table {
+                    table.row.push(cell);
+                }
+            }
+            TagEnd::TableHead | TagEnd::TableRow => {
+                if let Some(table) = &mut self.table {
+                    let row = std::mem::take(&mut table.row);
+                    table.rows.push(row);
+                    if tag == TagEnd::TableHead {
+                        table.header_rows = table.rows.len();
+                    }
+                }
+            }
+            TagEnd::Table => {
+                if let Some(table) = self.table.take() {
+                    self.push_table(table);
+                }
+            }
+            TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough => {
+                self.styles.pop();
+            }
+            TagEnd::Link | TagEnd::Image => {
+                self.styles.pop();
+                if let Some((start, link)) = self.link.take() {
+                    self.push_link(start, link);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn push_text(&mut self, text: String, style: Style) {
+        let style = self
+            .styles
+            .iter()
+            .fold(Style::default(), |acc, s| acc.patch(*s))
+            .patch(style);
+        self.spans.push(Span::styled(text, style));
+    }
+
+    fn line_len(&self) -> usize {
+        chars_len(&self.spans)
+    }
+
+    fn push_link(&mut self, start: usize, link: usize) {
+        let end = self.line_len();
+        if end > start {
+            self.links.push((start..end, link));
+        }
+    }
+
+    /// Adds a blank line before a new block unless it's the first thing in the document or in a
+    /// list item.
+    fn start_block(&mut self) {
+        self.flush();
+        let first_in_item = matches!(
+            self.containers.last(),
+            Some(Container::Item {
+                marker: Some(_),
+                ..
+            })
+        );
+        if !self.lines.is_empty() && !first_in_item {
+            let (indent, _) = self.indents(false);
+            self.lines.push(MarkdownLine {
+                line: Line::default(),
+                initial_indent: indent.clone(),
+                subsequent_indent: indent,
+                kind: LineKind::Text,
+                links: Vec::new(),
+            });
+        }
+    }
+
+    /// Ends the current line of inline text, if there is one.
+    fn flush(&mut self) {
+        if self.spans.is_empty() || self.table.is_some() {
+            return;
+        }
+        // Links that span a hard break continue at the start of the next line
+        if let Some((start, link)) = self.link {
+            self.push_link(start, link);
+            self.link = Some((0, link));
+        }
+        let line = Line::from(std::mem::take(&mut self.spans));
+        self.push_line(line, LineKind::Text);
+    }
+
+    fn push_line(&mut self, line: Line<'static>, kind: LineKind) {
+        let (initial_indent, subsequent_indent) = self.indents(true);
+        self.lines.push(MarkdownLine {
+            line,
+            initial_indent,
+            subsequent_indent,
+            kind,
+            links: std::mem::take(&mut self.links),
+        });
+    }
+
+    /// Returns the indentation for the first and subsequent rows of a line. List markers are only
+    /// shown once per item, so they're consumed if `take_marker` is set.
+    fn indents(&mut self, take_marker: bool) -> (Vec<Span<'static>>, Vec<Span<'static>>) {
+        let mut initial = Vec::new();
+        let mut subsequent = Vec::new();
+        let quote = Span::styled("│ ", self.theme.block_quote);
+        let innermost = self.containers.len().saturating_sub(1);
+        for (i, container) in self.containers.iter_mut().enumerate() {
+            match container {
+                Container::Quote => {
+                    initial.push(quote.clone());
+                    subsequent.push(quote.clone());
+                }
+                Container::Item { marker, width } => {
+                    let padding = Span::raw(" ".repeat(*width));
+                    // Markers of outer items were already shown on a previous line
+                    match marker.take_if(|_| take_marker && i == innermost) {
+                        Some(marker) => initial.push(marker),
+                        None => initial.push(padding.clone()),
+                    }
+                    subsequent.push(padding);
+                }
+            }
+        }
+        (initial, subsequent)
+    }
+
+    fn push_table(&mut self, table: Table) {
+        let columns = table.rows.iter().map(|r| r.len()).max().unwrap_or(0);
+        let widths: Vec<_> = (0..columns)
+            .map(|c| {
+                table
+                    .rows
+                    .iter()
+                    .filter_map(|r| r.get(c))
+                    .map(|cell| cell.line.width())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        let separator = Span::styled(" │ ", self.theme.separator);
+
+        for (i, row) in table.rows.into_iter().enumerate() {
+            let is_header = i < table.header_rows;
+            let mut spans = Vec::new();
+            let mut links = Vec::new();
+            for (c, width) in widths.iter().enumerate() {
+                if c > 0 {
+                    spans.push(separator.clone());
+                }
+                let TableCell {
+                    mut line,
+                    links: cell_links,
+                } = row.get(c).cloned().unwrap_or_default();
+                if is_header {
+                    line = line.patch_style(self.theme.strong);
+                }
+                let fill = width - line.width();
+                let (left, right) = match table.alignments.get(c) {
+                    Some(Alignment::Right) => (fill, 0),
+                    Some(Alignment::Center) => (fill / 2, fill - fill / 2),
+                    _ => (0, fill),
+                };
+                spans.push(Span::raw(" ".repeat(left)));
+                let offset = chars_len(&spans);
+                links.extend(
+                    cell_links
+                        .into_iter()
+                        .map(|(range, link)| (range.start + offset..range.end + offset, link)),
+                );
+                spans.extend(line.spans);
+                spans.push(Span::raw(" ".repeat(right)));
+            }
+            self.links = links;
+            self.push_line(Line::from(spans), LineKind::Preformatted);
+
+            if i + 1 == table.header_rows {
+                let rule = widths
+                    .iter()
+                    .map(|w| "─".repeat(*w))
+                    .collect::<Vec<_>>()
+                    .join("─┼─");
+                self.push_line(
+                    Line::styled(rule, self.theme.separator),
+                    LineKind::Preformatted,
+                );
+            }
+        }
+    }
+}
+
+fn spans_width(spans: &[Span<'_>]) -> usize {
+    spans.iter().map(|s| s.width()).sum()
+}
+
+fn chars_len(spans: &[Span<'_>]) -> usize {
+    spans.iter().map(|s| s.content.chars().count()).sum()
+}
+
+/// Returns the characters in `range` while keeping the style of each span.
+fn slice_spans(spans: &[Span<'_>], range: Range<usize>) -> Vec<Span<'static>> {
+    let mut sliced = Vec::new();
+    let mut offset = 0;
+    for span in spans {
+        let len = span.content.chars().count();
+        let start = range.start.saturating_sub(offset).min(len);
+        let end = range.end.saturating_sub(offset).min(len);
+        if start < end {
+            let content: String = span.content.chars().skip(start).take(end - start).collect();
+            sliced.push(Span::styled(content, span.style));
+        }
+        offset += len;
+    }
+    sliced
+}
+
+/// Returns the parts of `links` that are shown on a row. `start` is the character offset of the
+/// row within its line and `indent` is the width of the row's indentation.
+fn row_links(
+    spans: &[Span<'_>],
+    start: usize,
+    indent: usize,
+    links: &[(Range<usize>, usize)],
+) -> Vec<LinkSegment> {
+    let text: String = spans.iter().map(|s| s.content.as_ref()).collect();
+    let end = start + text.chars().count();
+    let column = |offset: usize| {
+        let prefix: String = text.chars().take(offset).collect();
+        indent + Span::raw(prefix).width()
+    };
+    links
+        .iter()
+        .filter(|(range, _)| range.start < end && range.end > start)
+        .map(|(range, link)| {
+            let chars = range.start.max(start) - start..range.end.min(end) - start;
+            LinkSegment {
+                link: *link,
+                columns: column(chars.start)..column(chars.end),
+                spans: slice_spans(spans, chars),
+            }
+        })
+        .collect()
+}
+
+fn layout(lines: &[MarkdownLine], width: usize, separator_style: Style) -> Vec<MarkdownRow> {
+    let mut rows = Vec::new();
+    for line in lines {
+        let indent_width =
+            spans_width(&line.initial_indent).max(spans_width(&line.subsequent_indent));
+        let text_width = width.saturating_sub(indent_width).max(1);
+        match line.kind {
+            LineKind::Rule => {
+                let mut spans = line.initial_indent.clone();
+                spans.push(Span::styled("─".repeat(text_width), separator_style));
+                rows.push(MarkdownRow {
+                    line: Line::from(spans),
+                    links: Vec::new(),
+                });
+            }
+            LineKind::Preformatted => {
+                let links = row_links(
+                    &line.line.spans,
+                    0,
+                    spans_width(&line.initial_indent),
+                    &line.links,
+                );
+                let mut spans = line.initial_indent.clone();
+                spans.extend(line.line.spans.iter().cloned());
+                rows.push(MarkdownRow {
+                    line: Line::from(spans),
+                    links,
+                });
+            }
+            LineKind::Text => {
+                let wrapped = wrap_line_with_offsets(
+                    &line.line,
+                    wrap::Options {
+                        width: text_width,
+                        initial_indent: Span::raw(""),
+                        subsequent_indent: Span::raw(""),
+                        break_words: true,
+                    },
+                );
+                for (i, (start, row)) in wrapped.into_iter().enumerate() {
+                    let mut spans = if i == 0 {
+                        line.initial_indent.clone()
+                    } else {
+                        line.subsequent_indent.clone()
+                    };
+                    let links = row_links(&row.spans, start, spans_width(&spans), &line.links);
+                    spans.extend(
+                        row.spans
+                            .into_iter()
+                            .map(|s| Span::styled(s.content.into_owned(), s.style)),
+                    );
+                    rows.push(MarkdownRow {
+                        line: Line::from(spans),
+                        links,
+                    });
+                }
+            }
+        }
+    }
+    rows
+}
+
+struct RenderMarkdown {
+    document: Document,
+    links: RwSignal<Vec<LinkArea>>,
+    separator_style: Style,
+    style: Style,
+}
+
+impl RenderMarkdown {
+    fn natural_width(&self) -> usize {
+        self.document
+            .lines
+            .iter()
+            .map(|l| spans_width(&l.initial_indent) + l.line.width())
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl WidgetRole for RenderMarkdown {
+    fn widget_role() -> Option<Role> {
+        Some(Role::Text)
+    }
+}
+
+impl RenderNode for RenderMarkdown {
+    fn render(&mut self, area: Rect, frame: &mut Frame) {
+        let buf = frame.buffer_mut();
+        buf.set_style(area, self.style);
+
+        let rows = layout(
+            &self.document.lines,
+            area.width as usize,
+            self.separator_style,
+        );
+        let mut links: Vec<LinkArea> = Vec::new();
+        for (y, row) in (0..area.height).zip(rows) {
+            for segment in row.links {
+                let x = segment.columns.start as u16;
+                let end = (segment.columns.end as u16).min(area.width);
+                if x >= end {
+                    continue;
+                }
+                links.push(LinkArea {
+                    link: segment.link,
+                    url: self.document.urls[segment.link].clone(),
+                    area: Rect {
+                        x,
+                        y,
+                        width: end - x,
+                        height: 1,
+                    },
+                    spans: segment.spans,
+                    primary: !links.iter().any(|l| l.link == segment.link),
+                });
+            }
+            row.line.render(
+                Rect {
+                    x: area.x,
+                    y: area.y + y,
+                    width: area.width,
+                    height: 1,
+                },
+                buf,
+            );
+        }
+        // The link nodes are positioned using the layout from this render, so they only need to
+        // be rebuilt when it changes
+        if self.links.with_untracked(|l| *l != links) {
+            self.links.set(links);
+        }
+    }
+}
+
+impl MeasureNode for RenderMarkdown {
+    fn measure(
+        &self,
+        known_dimensions: taffy::Size<Option<f32>>,
+        available_space: taffy::Size<taffy::AvailableSpace>,
+        _style: &taffy::Style,
+    ) -> taffy::Size<f32> {
+        let natural_width = self.natural_width() as f32;
+        let width = known_dimensions
+            .width
+            .unwrap_or(match available_space.width {
+                taffy::AvailableSpace::Definite(width) => width.min(natural_width),
+                _ => natural_width,
+            });
+        let height = known_dimensions.height.unwrap_or_else(|| {
+            layout(&self.document.lines, width as usize, self.separator_style).len() as f32
+        });
+        taffy::Size { width, height }
+    }
+
+    fn estimate_size(&self) -> taffy::Size<f32> {
+        taffy::Size {
+            width: self.natural_width() as f32,
+            height: self.document.lines.len() as f32,
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "./markdown_test.rs"]
+mod markdown_test;
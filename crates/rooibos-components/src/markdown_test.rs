@@ -0,0 +1,73 @@
+use std::ops::Range;
+
+use super::{layout, parse};
+use crate::default_theme;
+
+// Returns the row, columns, text and index of each visible part of a link
+fn links(source: &str, width: usize) -> (Vec<(usize, Range<usize>, String, usize)>, Vec<String>) {
+    let theme = default_theme().markdown_theme;
+    let document = parse(source, &theme);
+    let segments = layout(&document.lines, width, theme.separator)
+        .into_iter()
+        .enumerate()
+        .flat_map(|(row, markdown_row)| {
+            markdown_row.links.into_iter().map(move |segment| {
+                let text = segment.spans.iter().map(|s| s.content.as_ref()).collect();
+                (row, segment.columns, text, segment.link)
+            })
+        })
+        .collect();
+    (segments, document.urls)
+}
+
+#[test]
+fn link_in_paragraph() {
+    let (segments, urls) = links("See the [docs](https://docs.rs) here", 40);
+    assert_eq!(segments, vec![(0, 8..12, "docs".to_string(), 0)]);
+    assert_eq!(urls, vec!["https://docs.rs".to_string()]);
+}
+
+#[test]
+fn link_in_list_item() {
+    let (segments, _) = links("- item [link](https://example.com)", 40);
+    assert_eq!(segments, vec![(0, 7..11, "link".to_string(), 0)]);
+}
+
+#[test]
+fn link_in_table() {
+    let source = "| Name | Link |\n| ---- | ---- |\n| a | [docs](https://docs.rs) |\n";
+    let (segments, urls) = links(source, 40);
+    // The header and the rule under it come before the row with the link
+    assert_eq!(segments, vec![(2, 7..11, "docs".to_string(), 0)]);
+    assert_eq!(urls, vec!["https://docs.rs".to_string()]);
+}
+
+#[test]
+fn link_across_hard_break() {
+    let source = "see [first  \nsecond](https://example.com) after";
+    let (segments, urls) = links(source, 40);
+    assert_eq!(
+        segments,
+        vec![
+            (0, 4..9, "first".to_string(), 0),
+            (1, 0..6, "second".to_string(), 0),
+        ]
+    );
+    assert_eq!(urls.len(), 1);
+}
+
+#[test]
+fn multiple_links() {
+    let (segments, urls) = links("[one](https://one.com) and [two](https://two.com)", 40);
+    assert_eq!(
+        segments,
+        vec![
+            (0, 0..3, "one".to_string(), 0),
+            (0, 8..11, "two".to_string(), 1),
+        ]
+    );
+    assert_eq!(
+        urls,
+        vec!["https://one.com".to_string(), "https://two.com".to_string()]
+    );
+}
@@ -3,6 +3,7 @@ use std::time::Duration;
 
 use ratatui::text::Text;
 use rooibos_dom::BorderType;
+use rooibos_dom::clock::sleep;
 use rooibos_reactive::dom::Render;
 use rooibos_reactive::dom::layout::{
     Borders, Dimension, align_items, auto, borders, clear, end, full, height, max_width,
@@ -15,7 +16,7 @@ use rooibos_reactive::graph::traits::{Get, Update, WithValue};
 use rooibos_reactive::graph::wrappers::read::Signal;
 use rooibos_reactive::{IntoSignal, col, for_each, wgt};
 use tokio::sync::broadcast;
-use wasm_compat::futures::{sleep, spawn};
+use wasm_compat::futures::spawn_local;
 
 use crate::{BorderPropertiesBorderTypeExt, ColorThemeColorTheme};
 
@@ -140,12 +141,13 @@ impl Notifications {
         } = self;
         let (notifications, set_notifications) = signal(vec![]);
 
-        spawn(async move {
+        // The timeouts use the dom clock, which is local to the current thread
+        spawn_local(async move {
             while let Ok(notification) = rx.recv().await {
                 let id = notification.id;
                 let timeout = notification.timeout;
                 set_notifications.update(|n| n.push(notification));
-                spawn(async move {
+                spawn_local(async move {
                     sleep(timeout).await;
                     set_notifications.update(|n| {
                         let idx = n.iter().position(|n| n.id == id);
@@ -0,0 +1,704 @@
+use std::time::Duration;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Widget;
+use rooibos_dom::MeasureNode;
+use rooibos_dom::widgets::{Role, WidgetRole};
+use rooibos_reactive::dom::div::taffy;
+use rooibos_reactive::dom::{LayoutProps, Render, UpdateLayoutProps};
+use rooibos_reactive::graph::IntoReactiveValue;
+use rooibos_reactive::graph::computed::Memo;
+use rooibos_reactive::graph::effect::Effect;
+use rooibos_reactive::graph::owner::{StoredValue, on_cleanup};
+use rooibos_reactive::graph::signal::{ArcRwSignal, ReadSignal, signal};
+use rooibos_reactive::graph::traits::{Get, GetUntracked, GetValue, Set, SetValue, Update};
+use rooibos_reactive::graph::wrappers::read::Signal;
+use rooibos_reactive::{IntoSignal, wgt};
+use rooibos_theme::{ProfileVariant, Style};
+use tokio_util::future::FutureExt;
+use tokio_util::sync::CancellationToken;
+use wasm_compat::futures::spawn_local;
+
+use crate::ColorThemeColorTheme;
+
+// Minimum number of cells reserved for the bar when measuring a progress bar
+const MIN_BAR_WIDTH: usize = 10;
+
+/// Characters used to draw a progress component.
+///
+/// `filled`, `head`, and `empty` should each be a single cell wide. `head` is drawn at the leading
+/// edge of the filled section and may be empty.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProgressSymbols {
+    pub start: &'static str,
+    pub end: &'static str,
+    pub filled: &'static str,
+    pub head: &'static str,
+    pub empty: &'static str,
+}
+
+impl ProgressSymbols {
+    pub const UNICODE: Self = Self {
+        start: "",
+        end: "",
+        filled: "█",
+        head: "",
+        empty: "░",
+    };
+
+    pub const ASCII: Self = Self {
+        start: "[",
+        end: "]",
+        filled: "=",
+        head: ">",
+        empty: " ",
+    };
+
+    /// Uses [`UNICODE`](Self::UNICODE) symbols, falling back to [`ASCII`](Self::ASCII) symbols
+    /// when the terminal profile is ascii-only or output isn't a TTY.
+    pub fn adaptive() -> Self {
+        ProfileVariant::new(Self::UNICODE)
+            .ascii(Self::ASCII)
+            .no_tty(Self::ASCII)
+            .adapt()
+    }
+}
+
+/// Handle used to report progress from a background task.
+///
+/// The reporter is `Send` and `Sync`, so it can be moved into a spawned service while a
+/// [`ProgressBar`] or [`Gauge`] displays the progress.
+#[derive(Clone)]
+pub struct ProgressReporter {
+    progress: ArcRwSignal<f64>,
+    indeterminate: ArcRwSignal<bool>,
+}
+
+impl Default for ProgressReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProgressReporter {
+    pub fn new() -> Self {
+        Self {
+            progress: ArcRwSignal::new(0.0),
+            indeterminate: ArcRwSignal::new(false),
+        }
+    }
+
+    /// Sets the progress as a value between `0.0` and `1.0`. This also leaves indeterminate
+    /// mode.
+    pub fn set(&self, progress: f64) {
+        self.progress.set(progress.clamp(0.0, 1.0));
+        self.set_indeterminate(false);
+    }
+
+    /// Adds `delta` to the current progress.
+    pub fn inc(&self, delta: f64) {
+        self.progress
+            .update(|progress| *progress = (*progress + delta).clamp(0.0, 1.0));
+        self.set_indeterminate(false);
+    }
+
+    /// Use when the amount of remaining work isn't known.
+    pub fn set_indeterminate(&self, indeterminate: bool) {
+        if self.indeterminate.get_untracked() != indeterminate {
+            self.indeterminate.set(indeterminate);
+        }
+    }
+
+    pub fn finish(&self) {
+        self.set(1.0);
+    }
+
+    pub fn progress(&self) -> Signal<f64> {
+        self.progress.clone().into()
+    }
+
+    pub fn is_indeterminate(&self) -> Signal<bool> {
+        self.indeterminate.clone().into()
+    }
+}
+
+#[derive(Clone, Copy)]
+struct ProgressStats {
+    show_percent: bool,
+    show_eta: bool,
+    show_throughput: bool,
+    total: Option<Signal<f64>>,
+    unit: Signal<&'static str>,
+}
+
+impl Default for ProgressStats {
+    fn default() -> Self {
+        Self {
+            show_percent: true,
+            show_eta: false,
+            show_throughput: false,
+            total: None,
+            unit: "".into(),
+        }
+    }
+}
+
+impl ProgressStats {
+    fn status(self, progress: Signal<f64>, indeterminate: Signal<bool>) -> Signal<String> {
+        let Self {
+            show_percent,
+            show_eta,
+            show_throughput,
+            total,
+            unit,
+        } = self;
+        let rate = track_rate(progress);
+
+        (move || {
+            if indeterminate.get() {
+                return String::new();
+            }
+            let progress = progress.get().clamp(0.0, 1.0);
+            let rate = rate.get();
+            let mut parts = Vec::new();
+            if show_percent {
+                parts.push(format!("{:>3.0}%", progress * 100.0));
+            }
+            if show_eta
+                && progress < 1.0
+                && let Some(rate) = rate
+            {
+                parts.push(format!("ETA {}", format_duration((1.0 - progress) / rate)));
+            }
+            if show_throughput && let Some(rate) = rate {
+                parts.push(match total {
+                    Some(total) => format_throughput(rate * total.get(), unit.get()),
+                    None => format!("{:.1}%/s", rate * 100.0),
+                });
+            }
+            parts.join("  ")
+        })
+        .signal()
+    }
+}
+
+// Average rate of progress per second since progress started, or since it last went backwards
+fn track_rate(progress: Signal<f64>) -> Memo<Option<f64>> {
+    let start = StoredValue::new(None::<(f64, f64)>);
+    Memo::new(move |_| {
+        let progress = progress.get();
+        let now = rooibos_dom::clock::now();
+        let Some((start_time, start_progress)) = start
+            .get_value()
+            .filter(|(_, start_progress)| *start_progress <= progress)
+        else {
+            start.set_value(Some((now, progress)));
+            return None;
+        };
+        let elapsed = (now - start_time) / 1000.0;
+        if elapsed <= 0.0 || progress <= start_progress {
+            return None;
+        }
+        Some((progress - start_progress) / elapsed)
+    })
+}
+
+fn format_duration(secs: f64) -> String {
+    let secs = secs.ceil() as u64;
+    let (hours, minutes, secs) = (secs / 3600, (secs / 60) % 60, secs % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{secs:02}")
+    } else {
+        format!("{minutes}:{secs:02}")
+    }
+}
+
+fn format_throughput(amount: f64, unit: &str) -> String {
+    if unit.is_empty() {
+        format!("{amount:.1}/s")
+    } else {
+        format!("{amount:.1} {unit}/s")
+    }
+}
+
+// Advances a counter on each tick while active, used to animate indeterminate progress
+fn use_tick(active: Signal<bool>, tick_interval: Duration) -> ReadSignal<usize> {
+    let (tick, set_tick) = signal(0usize);
+
+    let cancellation_token = CancellationToken::new();
+    {
+        let cancellation_token = cancellation_token.clone();
+        Effect::new(move |child_token: Option<CancellationToken>| {
+            if let Some(child_token) = &child_token {
+                child_token.cancel();
+            }
+            let child_token = cancellation_token.child_token();
+            if active.get() {
+                spawn_local({
+                    let child_token = child_token.clone();
+                    async move {
+                        loop {
+                            if rooibos_dom::clock::sleep(tick_interval)
+                                .with_cancellation_token(&child_token)
+                                .await
+                                .is_none()
+                            {
+                                return;
+                            }
+
+                            if set_tick.try_update(|t| *t = t.wrapping_add(1)).is_none() {
+                                return;
+                            }
+                        }
+                    }
+                });
+            }
+            child_token
+        });
+    }
+
+    on_cleanup(move || {
+        cancellation_token.cancel();
+    });
+
+    tick
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Segments {
+    before: usize,
+    filled: usize,
+    head: bool,
+    after: usize,
+}
+
+impl Segments {
+    fn new(width: usize, ratio: f64, tick: Option<usize>, symbols: &ProgressSymbols) -> Self {
+        match tick {
+            Some(tick) => {
+                // Bounce a block back and forth across the bar
+                let filled = (width / 4).max(1).min(width);
+                let travel = width - filled;
+                let before = if travel == 0 {
+                    0
+                } else {
+                    let pos = tick % (2 * travel);
+                    if pos > travel { 2 * travel - pos } else { pos }
+                };
+                Self {
+                    before,
+                    filled,
+                    head: false,
+                    after: width - before - filled,
+                }
+            }
+            None => {
+                let filled = ((width as f64) * ratio.clamp(0.0, 1.0)).floor() as usize;
+                let head = !symbols.head.is_empty() && ratio > 0.0 && filled < width;
+                Self {
+                    before: 0,
+                    filled,
+                    head,
+                    after: width - filled - head as usize,
+                }
+            }
+        }
+    }
+
+    fn spans(
+        self,
+        symbols: &ProgressSymbols,
+        style: Style,
+        bar_style: Style,
+    ) -> impl Iterator<Item = Span<'static>> {
+        [
+            Span::styled(symbols.empty.repeat(self.before), style),
+            Span::styled(symbols.filled.repeat(self.filled), bar_style),
+            Span::styled(if self.head { symbols.head } else { "" }, bar_style),
+            Span::styled(symbols.empty.repeat(self.after), style),
+        ]
+        .into_iter()
+    }
+}
+
+macro_rules! progress_builders {
+    ($bar_style:ident) => {
+        pub fn style<M>(mut self, style: impl IntoReactiveValue<Signal<Style>, M>) -> Self {
+            self.style = style.into_reactive_value();
+            self
+        }
+
+        /// Style of the filled section.
+        pub fn $bar_style<M>(
+            mut self,
+            $bar_style: impl IntoReactiveValue<Signal<Style>, M>,
+        ) -> Self {
+            self.bar_style = $bar_style.into_reactive_value();
+            self
+        }
+
+        pub fn symbols<M>(
+            mut self,
+            symbols: impl IntoReactiveValue<Signal<ProgressSymbols>, M>,
+        ) -> Self {
+            self.symbols = symbols.into_reactive_value();
+            self
+        }
+
+        pub fn label<M>(mut self, label: impl IntoReactiveValue<Signal<Span<'static>>, M>) -> Self {
+            self.label = Some(label.into_reactive_value());
+            self
+        }
+
+        /// Shows an animation instead of the current progress while the amount of remaining work
+        /// isn't known.
+        pub fn indeterminate<M>(
+            mut self,
+            indeterminate: impl IntoReactiveValue<Signal<bool>, M>,
+        ) -> Self {
+            self.indeterminate = indeterminate.into_reactive_value();
+            self
+        }
+
+        /// How often the indeterminate animation advances.
+        pub fn tick_interval(mut self, tick_interval: Duration) -> Self {
+            self.tick_interval = tick_interval;
+            self
+        }
+
+        pub fn show_percent(mut self, show_percent: bool) -> Self {
+            self.stats.show_percent = show_percent;
+            self
+        }
+
+        /// Shows the estimated time remaining, based on the average rate of progress.
+        pub fn show_eta(mut self, show_eta: bool) -> Self {
+            self.stats.show_eta = show_eta;
+            self
+        }
+
+        /// Shows the average rate of progress. This is displayed as a percentage unless
+        /// [`total`](Self::total) is set.
+        pub fn show_throughput(mut self, show_throughput: bool) -> Self {
+            self.stats.show_throughput = show_throughput;
+            self
+        }
+
+        /// The total amount of work, used to display throughput in terms of [`unit`](Self::unit).
+        pub fn total<M>(mut self, total: impl IntoReactiveValue<Signal<f64>, M>) -> Self {
+            self.stats.total = Some(total.into_reactive_value());
+            self
+        }
+
+        pub fn unit<M>(mut self, unit: impl IntoReactiveValue<Signal<&'static str>, M>) -> Self {
+            self.stats.unit = unit.into_reactive_value();
+            self
+        }
+
+        /// Renders progress using the values sent to `reporter`.
+        pub fn render_reporter(self, reporter: &ProgressReporter) -> impl Render {
+            self.indeterminate(reporter.is_indeterminate())
+                .render(reporter.progress())
+        }
+    };
+}
+
+/// A single-line progress bar with an optional status label.
+pub struct ProgressBar {
+    style: Signal<Style>,
+    bar_style: Signal<Style>,
+    symbols: Signal<ProgressSymbols>,
+    label: Option<Signal<Span<'static>>>,
+    indeterminate: Signal<bool>,
+    tick_interval: Duration,
+    stats: ProgressStats,
+    layout_props: LayoutProps,
+}
+
+impl UpdateLayoutProps for ProgressBar {
+    fn layout_props(&self) -> LayoutProps {
+        self.layout_props.clone()
+    }
+
+    fn update_props(mut self, props: LayoutProps) -> Self {
+        self.layout_props = props;
+        self
+    }
+}
+
+impl Default for ProgressBar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProgressBar {
+    pub fn new() -> Self {
+        Self {
+            style: Style::default().fg_text_primary(),
+            bar_style: Style::default().fg_active(),
+            symbols: (ProgressSymbols::adaptive).signal(),
+            label: None,
+            indeterminate: false.into(),
+            tick_interval: Duration::from_millis(100),
+            stats: ProgressStats::default(),
+            layout_props: LayoutProps::default(),
+        }
+    }
+
+    progress_builders!(bar_style);
+
+    /// Renders the bar. `progress` should be between `0.0` and `1.0`.
+    pub fn render<M>(self, progress: impl IntoReactiveValue<Signal<f64>, M>) -> impl Render {
+        let Self {
+            style,
+            bar_style,
+            symbols,
+            label,
+            indeterminate,
+            tick_interval,
+            stats,
+            layout_props,
+        } = self;
+        let progress: Signal<f64> = progress.into_reactive_value();
+        let status = stats.status(progress, indeterminate);
+        let tick = use_tick(indeterminate, tick_interval);
+
+        wgt!(ProgressBarWidget {
+            ratio: progress.get(),
+            tick: indeterminate.get().then(|| tick.get()),
+            symbols: symbols.get(),
+            label: label.map(|l| l.get()),
+            status: status.get(),
+            style: style.get(),
+            bar_style: bar_style.get(),
+        })
+        .layout_props(layout_props)
+    }
+}
+
+struct ProgressBarWidget {
+    ratio: f64,
+    tick: Option<usize>,
+    symbols: ProgressSymbols,
+    label: Option<Span<'static>>,
+    status: String,
+    style: Style,
+    bar_style: Style,
+}
+
+impl ProgressBarWidget {
+    // Width of everything except the bar itself
+    fn decoration_width(&self) -> usize {
+        let label_width = self.label.as_ref().map(|l| l.width() + 1).unwrap_or(0);
+        let status_width = if self.status.is_empty() {
+            0
+        } else {
+            Span::raw(&self.status).width() + 1
+        };
+        label_width
+            + status_width
+            + Span::raw(self.symbols.start).width()
+            + Span::raw(self.symbols.end).width()
+    }
+}
+
+impl WidgetRole for ProgressBarWidget {
+    fn widget_role() -> Option<Role> {
+        Some(Role::ProgressIndicator)
+    }
+}
+
+impl Widget for &ProgressBarWidget {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let bar_width = (area.width as usize).saturating_sub(self.decoration_width());
+        let segments = Segments::new(bar_width, self.ratio, self.tick, &self.symbols);
+
+        let mut line = Line::default();
+        if let Some(label) = &self.label {
+            line.spans.push(label.clone().patch_style(self.style));
+            line.spans.push(Span::raw(" "));
+        }
+        line.spans
+            .push(Span::styled(self.symbols.start, self.style));
+        line.spans
+            .extend(segments.spans(&self.symbols, self.style, self.bar_style));
+        line.spans.push(Span::styled(self.symbols.end, self.style));
+        if !self.status.is_empty() {
+            line.spans.push(Span::raw(" "));
+            line.spans
+                .push(Span::styled(self.status.clone(), self.style));
+        }
+        line.render(area, buf);
+    }
+}
+
+impl MeasureNode for ProgressBarWidget {
+    fn measure(
+        &self,
+        known_dimensions: taffy::Size<Option<f32>>,
+        _available_space: taffy::Size<taffy::AvailableSpace>,
+        _style: &taffy::Style,
+    ) -> taffy::Size<f32> {
+        let size = self.estimate_size();
+        taffy::Size {
+            width: known_dimensions.width.unwrap_or(size.width),
+            height: known_dimensions.height.unwrap_or(size.height),
+        }
+    }
+
+    fn estimate_size(&self) -> taffy::Size<f32> {
+        taffy::Size {
+            width: (self.decoration_width() + MIN_BAR_WIDTH) as f32,
+            height: 1.0,
+        }
+    }
+}
+
+/// A progress block that fills its area, with its status label centered inside.
+pub struct Gauge {
+    style: Signal<Style>,
+    bar_style: Signal<Style>,
+    symbols: Signal<ProgressSymbols>,
+    label: Option<Signal<Span<'static>>>,
+    indeterminate: Signal<bool>,
+    tick_interval: Duration,
+    stats: ProgressStats,
+    layout_props: LayoutProps,
+}
+
+impl UpdateLayoutProps for Gauge {
+    fn layout_props(&self) -> LayoutProps {
+        self.layout_props.clone()
+    }
+
+    fn update_props(mut self, props: LayoutProps) -> Self {
+        self.layout_props = props;
+        self
+    }
+}
+
+impl Default for Gauge {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Gauge {
+    pub fn new() -> Self {
+        Self {
+            style: Style::default().fg_text_primary(),
+            bar_style: Style::default().fg_active(),
+            symbols: (ProgressSymbols::adaptive).signal(),
+            label: None,
+            indeterminate: false.into(),
+            tick_interval: Duration::from_millis(100),
+            stats: ProgressStats::default(),
+            layout_props: LayoutProps::default(),
+        }
+    }
+
+    progress_builders!(gauge_style);
+
+    /// Renders the gauge. `progress` should be between `0.0` and `1.0`.
+    pub fn render<M>(self, progress: impl IntoReactiveValue<Signal<f64>, M>) -> impl Render {
+        let Self {
+            style,
+            bar_style,
+            symbols,
+            label,
+            indeterminate,
+            tick_interval,
+            stats,
+            layout_props,
+        } = self;
+        let progress: Signal<f64> = progress.into_reactive_value();
+        let status = stats.status(progress, indeterminate);
+        let tick = use_tick(indeterminate, tick_interval);
+
+        wgt!(GaugeWidget {
+            ratio: progress.get(),
+            tick: indeterminate.get().then(|| tick.get()),
+            symbols: symbols.get(),
+            label: gauge_label(label.map(|l| l.get()), status.get()),
+            style: style.get(),
+            bar_style: bar_style.get(),
+        })
+        .layout_props(layout_props)
+    }
+}
+
+fn gauge_label(label: Option<Span<'static>>, status: String) -> Line<'static> {
+    let mut line = Line::default();
+    if let Some(label) = label {
+        line.spans.push(label);
+    }
+    if !status.is_empty() {
+        if !line.spans.is_empty() {
+            line.spans.push(Span::raw(" "));
+        }
+        line.spans.push(Span::raw(status));
+    }
+    line
+}
+
+struct GaugeWidget {
+    ratio: f64,
+    tick: Option<usize>,
+    symbols: ProgressSymbols,
+    label: Line<'static>,
+    style: Style,
+    bar_style: Style,
+}
+
+impl WidgetRole for GaugeWidget {
+    fn widget_role() -> Option<Role> {
+        Some(Role::ProgressIndicator)
+    }
+}
+
+impl Widget for &GaugeWidget {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let segments = Segments::new(area.width as usize, self.ratio, self.tick, &self.symbols);
+        for row in area.rows() {
+            Line::from_iter(segments.spans(&self.symbols, self.style, self.bar_style))
+                .render(row, buf);
+        }
+        if area.height > 0 {
+            let row = area.rows().nth((area.height / 2) as usize).unwrap_or(area);
+            self.label
+                .clone()
+                .patch_style(self.style)
+                .centered()
+                .render(row, buf);
+        }
+    }
+}
+
+impl MeasureNode for GaugeWidget {
+    fn measure(
+        &self,
+        known_dimensions: taffy::Size<Option<f32>>,
+        _available_space: taffy::Size<taffy::AvailableSpace>,
+        _style: &taffy::Style,
+    ) -> taffy::Size<f32> {
+        let size = self.estimate_size();
+        taffy::Size {
+            width: known_dimensions.width.unwrap_or(size.width),
+            height: known_dimensions.height.unwrap_or(size.height),
+        }
+    }
+
+    fn estimate_size(&self) -> taffy::Size<f32> {
+        taffy::Size {
+            width: (self.label.width() + 2) as f32,
+            height: 1.0,
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "./progress_test.rs"]
+mod progress_test;
@@ -0,0 +1,87 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::Widget;
+use rooibos_reactive::graph::traits::GetUntracked;
+use rooibos_theme::Style;
+
+use super::{ProgressBarWidget, ProgressReporter, ProgressSymbols, Segments};
+
+fn segments(width: usize, ratio: f64) -> Segments {
+    Segments::new(width, ratio, None, &ProgressSymbols::ASCII)
+}
+
+fn bounce(width: usize, tick: usize) -> Segments {
+    Segments::new(width, 0.0, Some(tick), &ProgressSymbols::ASCII)
+}
+
+fn render(ratio: f64, tick: Option<usize>) -> String {
+    let widget = ProgressBarWidget {
+        ratio,
+        tick,
+        symbols: ProgressSymbols::ASCII,
+        label: None,
+        status: String::new(),
+        style: Style::default(),
+        bar_style: Style::default(),
+    };
+    let area = Rect::new(0, 0, 12, 1);
+    let mut buf = Buffer::empty(area);
+    (&widget).render(area, &mut buf);
+    buf.content().iter().map(|cell| cell.symbol()).collect()
+}
+
+#[test]
+fn ratio_is_clamped() {
+    let below = segments(10, -0.5);
+    assert_eq!((below.filled, below.head, below.after), (0, false, 10));
+
+    let above = segments(10, 1.5);
+    assert_eq!((above.filled, above.head, above.after), (10, false, 0));
+
+    let half = segments(10, 0.5);
+    assert_eq!((half.filled, half.head, half.after), (5, true, 4));
+
+    assert_eq!(render(-1.0, None), "[          ]");
+    assert_eq!(render(0.5, None), "[=====>    ]");
+    assert_eq!(render(2.0, None), "[==========]");
+}
+
+#[test]
+fn reporter_clamps_progress() {
+    let reporter = ProgressReporter::new();
+    reporter.set(1.5);
+    assert_eq!(reporter.progress().get_untracked(), 1.0);
+
+    reporter.inc(-3.0);
+    assert_eq!(reporter.progress().get_untracked(), 0.0);
+
+    reporter.set_indeterminate(true);
+    reporter.inc(0.25);
+    assert_eq!(reporter.progress().get_untracked(), 0.25);
+    assert!(!reporter.is_indeterminate().get_untracked());
+}
+
+#[test]
+fn indeterminate_bounces() {
+    // A block a quarter of the width moves to the end of the bar and back again
+    let positions: Vec<_> = (0..=12).map(|tick| bounce(8, tick).before).collect();
+    assert_eq!(positions, [0, 1, 2, 3, 4, 5, 6, 5, 4, 3, 2, 1, 0]);
+    for tick in 0..=12 {
+        let segments = bounce(8, tick);
+        assert_eq!(segments.filled, 2);
+        assert!(!segments.head);
+        assert_eq!(segments.before + segments.filled + segments.after, 8);
+    }
+
+    assert_eq!(render(0.0, Some(0)), "[==        ]");
+    assert_eq!(render(0.9, Some(3)), "[   ==     ]");
+}
+
+#[test]
+fn indeterminate_fits_small_widths() {
+    for tick in 0..4 {
+        assert_eq!(bounce(0, tick).filled, 0);
+        let one = bounce(1, tick);
+        assert_eq!((one.before, one.filled, one.after), (0, 1, 0));
+    }
+}
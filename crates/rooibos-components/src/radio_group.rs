@@ -0,0 +1,192 @@
+use std::sync::Arc;
+
+use ratatui::text::{Line, Span};
+use rooibos_dom::events::KeyEventProps;
+use rooibos_dom::widgets::Role;
+use rooibos_reactive::dom::layout::{checked, focus_mode, vertical_list};
+use rooibos_reactive::dom::{LayoutProps, Render, UpdateLayoutProps};
+use rooibos_reactive::graph::IntoReactiveValue;
+use rooibos_reactive::graph::owner::StoredValue;
+use rooibos_reactive::graph::signal::RwSignal;
+use rooibos_reactive::graph::traits::{Get, Set, UpdateValue, With, WithUntracked};
+use rooibos_reactive::graph::wrappers::read::Signal;
+use rooibos_reactive::{IntoSignal, StateProp, col, focus_scope, for_each, use_state_prop, wgt};
+use rooibos_theme::Style;
+
+use crate::checkbox::{CheckableRole, CheckableWidget, default_checkable_style, is_activation_key};
+
+type LabelFn<T> = dyn Fn(&T) -> Line<'static> + Send + Sync;
+
+struct RadioButtonRole;
+
+impl CheckableRole for RadioButtonRole {
+    const ROLE: Role = Role::RadioButton;
+}
+
+/// A set of mutually exclusive options.
+///
+/// The options form a vertical focus list, so <kbd>Up</kbd> and <kbd>Down</kbd> move between
+/// them and <kbd>Space</kbd> or <kbd>Enter</kbd> selects the focused option.
+pub struct RadioGroup<T>
+where
+    T: Send + Sync + 'static,
+{
+    value: Option<RwSignal<Option<T>>>,
+    on_change: Box<dyn FnMut(T)>,
+    style: StateProp<Style>,
+    selected_symbol: Signal<&'static str>,
+    unselected_symbol: Signal<&'static str>,
+    layout_props: LayoutProps,
+}
+
+impl<T> Default for RadioGroup<T>
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> UpdateLayoutProps for RadioGroup<T>
+where
+    T: Send + Sync + 'static,
+{
+    fn layout_props(&self) -> LayoutProps {
+        self.layout_props.clone()
+    }
+
+    fn update_props(mut self, props: LayoutProps) -> Self {
+        self.layout_props = props;
+        self
+    }
+}
+
+impl<T> RadioGroup<T>
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    pub fn new() -> Self {
+        Self {
+            value: None,
+            on_change: Box::new(|_| {}),
+            style: default_checkable_style(),
+            selected_symbol: "(•)".into(),
+            unselected_symbol: "( )".into(),
+            layout_props: LayoutProps::default(),
+        }
+    }
+
+    /// Signal holding the selected option. This is updated when the user picks an option and can
+    /// be set to change the selection programmatically.
+    pub fn value(mut self, value: RwSignal<Option<T>>) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    pub fn on_change(mut self, on_change: impl FnMut(T) + 'static) -> Self {
+        self.on_change = Box::new(on_change);
+        self
+    }
+
+    /// Style for each option.
+    pub fn style(mut self, style: StateProp<Style>) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn selected_symbol<M>(
+        mut self,
+        selected_symbol: impl IntoReactiveValue<Signal<&'static str>, M>,
+    ) -> Self {
+        self.selected_symbol = selected_symbol.into_reactive_value();
+        self
+    }
+
+    pub fn unselected_symbol<M>(
+        mut self,
+        unselected_symbol: impl IntoReactiveValue<Signal<&'static str>, M>,
+    ) -> Self {
+        self.unselected_symbol = unselected_symbol.into_reactive_value();
+        self
+    }
+
+    /// Renders a radio button for each option. `label` is used to display each option.
+    pub fn render<M, F, L>(
+        self,
+        items: impl IntoReactiveValue<Signal<Vec<T>>, M>,
+        label: F,
+    ) -> impl Render
+    where
+        F: Fn(&T) -> L + Send + Sync + 'static,
+        L: Into<Line<'static>>,
+    {
+        let Self {
+            value,
+            on_change,
+            style,
+            selected_symbol,
+            unselected_symbol,
+            layout_props,
+        } = self;
+        let items: Signal<Vec<T>> = items.into_reactive_value();
+        let value = value.unwrap_or_else(|| RwSignal::new(None));
+        let label: Arc<LabelFn<T>> = Arc::new(move |item| label(item).into());
+        let on_change = StoredValue::new_local(on_change);
+
+        let radio_button = move |index: usize| {
+            let (style, set_style_state) = use_state_prop(style.clone());
+            let label = label.clone();
+            let item = move || items.with(|items| items.get(index).cloned());
+            let is_selected = (move || {
+                value.with(|value| {
+                    value.is_some() && items.with(|items| items.get(index) == value.as_ref())
+                })
+            })
+            .signal();
+
+            let select = move || {
+                let Some(item) = items.with_untracked(|items| items.get(index).cloned()) else {
+                    return;
+                };
+                if value.with_untracked(|v| v.as_ref() != Some(&item)) {
+                    value.set(Some(item.clone()));
+                    on_change.update_value(|on_change| on_change(item));
+                }
+            };
+
+            let content = move || {
+                let symbol = if is_selected.get() {
+                    selected_symbol.get()
+                } else {
+                    unselected_symbol.get()
+                };
+                let mut line = item().map(|item| label(&item)).unwrap_or_default();
+                line.spans.insert(0, Span::raw(format!("{symbol} ")));
+                CheckableWidget::<RadioButtonRole>::new(line.patch_style(style.get()))
+            };
+
+            wgt!(style(checked(is_selected)), content())
+                .on_state_change(set_style_state)
+                .on_click(move |_| select())
+                .on_key_down(move |props: KeyEventProps| {
+                    if is_activation_key(&props) {
+                        props.handle.stop_propagation();
+                        select();
+                    }
+                })
+        };
+
+        col![
+            style(layout_props),
+            focus_scope!(
+                style(focus_mode(vertical_list())),
+                for_each(
+                    move || 0..items.with(|items| items.len()),
+                    |i| *i,
+                    radio_button
+                )
+            )
+        ]
+    }
+}
@@ -0,0 +1,790 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, List, ListItem, ListState, StatefulWidget, Widget};
+use rooibos_dom::events::{ClickEventProps, KeyEventProps};
+use rooibos_dom::widgets::{Role, WidgetRole};
+use rooibos_dom::{BorderType, KeyCode, KeyModifiers, MeasureNode};
+use rooibos_reactive::dom::div::taffy;
+use rooibos_reactive::dom::layout::{
+    Borders, borders, chars, clear, focusable, full, height, margin_left, margin_top, show, width,
+    z_index,
+};
+use rooibos_reactive::dom::{LayoutProps, Render, UpdateLayoutProps};
+use rooibos_reactive::graph::IntoReactiveValue;
+use rooibos_reactive::graph::computed::Memo;
+use rooibos_reactive::graph::signal::RwSignal;
+use rooibos_reactive::graph::traits::{Get, GetUntracked, Set, With, WithUntracked};
+use rooibos_reactive::graph::wrappers::read::Signal;
+use rooibos_reactive::{IntoSignal, StateProp, col, use_state_prop, wgt};
+use rooibos_theme::Style;
+
+#[cfg(feature = "input")]
+use crate::Input;
+#[cfg(feature = "input")]
+use crate::input::InputRole;
+use crate::{BorderPropertiesBorderTypeExt, ColorThemeColorTheme, VirtualListState};
+
+/// Dropdown lists are mounted as their own roots at this z-index so they're drawn on top of the
+/// rest of the app, but below any [`Dialog`](crate::Dialog).
+pub const DROPDOWN_Z_INDEX: i32 = 50;
+
+type LabelFn<T> = dyn Fn(&T) -> String + Send + Sync;
+
+type RowFn = dyn Fn(usize) -> ListItem<'static> + Send + Sync;
+
+/// Open state and placement of a dropdown list.
+#[derive(Clone, Copy)]
+struct Dropdown {
+    open: RwSignal<bool>,
+    anchor: RwSignal<Rect>,
+    viewport: RwSignal<Rect>,
+    list: VirtualListState,
+}
+
+impl Dropdown {
+    fn new() -> Self {
+        Self {
+            open: RwSignal::new(false),
+            anchor: RwSignal::new(Rect::default()),
+            viewport: RwSignal::new(Rect::default()),
+            list: VirtualListState::new(),
+        }
+    }
+
+    fn is_open(&self) -> bool {
+        self.open.get_untracked()
+    }
+
+    /// Opens the list below (or above, if there's no room) the given rect.
+    fn open(&self, anchor: Rect) {
+        if self.anchor.get_untracked() != anchor {
+            self.anchor.set(anchor);
+        }
+        if !self.open.get_untracked() {
+            self.open.set(true);
+        }
+    }
+
+    fn close(&self) {
+        if self.open.get_untracked() {
+            self.open.set(false);
+        }
+    }
+
+    /// Handles keys that move the highlighted row while the list is open.
+    fn handle_navigation(&self, props: &mut KeyEventProps) -> bool {
+        if !props.event.modifiers.is_empty() {
+            return false;
+        }
+        match props.event.code {
+            KeyCode::Down => self.list.select_next(),
+            KeyCode::Up => self.list.select_previous(),
+            KeyCode::PageDown => self.list.page_down(),
+            KeyCode::PageUp => self.list.page_up(),
+            KeyCode::Home => self.list.select_first(),
+            KeyCode::End => self.list.select_last(),
+            _ => return false,
+        }
+        props.handle.stop_propagation();
+        true
+    }
+
+    /// Calculates the position and size of the list relative to the viewport. The list is shown
+    /// below the anchor unless it only fits above it.
+    fn area(&self, item_count: usize, max_visible: u16) -> Rect {
+        let anchor = self.anchor.get();
+        let viewport = self.viewport.get();
+        let rows = (item_count.max(1) as u16).min(max_visible);
+        // Add space for the borders
+        let list_height = rows.saturating_add(2);
+
+        let space_below = viewport.bottom().saturating_sub(anchor.bottom());
+        let space_above = anchor.y.saturating_sub(viewport.y);
+        let y = if space_below < list_height && space_above >= list_height {
+            anchor.y - list_height
+        } else {
+            anchor.bottom()
+        };
+        let list_height = list_height.min(viewport.bottom().saturating_sub(y));
+        let x = anchor.x.min(viewport.right().saturating_sub(anchor.width));
+        Rect {
+            x: x.saturating_sub(viewport.x),
+            y: y.saturating_sub(viewport.y),
+            width: anchor.width.min(viewport.width),
+            height: list_height,
+        }
+    }
+
+    fn render(
+        self,
+        item_count: Signal<usize>,
+        max_visible: Signal<u16>,
+        highlight_style: Signal<Style>,
+        row: Arc<RowFn>,
+        on_pick: Rc<RefCell<dyn FnMut(usize)>>,
+    ) -> impl Render {
+        let Self {
+            open,
+            viewport,
+            list,
+            ..
+        } = self;
+        let visible_rows = list.visible_rows(item_count, |_| 1);
+        let area = Memo::new(move |_| self.area(item_count.get(), max_visible.get()));
+
+        let list_state = move || {
+            let visible = visible_rows.get();
+            ListState::default().with_selected(
+                list.selected()
+                    .get()
+                    .filter(|s| visible.contains(s))
+                    .map(|s| s - visible.start),
+            )
+        };
+
+        let block = move || {
+            Block::bordered()
+                .border_set(BorderType::primary().get().into())
+                .border_style(Style::default().fg_border_focused().get())
+        };
+
+        col![
+            style(
+                z_index(DROPDOWN_Z_INDEX),
+                width(full()),
+                height(full()),
+                show(open),
+                focusable(false),
+            ),
+            wgt!(
+                style(
+                    margin_left(chars(move || area.get().x as u32)),
+                    margin_top(chars(move || area.get().y as u32)),
+                    width(chars(move || area.get().width as u32)),
+                    height(chars(move || area.get().height as u32)),
+                    clear(true),
+                    focusable(false),
+                ),
+                list_state(),
+                OptionList(
+                    List::new(visible_rows.get().map(|i| row(i)))
+                        .highlight_style(highlight_style.get())
+                        .block(block())
+                )
+            )
+            .on_size_change(move |rect: Rect| {
+                list.set_viewport_height(rect.height.saturating_sub(2));
+            })
+            .on_click(move |props: ClickEventProps| {
+                props.handle.stop_propagation();
+                let Some(line) = props.event.row.checked_sub(props.data.rect.y + 1) else {
+                    return;
+                };
+                if let Some(clicked) = list.row_at(visible_rows.get_untracked(), line, |_| 1) {
+                    list.select(Some(clicked));
+                    on_pick.borrow_mut()(clicked);
+                }
+            })
+        ]
+        .on_size_change(move |rect: Rect| {
+            if viewport.get_untracked() != rect {
+                viewport.set(rect);
+            }
+        })
+        // Clicking anywhere outside of the list closes it
+        .on_click(move |props: ClickEventProps| {
+            if props.data.is_direct {
+                self.close();
+            }
+        })
+    }
+}
+
+fn default_borders() -> StateProp<Borders> {
+    StateProp::new(
+        (|| {
+            Borders::all()
+                .border_type(BorderType::primary().get())
+                .fg_border()
+                .get()
+        })
+        .signal(),
+    )
+    .focused(|b: Borders| b.fg_border_focused().get())
+    .hovered(move |b: Borders| b.border_type(BorderType::hovered().get()))
+    .disabled(move |b: Borders| {
+        b.border_type(BorderType::disabled().get())
+            .fg_disabled_dark()
+            .get()
+    })
+}
+
+/// A dropdown that shows the selected value and opens a list of options to pick from.
+///
+/// The list opens with <kbd>Enter</kbd>, <kbd>Space</kbd>, <kbd>Down</kbd> or a mouse click.
+/// Typing a character jumps to the next option that starts with it.
+pub struct Select<T>
+where
+    T: Send + Sync + 'static,
+{
+    value: Option<RwSignal<Option<T>>>,
+    on_change: Rc<RefCell<dyn FnMut(T)>>,
+    placeholder: Signal<String>,
+    style: Signal<Style>,
+    placeholder_style: Signal<Style>,
+    highlight_style: Signal<Style>,
+    borders: StateProp<Borders>,
+    max_visible: Signal<u16>,
+    layout_props: LayoutProps,
+}
+
+impl<T> Default for Select<T>
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> UpdateLayoutProps for Select<T>
+where
+    T: Send + Sync + 'static,
+{
+    fn layout_props(&self) -> LayoutProps {
+        self.layout_props.clone()
+    }
+
+    fn update_props(mut self, props: LayoutProps) -> Self {
+        self.layout_props = props;
+        self
+    }
+}
+
+impl<T> Select<T>
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    pub fn new() -> Self {
+        Self {
+            value: None,
+            on_change: Rc::new(RefCell::new(|_| {})),
+            placeholder: String::new().into(),
+            style: Style::default().into(),
+            placeholder_style: Style::default().fg_disabled_dark(),
+            highlight_style: Style::default().reversed().into(),
+            borders: default_borders(),
+            max_visible: 8.into(),
+            layout_props: LayoutProps::default(),
+        }
+    }
+
+    /// Signal holding the selected value. This is updated when the user picks an option and can
+    /// be set to change the selection programmatically.
+    pub fn value(mut self, value: RwSignal<Option<T>>) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    pub fn on_change(mut self, on_change: impl FnMut(T) + 'static) -> Self {
+        self.on_change = Rc::new(RefCell::new(on_change));
+        self
+    }
+
+    /// Text shown when nothing is selected.
+    pub fn placeholder<M>(
+        mut self,
+        placeholder: impl IntoReactiveValue<Signal<String>, M>,
+    ) -> Self {
+        self.placeholder = placeholder.into_reactive_value();
+        self
+    }
+
+    pub fn style<M>(mut self, style: impl IntoReactiveValue<Signal<Style>, M>) -> Self {
+        self.style = style.into_reactive_value();
+        self
+    }
+
+    pub fn placeholder_style<M>(
+        mut self,
+        placeholder_style: impl IntoReactiveValue<Signal<Style>, M>,
+    ) -> Self {
+        self.placeholder_style = placeholder_style.into_reactive_value();
+        self
+    }
+
+    pub fn highlight_style<M>(
+        mut self,
+        highlight_style: impl IntoReactiveValue<Signal<Style>, M>,
+    ) -> Self {
+        self.highlight_style = highlight_style.into_reactive_value();
+        self
+    }
+
+    pub fn borders(mut self, borders: StateProp<Borders>) -> Self {
+        self.borders = borders;
+        self
+    }
+
+    /// Maximum number of options shown at once before the list starts scrolling.
+    pub fn max_visible<M>(mut self, max_visible: impl IntoReactiveValue<Signal<u16>, M>) -> Self {
+        self.max_visible = max_visible.into_reactive_value();
+        self
+    }
+
+    /// Renders a dropdown with the given options. `label` is used to display each option.
+    pub fn render<M, F>(
+        self,
+        items: impl IntoReactiveValue<Signal<Vec<T>>, M>,
+        label: F,
+    ) -> impl Render
+    where
+        F: Fn(&T) -> String + Send + Sync + 'static,
+    {
+        let Self {
+            value,
+            on_change,
+            placeholder,
+            style,
+            placeholder_style,
+            highlight_style,
+            borders: select_borders,
+            max_visible,
+            layout_props,
+        } = self;
+        let items: Signal<Vec<T>> = items.into_reactive_value();
+        let value = value.unwrap_or_else(|| RwSignal::new(None));
+        let label: Arc<LabelFn<T>> = Arc::new(label);
+        let dropdown = Dropdown::new();
+        let (select_borders, set_border_state) = use_state_prop(select_borders);
+
+        let pick = move |index: usize| {
+            let Some(item) = items.with_untracked(|items| items.get(index).cloned()) else {
+                return;
+            };
+            dropdown.close();
+            value.set(Some(item.clone()));
+            on_change.borrow_mut()(item);
+        };
+        let pick: Rc<RefCell<dyn FnMut(usize)>> = Rc::new(RefCell::new(pick));
+
+        let open = move |anchor: Rect| {
+            // Start from the current value so it's in view when the list opens
+            let current = value.with_untracked(|value| {
+                value.as_ref().and_then(|value| {
+                    items.with_untracked(|items| items.iter().position(|i| i == value))
+                })
+            });
+            dropdown.list.select(current.or(Some(0)));
+            dropdown.open(anchor);
+        };
+
+        let type_ahead = {
+            let label = label.clone();
+            move |c: char| {
+                let c = c.to_lowercase().to_string();
+                let start = dropdown
+                    .list
+                    .selected()
+                    .get_untracked()
+                    .map_or(0, |s| s + 1);
+                let found = items.with_untracked(|items| {
+                    let len = items.len();
+                    (0..len)
+                        .map(|i| (start + i) % len)
+                        .find(|&i| label(&items[i]).to_lowercase().starts_with(&c))
+                });
+                if found.is_some() {
+                    dropdown.list.select(found);
+                }
+                found
+            }
+        };
+
+        let row: Arc<RowFn> = {
+            let label = label.clone();
+            Arc::new(move |i| {
+                let text = items.with(|items| items.get(i).map(|item| label(item)));
+                ListItem::new(text.unwrap_or_default())
+            })
+        };
+
+        let collapsed = move || {
+            let text = value.with(|value| value.as_ref().map(|value| label(value)));
+            let content = match text {
+                Some(text) => Span::styled(text, style.get()),
+                None => Span::styled(placeholder.get(), placeholder_style.get()),
+            };
+            let indicator = if dropdown.open.get() { " ▲" } else { " ▼" };
+            SelectWidget(Line::from(vec![
+                content,
+                Span::styled(indicator, style.get()),
+            ]))
+        };
+
+        let on_key_down = {
+            let pick = pick.clone();
+            move |mut props: KeyEventProps| {
+                let code = props.event.code;
+                if dropdown.is_open() {
+                    if dropdown.handle_navigation(&mut props) {
+                        return;
+                    }
+                    match code {
+                        KeyCode::Enter | KeyCode::Char(' ') => {
+                            if let Some(selected) = dropdown.list.selected().get_untracked() {
+                                pick.borrow_mut()(selected);
+                            }
+                        }
+                        KeyCode::Esc => dropdown.close(),
+                        KeyCode::Char(c) if !has_command_modifiers(props.event.modifiers) => {
+                            type_ahead(c);
+                        }
+                        _ => return,
+                    }
+                    props.handle.stop_propagation();
+                    return;
+                }
+                if !props.event.modifiers.is_empty() && !matches!(code, KeyCode::Char(_)) {
+                    return;
+                }
+                match code {
+                    KeyCode::Enter | KeyCode::Char(' ') | KeyCode::Down => open(props.data.rect),
+                    // Type-ahead selects the option directly while the list is closed
+                    KeyCode::Char(c) if !has_command_modifiers(props.event.modifiers) => {
+                        match type_ahead(c) {
+                            Some(found) => pick.borrow_mut()(found),
+                            None => return,
+                        }
+                    }
+                    _ => return,
+                }
+                props.handle.stop_propagation();
+            }
+        };
+
+        col![
+            wgt!(style(borders(select_borders)), collapsed())
+                .layout_props(layout_props)
+                .on_state_change(set_border_state)
+                .on_key_down(on_key_down)
+                .on_click(move |props: ClickEventProps| {
+                    if dropdown.is_open() {
+                        dropdown.close();
+                    } else {
+                        open(props.data.rect);
+                    }
+                })
+                .on_direct_blur(move |_, _, _| dropdown.close()),
+            dropdown.render(
+                (move || items.with(|items| items.len())).signal(),
+                max_visible,
+                highlight_style,
+                row,
+                pick,
+            )
+        ]
+    }
+}
+
+/// A text input with a dropdown list of options that's filtered as the user types.
+///
+/// Options are matched case-insensitively against any part of their label.
+/// <kbd>Up</kbd>/<kbd>Down</kbd> move through the matches and <kbd>Enter</kbd> picks one.
+#[cfg(feature = "input")]
+pub struct ComboBox<T>
+where
+    T: Send + Sync + 'static,
+{
+    value: Option<RwSignal<Option<T>>>,
+    on_change: Rc<RefCell<dyn FnMut(T)>>,
+    placeholder: Signal<String>,
+    highlight_style: Signal<Style>,
+    max_visible: Signal<u16>,
+    layout_props: LayoutProps,
+}
+
+#[cfg(feature = "input")]
+impl<T> Default for ComboBox<T>
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "input")]
+impl<T> UpdateLayoutProps for ComboBox<T>
+where
+    T: Send + Sync + 'static,
+{
+    fn layout_props(&self) -> LayoutProps {
+        self.layout_props.clone()
+    }
+
+    fn update_props(mut self, props: LayoutProps) -> Self {
+        self.layout_props = props;
+        self
+    }
+}
+
+#[cfg(feature = "input")]
+impl<T> ComboBox<T>
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    pub fn new() -> Self {
+        Self {
+            value: None,
+            on_change: Rc::new(RefCell::new(|_| {})),
+            placeholder: String::new().into(),
+            highlight_style: Style::default().reversed().into(),
+            max_visible: 8.into(),
+            layout_props: LayoutProps::default(),
+        }
+    }
+
+    /// Signal holding the selected value. This is updated when the user picks an option.
+    pub fn value(mut self, value: RwSignal<Option<T>>) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    pub fn on_change(mut self, on_change: impl FnMut(T) + 'static) -> Self {
+        self.on_change = Rc::new(RefCell::new(on_change));
+        self
+    }
+
+    pub fn placeholder<M>(
+        mut self,
+        placeholder: impl IntoReactiveValue<Signal<String>, M>,
+    ) -> Self {
+        self.placeholder = placeholder.into_reactive_value();
+        self
+    }
+
+    pub fn highlight_style<M>(
+        mut self,
+        highlight_style: impl IntoReactiveValue<Signal<Style>, M>,
+    ) -> Self {
+        self.highlight_style = highlight_style.into_reactive_value();
+        self
+    }
+
+    /// Maximum number of options shown at once before the list starts scrolling.
+    pub fn max_visible<M>(mut self, max_visible: impl IntoReactiveValue<Signal<u16>, M>) -> Self {
+        self.max_visible = max_visible.into_reactive_value();
+        self
+    }
+
+    /// Renders an input with the given options. `label` is used to display and filter each
+    /// option.
+    pub fn render<M, F>(
+        self,
+        items: impl IntoReactiveValue<Signal<Vec<T>>, M>,
+        label: F,
+    ) -> impl Render
+    where
+        F: Fn(&T) -> String + Send + Sync + 'static,
+    {
+        let Self {
+            value,
+            on_change,
+            placeholder,
+            highlight_style,
+            max_visible,
+            layout_props,
+        } = self;
+        let items: Signal<Vec<T>> = items.into_reactive_value();
+        let value = value.unwrap_or_else(|| RwSignal::new(None));
+        let label: Arc<LabelFn<T>> = Arc::new(label);
+        let dropdown = Dropdown::new();
+        let input_ref = Input::get_ref();
+        // The filter is only applied after the user starts typing so all options are shown
+        // after picking one
+        let filtering = RwSignal::new(false);
+
+        let matches = {
+            let label = label.clone();
+            Memo::new(move |_| {
+                let filter = input_ref.text().get().to_lowercase();
+                items.with(|items| {
+                    items
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, item)| {
+                            !filtering.get() || label(item).to_lowercase().contains(&filter)
+                        })
+                        .map(|(i, _)| i)
+                        .collect::<Vec<_>>()
+                })
+            })
+        };
+
+        let pick = {
+            let label = label.clone();
+            move |index: usize| {
+                let Some(item) = matches
+                    .with_untracked(|m| m.get(index).copied())
+                    .and_then(|i| items.with_untracked(|items| items.get(i).cloned()))
+                else {
+                    return;
+                };
+                dropdown.close();
+                filtering.set(false);
+                input_ref.set_text(label(&item));
+                value.set(Some(item.clone()));
+                on_change.borrow_mut()(item);
+            }
+        };
+        let pick: Rc<RefCell<dyn FnMut(usize)>> = Rc::new(RefCell::new(pick));
+
+        let row: Arc<RowFn> = Arc::new(move |i| {
+            let text = matches
+                .with(|m| m.get(i).copied())
+                .and_then(|i| items.with(|items| items.get(i).map(|item| label(item))));
+            ListItem::new(text.unwrap_or_default())
+        });
+
+        let on_key_down = {
+            let pick = pick.clone();
+            move |mut props: KeyEventProps| {
+                let code = props.event.code;
+                if dropdown.is_open() {
+                    if matches!(code, KeyCode::Home | KeyCode::End) {
+                        // Let the input handle these so the cursor can still be moved
+                        return;
+                    }
+                    if dropdown.handle_navigation(&mut props) {
+                        return;
+                    }
+                    match code {
+                        KeyCode::Enter => {
+                            if let Some(selected) = dropdown.list.selected().get_untracked() {
+                                pick.borrow_mut()(selected);
+                            }
+                        }
+                        KeyCode::Esc => dropdown.close(),
+                        _ => {
+                            if matches!(code, KeyCode::Char(_) | KeyCode::Backspace) {
+                                filtering.set(true);
+                                dropdown.list.select(Some(0));
+                            }
+                            return;
+                        }
+                    }
+                    props.handle.stop_propagation();
+                    return;
+                }
+                match code {
+                    KeyCode::Down if props.event.modifiers.is_empty() => {
+                        props.handle.stop_propagation();
+                    }
+                    KeyCode::Char(_) | KeyCode::Backspace
+                        if !has_command_modifiers(props.event.modifiers) =>
+                    {
+                        filtering.set(true);
+                    }
+                    _ => return,
+                }
+                dropdown.list.select(Some(0));
+                dropdown.open(props.data.rect);
+            }
+        };
+
+        col![
+            Input::default()
+                .placeholder_text(placeholder)
+                .update_props(layout_props)
+                .on_key_down(on_key_down)
+                .on_direct_blur(move |_, _, _| dropdown.close())
+                .render_with_role::<ComboBoxRole>(input_ref),
+            dropdown.render(
+                (move || matches.with(|m| m.len())).signal(),
+                max_visible,
+                highlight_style,
+                row,
+                pick,
+            )
+        ]
+    }
+}
+
+fn has_command_modifiers(modifiers: KeyModifiers) -> bool {
+    modifiers.intersects(KeyModifiers::CTRL | KeyModifiers::ALT)
+}
+
+#[cfg(feature = "input")]
+struct ComboBoxRole;
+
+#[cfg(feature = "input")]
+impl InputRole for ComboBoxRole {
+    const ROLE: Role = Role::EditableComboBox;
+}
+
+struct SelectWidget(Line<'static>);
+
+impl WidgetRole for SelectWidget {
+    fn widget_role() -> Option<Role> {
+        Some(Role::ComboBox)
+    }
+}
+
+impl Widget for &SelectWidget {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self.0).render(area, buf);
+    }
+}
+
+impl MeasureNode for SelectWidget {
+    fn measure(
+        &self,
+        known_dimensions: taffy::Size<Option<f32>>,
+        available_space: taffy::Size<taffy::AvailableSpace>,
+        style: &taffy::Style,
+    ) -> taffy::Size<f32> {
+        self.0.measure(known_dimensions, available_space, style)
+    }
+
+    fn estimate_size(&self) -> taffy::Size<f32> {
+        self.0.estimate_size()
+    }
+}
+
+#[derive(Clone)]
+struct OptionList(List<'static>);
+
+impl WidgetRole for OptionList {
+    fn widget_role() -> Option<Role> {
+        Some(Role::ListBox)
+    }
+}
+
+impl StatefulWidget for OptionList {
+    type State = ListState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(self.0, area, buf, state);
+    }
+}
+
+impl MeasureNode for OptionList {
+    fn measure(
+        &self,
+        known_dimensions: taffy::Size<Option<f32>>,
+        available_space: taffy::Size<taffy::AvailableSpace>,
+        style: &taffy::Style,
+    ) -> taffy::Size<f32> {
+        self.0.measure(known_dimensions, available_space, style)
+    }
+
+    fn estimate_size(&self) -> taffy::Size<f32> {
+        self.0.estimate_size()
+    }
+}
@@ -113,7 +113,7 @@ impl Spinner {
                         let child_token = child_token.clone();
                         async move {
                             loop {
-                                if wasm_compat::futures::sleep(tick_interval)
+                                if rooibos_dom::clock::sleep(tick_interval)
                                     .with_cancellation_token(&child_token)
                                     .await
                                     .is_none()
@@ -0,0 +1,780 @@
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use ratatui::layout::{Constraint, Flex, Layout, Position, Rect};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, Cell, HighlightSpacing, Row, Table, TableState};
+use rooibos_dom::events::{
+    BlurEvent, ClickEventProps, DragEventProps, EventData, EventHandle, FocusEvent, KeyEventProps,
+    KeyHandler,
+};
+use rooibos_dom::{KeyCode, KeyModifiers, MouseButton};
+use rooibos_reactive::dom::{LayoutProps, Render, UpdateLayoutProps};
+use rooibos_reactive::graph::IntoReactiveValue;
+use rooibos_reactive::graph::computed::Memo;
+use rooibos_reactive::graph::effect::Effect;
+use rooibos_reactive::graph::owner::StoredValue;
+use rooibos_reactive::graph::signal::RwSignal;
+use rooibos_reactive::graph::traits::{
+    Get, GetUntracked, GetValue, Set, SetValue, Track, Update, With, WithUntracked,
+};
+use rooibos_reactive::graph::wrappers::read::Signal;
+use rooibos_reactive::wgt;
+use rooibos_theme::Style;
+
+use crate::VirtualListState;
+
+type CellFn<T> = dyn Fn(&T) -> Cell<'static> + Send + Sync;
+type CompareFn<T> = dyn Fn(&T, &T) -> Ordering + Send + Sync;
+
+/// Definition of a single column in a [`TableView`].
+pub struct Column<T> {
+    title: Text<'static>,
+    width: Constraint,
+    cell: Arc<CellFn<T>>,
+    compare: Option<Arc<CompareFn<T>>>,
+}
+
+impl<T> Clone for Column<T> {
+    fn clone(&self) -> Self {
+        Self {
+            title: self.title.clone(),
+            width: self.width,
+            cell: self.cell.clone(),
+            compare: self.compare.clone(),
+        }
+    }
+}
+
+impl<T> Column<T> {
+    pub fn new<F>(title: impl Into<Text<'static>>, cell: F) -> Self
+    where
+        F: Fn(&T) -> Cell<'static> + Send + Sync + 'static,
+    {
+        Self {
+            title: title.into(),
+            width: Constraint::Fill(1),
+            cell: Arc::new(cell),
+            compare: None,
+        }
+    }
+
+    pub fn width(mut self, width: Constraint) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Makes the column sortable by clicking on its header.
+    pub fn sort_by<F>(mut self, compare: F) -> Self
+    where
+        F: Fn(&T, &T) -> Ordering + Send + Sync + 'static,
+    {
+        self.compare = Some(Arc::new(compare));
+        self
+    }
+
+    /// Makes the column sortable by clicking on its header.
+    pub fn sort_by_key<K, F>(self, key: F) -> Self
+    where
+        K: Ord,
+        F: Fn(&T) -> K + Send + Sync + 'static,
+    {
+        self.sort_by(move |a, b| key(a).cmp(&key(b)))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TableSort {
+    pub column: usize,
+    pub direction: SortDirection,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelectionMode {
+    #[default]
+    Single,
+    Multi,
+}
+
+/// Sorting, selection, and column width state for a [`TableView`].
+///
+/// Selected rows are stored as indices into the original items, so the selection is preserved
+/// when the table is re-sorted.
+#[derive(Clone, Copy)]
+pub struct TableViewState {
+    cursor: VirtualListState,
+    selected: RwSignal<BTreeSet<usize>>,
+    anchor: RwSignal<Option<usize>>,
+    sort: RwSignal<Option<TableSort>>,
+    column_widths: RwSignal<Vec<Option<u16>>>,
+    // Set when the table is rendered. Reading the memo directly ensures the order always matches
+    // the current items.
+    row_order: StoredValue<Option<Memo<Vec<usize>>>>,
+}
+
+impl Default for TableViewState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TableViewState {
+    pub fn new() -> Self {
+        Self {
+            cursor: VirtualListState::new(),
+            selected: RwSignal::new(BTreeSet::new()),
+            anchor: RwSignal::new(None),
+            sort: RwSignal::new(None),
+            column_widths: RwSignal::new(Vec::new()),
+            row_order: StoredValue::new(None),
+        }
+    }
+
+    /// Indices of the selected items.
+    pub fn selected(&self) -> Signal<BTreeSet<usize>> {
+        self.selected.into()
+    }
+
+    /// Position of the highlighted row, relative to the current sort order.
+    pub fn cursor(&self) -> Signal<Option<usize>> {
+        self.cursor.selected()
+    }
+
+    pub fn sort(&self) -> Signal<Option<TableSort>> {
+        self.sort.into()
+    }
+
+    pub fn set_sort(&self, sort: Option<TableSort>) {
+        self.sort.set(sort);
+    }
+
+    /// Width of each column, if it's been resized with the mouse.
+    pub fn column_widths(&self) -> Signal<Vec<Option<u16>>> {
+        self.column_widths.into()
+    }
+
+    pub fn set_column_width(&self, column: usize, width: Option<u16>) {
+        self.column_widths.update(|widths| {
+            if widths.len() <= column {
+                widths.resize(column + 1, None);
+            }
+            widths[column] = width;
+        });
+    }
+
+    pub fn select(&self, item: usize) {
+        self.selected.set(BTreeSet::from([item]));
+        self.anchor.set(Some(item));
+    }
+
+    pub fn toggle_selected(&self, item: usize) {
+        self.selected.update(|selected| {
+            if !selected.remove(&item) {
+                selected.insert(item);
+            }
+        });
+        self.anchor.set(Some(item));
+    }
+
+    pub fn select_all(&self) {
+        let all = self.with_row_order(|r| r.iter().copied().collect());
+        self.selected.set(all);
+    }
+
+    pub fn clear_selection(&self) {
+        self.selected.set(BTreeSet::new());
+        self.anchor.set(None);
+    }
+
+    fn with_row_order<R>(&self, f: impl FnOnce(&[usize]) -> R) -> R {
+        match self.row_order.get_value() {
+            Some(row_order) => row_order.with_untracked(|r| f(r)),
+            None => f(&[]),
+        }
+    }
+
+    fn item_at(&self, row: usize) -> Option<usize> {
+        self.with_row_order(|r| r.get(row).copied())
+    }
+
+    fn row_of(&self, item: usize) -> Option<usize> {
+        self.with_row_order(|r| r.iter().position(|i| *i == item))
+    }
+
+    /// Selects every row between the anchor row and `row`, in display order.
+    fn select_range(&self, row: usize) {
+        let anchor_row = self
+            .anchor
+            .get_untracked()
+            .and_then(|a| self.row_of(a))
+            .unwrap_or(row);
+        let selected = self.with_row_order(|r| {
+            // The row may be out of date if the number of items changed since it was set
+            let last = r.len().checked_sub(1)?;
+            let end = anchor_row.max(row).min(last);
+            let start = anchor_row.min(row).min(end);
+            Some(r[start..=end].iter().copied().collect())
+        });
+        if let Some(selected) = selected {
+            self.selected.set(selected);
+        }
+    }
+
+    fn on_cursor_moved(&self, mode: SelectionMode, extend: bool) {
+        let Some(item) = self
+            .cursor
+            .selected()
+            .get_untracked()
+            .and_then(|row| self.item_at(row))
+        else {
+            return;
+        };
+        match (mode, extend) {
+            (SelectionMode::Multi, true) => {
+                if let Some(row) = self.cursor.selected().get_untracked() {
+                    self.select_range(row);
+                }
+            }
+            (SelectionMode::Multi, false) => self.anchor.set(Some(item)),
+            (SelectionMode::Single, _) => self.select(item),
+        }
+    }
+
+    fn handle_key(&self, mode: SelectionMode, props: &mut KeyEventProps) {
+        let prev_cursor = self.cursor.selected().get_untracked();
+        match (props.event.code, props.event.modifiers) {
+            (KeyCode::Char(' '), modifiers)
+                if modifiers.is_empty() && mode == SelectionMode::Multi =>
+            {
+                if let Some(item) = prev_cursor.and_then(|row| self.item_at(row)) {
+                    self.toggle_selected(item);
+                    props.handle.stop_propagation();
+                }
+            }
+            (KeyCode::Char('a'), KeyModifiers::CTRL) if mode == SelectionMode::Multi => {
+                self.select_all();
+                props.handle.stop_propagation();
+            }
+            (KeyCode::Up | KeyCode::Down, KeyModifiers::SHIFT) if mode == SelectionMode::Multi => {
+                if prev_cursor.is_none() {
+                    self.cursor.select_first();
+                } else if props.event.code == KeyCode::Up {
+                    self.cursor.select_previous();
+                } else {
+                    self.cursor.select_next();
+                }
+                self.on_cursor_moved(mode, true);
+                props.handle.stop_propagation();
+            }
+            _ => {
+                self.cursor.handle_key(props);
+                if self.cursor.selected().get_untracked() != prev_cursor {
+                    self.on_cursor_moved(mode, false);
+                }
+            }
+        }
+    }
+
+    fn toggle_sort(&self, column: usize) {
+        self.sort.update(|sort| {
+            *sort = match sort {
+                Some(TableSort {
+                    column: prev,
+                    direction: SortDirection::Ascending,
+                }) if *prev == column => Some(TableSort {
+                    column,
+                    direction: SortDirection::Descending,
+                }),
+                Some(TableSort {
+                    column: prev,
+                    direction: SortDirection::Descending,
+                }) if *prev == column => None,
+                _ => Some(TableSort {
+                    column,
+                    direction: SortDirection::Ascending,
+                }),
+            }
+        });
+    }
+}
+
+#[derive(Clone, Copy)]
+struct ResizeState {
+    start: Position,
+    column: usize,
+    start_width: u16,
+}
+
+pub struct TableView<T> {
+    columns: Vec<Column<T>>,
+    style: Signal<Style>,
+    header_style: Signal<Style>,
+    row_highlight_style: Signal<Style>,
+    selected_style: Signal<Style>,
+    block: Option<Signal<Block<'static>>>,
+    column_spacing: Signal<u16>,
+    highlight_spacing: Signal<HighlightSpacing>,
+    highlight_symbol: Option<Signal<&'static str>>,
+    selection_mode: Signal<SelectionMode>,
+    sort_indicators: Signal<(&'static str, &'static str)>,
+    on_row_click: Box<dyn FnMut(usize, T)>,
+    on_key_down: Box<dyn KeyHandler>,
+    on_direct_focus: Box<dyn FnMut(FocusEvent, EventData, EventHandle)>,
+    on_direct_blur: Box<dyn FnMut(BlurEvent, EventData, EventHandle)>,
+    state: Option<TableViewState>,
+    layout_props: LayoutProps,
+}
+
+impl<T> Default for TableView<T> {
+    fn default() -> Self {
+        Self {
+            columns: Vec::new(),
+            style: Default::default(),
+            header_style: Style::default().bold().into(),
+            row_highlight_style: Style::default().reversed().into(),
+            selected_style: Style::default().bold().into(),
+            block: Default::default(),
+            column_spacing: 1.into(),
+            highlight_spacing: Default::default(),
+            highlight_symbol: Default::default(),
+            selection_mode: Default::default(),
+            sort_indicators: (" ▲", " ▼").into(),
+            on_row_click: Box::new(move |_, _| {}),
+            on_key_down: Box::new(move |_| {}),
+            on_direct_focus: Box::new(move |_, _, _| {}),
+            on_direct_blur: Box::new(move |_, _, _| {}),
+            state: None,
+            layout_props: LayoutProps::default(),
+        }
+    }
+}
+
+impl<T> UpdateLayoutProps for TableView<T> {
+    fn layout_props(&self) -> LayoutProps {
+        self.layout_props.clone()
+    }
+
+    fn update_props(mut self, props: LayoutProps) -> Self {
+        self.layout_props = props;
+        self
+    }
+}
+
+impl<T> TableView<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn column(mut self, column: Column<T>) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    pub fn columns(mut self, columns: impl IntoIterator<Item = Column<T>>) -> Self {
+        self.columns.extend(columns);
+        self
+    }
+
+    pub fn state(mut self, state: TableViewState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    pub fn style<M>(mut self, style: impl IntoReactiveValue<Signal<Style>, M>) -> Self {
+        self.style = style.into_reactive_value();
+        self
+    }
+
+    pub fn header_style<M>(
+        mut self,
+        header_style: impl IntoReactiveValue<Signal<Style>, M>,
+    ) -> Self {
+        self.header_style = header_style.into_reactive_value();
+        self
+    }
+
+    /// Style of the row under the cursor.
+    pub fn row_highlight_style<M>(
+        mut self,
+        row_highlight_style: impl IntoReactiveValue<Signal<Style>, M>,
+    ) -> Self {
+        self.row_highlight_style = row_highlight_style.into_reactive_value();
+        self
+    }
+
+    /// Style of selected rows. Only used with [`SelectionMode::Multi`].
+    pub fn selected_style<M>(
+        mut self,
+        selected_style: impl IntoReactiveValue<Signal<Style>, M>,
+    ) -> Self {
+        self.selected_style = selected_style.into_reactive_value();
+        self
+    }
+
+    pub fn block<M>(mut self, block: impl IntoReactiveValue<Signal<Block<'static>>, M>) -> Self {
+        self.block = Some(block.into_reactive_value());
+        self
+    }
+
+    pub fn column_spacing<M>(
+        mut self,
+        column_spacing: impl IntoReactiveValue<Signal<u16>, M>,
+    ) -> Self {
+        self.column_spacing = column_spacing.into_reactive_value();
+        self
+    }
+
+    pub fn highlight_spacing<M>(
+        mut self,
+        highlight_spacing: impl IntoReactiveValue<Signal<HighlightSpacing>, M>,
+    ) -> Self {
+        self.highlight_spacing = highlight_spacing.into_reactive_value();
+        self
+    }
+
+    pub fn highlight_symbol<M>(
+        mut self,
+        highlight_symbol: impl IntoReactiveValue<Signal<&'static str>, M>,
+    ) -> Self {
+        self.highlight_symbol = Some(highlight_symbol.into_reactive_value());
+        self
+    }
+
+    pub fn selection_mode<M>(
+        mut self,
+        selection_mode: impl IntoReactiveValue<Signal<SelectionMode>, M>,
+    ) -> Self {
+        self.selection_mode = selection_mode.into_reactive_value();
+        self
+    }
+
+    /// Symbols appended to the title of the sorted column for ascending and descending order.
+    pub fn sort_indicators<M>(
+        mut self,
+        sort_indicators: impl IntoReactiveValue<Signal<(&'static str, &'static str)>, M>,
+    ) -> Self {
+        self.sort_indicators = sort_indicators.into_reactive_value();
+        self
+    }
+
+    pub fn on_row_click(mut self, on_row_click: impl FnMut(usize, T) + 'static) -> Self {
+        self.on_row_click = Box::new(on_row_click);
+        self
+    }
+
+    pub fn on_key_down(mut self, on_key_down: impl KeyHandler + 'static) -> Self {
+        self.on_key_down = Box::new(on_key_down);
+        self
+    }
+
+    pub fn on_direct_focus(
+        mut self,
+        on_direct_focus: impl FnMut(FocusEvent, EventData, EventHandle) + 'static,
+    ) -> Self {
+        self.on_direct_focus = Box::new(on_direct_focus);
+        self
+    }
+
+    pub fn on_direct_blur(
+        mut self,
+        on_blur: impl FnMut(BlurEvent, EventData, EventHandle) + 'static,
+    ) -> Self {
+        self.on_direct_blur = Box::new(on_blur);
+        self
+    }
+
+    pub fn render<M>(self, items: impl IntoReactiveValue<Signal<Vec<T>>, M>) -> impl Render
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        let Self {
+            columns,
+            style,
+            header_style,
+            row_highlight_style,
+            selected_style,
+            block,
+            column_spacing,
+            highlight_spacing,
+            highlight_symbol,
+            selection_mode,
+            sort_indicators,
+            mut on_row_click,
+            mut on_key_down,
+            on_direct_focus,
+            on_direct_blur,
+            state,
+            layout_props,
+        } = self;
+        let items: Signal<Vec<T>> = items.into_reactive_value();
+        let state = state.unwrap_or_default();
+        let columns = Arc::new(columns);
+
+        let row_order = Memo::new({
+            let columns = columns.clone();
+            move |_| {
+                items.with(|items| {
+                    let mut order: Vec<usize> = (0..items.len()).collect();
+                    if let Some(sort) = state.sort.get()
+                        && let Some(compare) =
+                            columns.get(sort.column).and_then(|c| c.compare.as_ref())
+                    {
+                        order.sort_by(|a, b| {
+                            let ordering = compare(&items[*a], &items[*b]);
+                            match sort.direction {
+                                SortDirection::Ascending => ordering,
+                                SortDirection::Descending => ordering.reverse(),
+                            }
+                        });
+                    }
+                    order
+                })
+            }
+        });
+
+        state.row_order.set_value(Some(row_order));
+
+        Effect::new(move || {
+            row_order.track();
+            // Keep the cursor on the same item when the sort order changes
+            if let Some(row) = state.anchor.get_untracked().and_then(|a| state.row_of(a)) {
+                state.cursor.select(Some(row));
+            }
+        });
+
+        let item_count = Memo::new(move |_| items.with(|i| i.len()));
+        let visible_rows = state.cursor.visible_rows(item_count.into(), |_| 1);
+
+        let widths = {
+            let columns = columns.clone();
+            move || {
+                state.column_widths.with(|overrides| {
+                    columns
+                        .iter()
+                        .enumerate()
+                        .map(|(i, c)| match overrides.get(i).copied().flatten() {
+                            Some(width) => Constraint::Length(width),
+                            None => c.width,
+                        })
+                        .collect::<Vec<_>>()
+                })
+            }
+        };
+
+        let inner_area = move |rect: Rect| match &block {
+            Some(block) => block.with_untracked(|b| b.inner(rect)),
+            None => rect,
+        };
+
+        // Mirrors the column layout calculation in ratatui's table widget so mouse events can be
+        // mapped to columns
+        let column_areas = {
+            let widths = widths.clone();
+            move |rect: Rect| {
+                let inner = inner_area(rect);
+                let has_selection = state.cursor.selected().get_untracked().is_some();
+                let selection_width = match highlight_spacing.get_untracked() {
+                    HighlightSpacing::Always => true,
+                    HighlightSpacing::WhenSelected => has_selection,
+                    HighlightSpacing::Never => false,
+                }
+                .then(|| highlight_symbol.map(|s| Span::raw(s.get_untracked()).width() as u16))
+                .flatten()
+                .unwrap_or_default();
+                let columns_area = Rect {
+                    x: inner.x.saturating_add(selection_width),
+                    width: inner.width.saturating_sub(selection_width),
+                    ..inner
+                };
+                Layout::horizontal(widths())
+                    .flex(Flex::Start)
+                    .spacing(column_spacing.get_untracked())
+                    .split(columns_area)
+            }
+        };
+
+        // The right edge of each column plus the spacing after it can be dragged to resize it
+        let resize_handle_at = {
+            let column_areas = column_areas.clone();
+            move |rect: Rect, column: u16| {
+                let spacing = column_spacing.get_untracked();
+                column_areas(rect).iter().position(|area| {
+                    let right = area.right();
+                    column + 1 >= right && column < right + spacing.max(1)
+                })
+            }
+        };
+
+        let table_state = move || {
+            let visible = visible_rows.get();
+            TableState::default().with_selected(
+                state
+                    .cursor
+                    .selected()
+                    .get()
+                    .filter(|s| visible.contains(s))
+                    .map(|s| s - visible.start),
+            )
+        };
+
+        let resize_state = StoredValue::new(None::<ResizeState>);
+
+        let render_columns = columns.clone();
+        wgt!(table_state(), {
+            let columns = &render_columns;
+            let (ascending, descending) = sort_indicators.get();
+            let sort = state.sort.get();
+            let header = Row::new(columns.iter().enumerate().map(|(i, column)| {
+                let mut title = column.title.clone();
+                if let Some(sort) = sort.filter(|s| s.column == i) {
+                    let indicator = match sort.direction {
+                        SortDirection::Ascending => ascending,
+                        SortDirection::Descending => descending,
+                    };
+                    match title.lines.last_mut() {
+                        Some(line) => line.push_span(indicator),
+                        None => title.lines.push(Line::from(indicator)),
+                    }
+                }
+                Cell::from(title)
+            }))
+            .style(header_style.get());
+
+            let multi = selection_mode.get() == SelectionMode::Multi;
+            let selected = state.selected.get();
+            let selected_style = selected_style.get();
+            let rows = items.with(|items| {
+                row_order.with(|order| {
+                    visible_rows
+                        .get()
+                        .filter_map(|row| {
+                            let item_index = *order.get(row)?;
+                            let item = items.get(item_index)?;
+                            let row = Row::new(columns.iter().map(|c| (c.cell)(item)));
+                            Some(if multi && selected.contains(&item_index) {
+                                row.style(selected_style)
+                            } else {
+                                row
+                            })
+                        })
+                        .collect::<Vec<_>>()
+                })
+            });
+
+            let mut table = Table::new(rows, widths())
+                .header(header)
+                .flex(Flex::Start)
+                .row_highlight_style(row_highlight_style.get())
+                .highlight_spacing(highlight_spacing.get())
+                .column_spacing(column_spacing.get())
+                .style(style.get());
+            if let Some(block) = &block {
+                table = table.block(block.get());
+            }
+            if let Some(highlight_symbol) = highlight_symbol {
+                table = table.highlight_symbol(highlight_symbol.get());
+            }
+            table
+        })
+        .on_size_change(move |rect: Rect| {
+            // Subtract one line for the header
+            state
+                .cursor
+                .set_viewport_height(inner_area(rect).height.saturating_sub(1));
+        })
+        .on_click({
+            let resize_handle_at = resize_handle_at.clone();
+            let column_areas = column_areas.clone();
+            let columns = columns.clone();
+            move |props: ClickEventProps| {
+                let rect = props.data.rect;
+                let inner = inner_area(rect);
+                if props.event.row < inner.y {
+                    return;
+                }
+                if props.event.row == inner.y {
+                    if resize_handle_at(rect, props.event.column).is_some() {
+                        return;
+                    }
+                    let clicked_column = column_areas(rect).iter().position(|area| {
+                        area.x <= props.event.column && props.event.column < area.right()
+                    });
+                    if let Some(column) = clicked_column
+                        && columns[column].compare.is_some()
+                    {
+                        state.toggle_sort(column);
+                    }
+                    return;
+                }
+
+                let line = props.event.row - inner.y - 1;
+                let Some(row) = state
+                    .cursor
+                    .row_at(visible_rows.get_untracked(), line, |_| 1)
+                else {
+                    return;
+                };
+                let Some(item_index) = state.item_at(row) else {
+                    return;
+                };
+                state.cursor.select(Some(row));
+                let modifiers = props.event.modifiers;
+                if selection_mode.get_untracked() == SelectionMode::Multi {
+                    if modifiers.contains(KeyModifiers::SHIFT) {
+                        state.select_range(row);
+                    } else if modifiers.contains(KeyModifiers::CTRL) {
+                        state.toggle_selected(item_index);
+                    } else {
+                        state.select(item_index);
+                    }
+                } else {
+                    state.select(item_index);
+                }
+                let item = items.with_untracked(|items| items[item_index].clone());
+                on_row_click(item_index, item);
+            }
+        })
+        .on_mouse_drag(move |props: DragEventProps| {
+            if props.event.button != MouseButton::Left {
+                return;
+            }
+            let rect = props.data.rect;
+            let start = Position::new(props.event.start_column, props.event.start_row);
+            let resize = match resize_state.get_value() {
+                Some(resize) if resize.start == start => resize,
+                _ => {
+                    if start.y != inner_area(rect).y {
+                        return;
+                    }
+                    let Some(column) = resize_handle_at(rect, start.x) else {
+                        return;
+                    };
+                    let resize = ResizeState {
+                        start,
+                        column,
+                        start_width: column_areas(rect)[column].width,
+                    };
+                    resize_state.set_value(Some(resize));
+                    resize
+                }
+            };
+            let delta = props.event.column as i32 - resize.start.x as i32;
+            let width = (resize.start_width as i32 + delta).clamp(1, u16::MAX as i32) as u16;
+            state.set_column_width(resize.column, Some(width));
+        })
+        .layout_props(layout_props)
+        .on_key_down(move |mut props: KeyEventProps| {
+            on_key_down.handle(props.clone());
+            state.handle_key(selection_mode.get_untracked(), &mut props);
+        })
+        .on_direct_focus(on_direct_focus)
+        .on_direct_blur(on_direct_blur)
+    }
+}
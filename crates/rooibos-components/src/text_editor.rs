@@ -0,0 +1,923 @@
+use std::collections::HashSet;
+use std::ops::Range;
+use std::sync::Arc;
+
+use ratatui::Frame;
+use ratatui::layout::{Position, Rect};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Widget;
+use ratatui_textarea::{CursorMove, TextArea};
+use rooibos_dom::events::{ClickEventProps, DragEventProps, KeyEventProps, KeyHandler};
+use rooibos_dom::widgets::{Role, WidgetRole};
+use rooibos_dom::wrap::{Options, wrap_line_with_offsets};
+use rooibos_dom::{
+    Event, KeyCode, KeyModifiers, MeasureNode, MouseButton, RenderNode, ScrollDirection,
+    set_editing,
+};
+use rooibos_reactive::IntoSignal;
+use rooibos_reactive::dom::div::taffy;
+use rooibos_reactive::dom::{DomWidget, LayoutProps, Render, UpdateLayoutProps};
+use rooibos_reactive::graph::IntoReactiveValue;
+use rooibos_reactive::graph::owner::{StoredValue, on_cleanup};
+use rooibos_reactive::graph::signal::{RwSignal, Trigger, signal};
+use rooibos_reactive::graph::traits::{
+    Get, GetValue, Notify, Set, SetValue, Track, Update, UpdateUntracked, UpdateValue, With,
+    WithUntracked, WithValue,
+};
+use rooibos_reactive::graph::wrappers::read::Signal;
+use rooibos_theme::Style;
+
+use crate::input::to_input;
+use crate::{ColorThemeColorTheme, SyntaxTheme};
+
+/// Produces styled text for a [`TextEditor`].
+pub trait Highlighter: Send + Sync {
+    /// Returns a styled line for each of the given lines. The text of each returned line must
+    /// match the text of the corresponding input line.
+    ///
+    /// This is called while rendering, so any signals read here (like theme colors) will cause
+    /// the editor to update when they change.
+    fn highlight(&self, lines: &[String]) -> Vec<Line<'static>>;
+}
+
+impl<F> Highlighter for F
+where
+    F: Fn(&str) -> Line<'static> + Send + Sync,
+{
+    fn highlight(&self, lines: &[String]) -> Vec<Line<'static>> {
+        lines.iter().map(|line| self(line)).collect()
+    }
+}
+
+/// A [`Highlighter`] that leaves text unstyled.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PlainText;
+
+impl Highlighter for PlainText {
+    fn highlight(&self, lines: &[String]) -> Vec<Line<'static>> {
+        lines.iter().map(|line| Line::raw(line.clone())).collect()
+    }
+}
+
+/// A simple [`Highlighter`] for keywords, numbers, string literals, and line comments.
+///
+/// Styles default to the colors from the current [`SyntaxTheme`].
+pub struct KeywordHighlighter {
+    keywords: HashSet<String>,
+    line_comment: Option<String>,
+    string_delimiters: Vec<char>,
+    keyword_style: Signal<Style>,
+    string_style: Signal<Style>,
+    number_style: Signal<Style>,
+    comment_style: Signal<Style>,
+}
+
+impl KeywordHighlighter {
+    pub fn new<I, S>(keywords: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            keywords: keywords.into_iter().map(Into::into).collect(),
+            line_comment: None,
+            string_delimiters: vec!['"'],
+            keyword_style: SyntaxTheme::keyword(),
+            string_style: SyntaxTheme::string(),
+            number_style: SyntaxTheme::number(),
+            comment_style: SyntaxTheme::comment(),
+        }
+    }
+
+    /// Prefix that starts a comment running to the end of the line, like `//` or `#`.
+    pub fn line_comment(mut self, line_comment: impl Into<String>) -> Self {
+        self.line_comment = Some(line_comment.into());
+        self
+    }
+
+    /// Characters that start and end a string literal. Defaults to `"`.
+    pub fn string_delimiters(mut self, string_delimiters: impl IntoIterator<Item = char>) -> Self {
+        self.string_delimiters = string_delimiters.into_iter().collect();
+        self
+    }
+
+    pub fn keyword_style<M>(
+        mut self,
+        keyword_style: impl IntoReactiveValue<Signal<Style>, M>,
+    ) -> Self {
+        self.keyword_style = keyword_style.into_reactive_value();
+        self
+    }
+
+    pub fn string_style<M>(
+        mut self,
+        string_style: impl IntoReactiveValue<Signal<Style>, M>,
+    ) -> Self {
+        self.string_style = string_style.into_reactive_value();
+        self
+    }
+
+    pub fn number_style<M>(
+        mut self,
+        number_style: impl IntoReactiveValue<Signal<Style>, M>,
+    ) -> Self {
+        self.number_style = number_style.into_reactive_value();
+        self
+    }
+
+    pub fn comment_style<M>(
+        mut self,
+        comment_style: impl IntoReactiveValue<Signal<Style>, M>,
+    ) -> Self {
+        self.comment_style = comment_style.into_reactive_value();
+        self
+    }
+
+    fn highlight_line(&self, line: &str, styles: &[Style; 4]) -> Line<'static> {
+        let [keyword_style, string_style, number_style, comment_style] = *styles;
+        let mut spans: Vec<Span<'static>> = Vec::new();
+        let mut push = |text: &str, style: Style| {
+            let style: ratatui::style::Style = style.into();
+            match spans.last_mut() {
+                Some(last) if last.style == style => last.content.to_mut().push_str(text),
+                _ => spans.push(Span::styled(text.to_string(), style)),
+            }
+        };
+
+        let mut rest = line;
+        while let Some(c) = rest.chars().next() {
+            if let Some(prefix) = &self.line_comment
+                && rest.starts_with(prefix.as_str())
+            {
+                push(rest, comment_style);
+                break;
+            }
+            let end = if self.string_delimiters.contains(&c) {
+                let mut escaped = false;
+                rest.char_indices()
+                    .skip(1)
+                    .find(|(_, ch)| {
+                        let found = !escaped && *ch == c;
+                        escaped = !escaped && *ch == '\\';
+                        found
+                    })
+                    .map_or(rest.len(), |(i, ch)| i + ch.len_utf8())
+            } else if is_word_char(c) {
+                rest.find(|ch| !is_word_char(ch)).unwrap_or(rest.len())
+            } else {
+                c.len_utf8()
+            };
+            let (token, remaining) = rest.split_at(end);
+            let style = if self.string_delimiters.contains(&c) {
+                string_style
+            } else if c.is_ascii_digit() {
+                number_style
+            } else if self.keywords.contains(token) {
+                keyword_style
+            } else {
+                Style::default()
+            };
+            push(token, style);
+            rest = remaining;
+        }
+        Line::from(spans)
+    }
+}
+
+impl Highlighter for KeywordHighlighter {
+    fn highlight(&self, lines: &[String]) -> Vec<Line<'static>> {
+        let styles = [
+            self.keyword_style.get(),
+            self.string_style.get(),
+            self.number_style.get(),
+            self.comment_style.get(),
+        ];
+        lines
+            .iter()
+            .map(|line| self.highlight_line(line, &styles))
+            .collect()
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+#[derive(Clone, Copy)]
+pub struct TextEditorRef {
+    text_area: RwSignal<TextArea<'static>>,
+}
+
+impl TextEditorRef {
+    /// The contents of the editor, with lines separated by `\n`.
+    pub fn text(&self) -> Signal<String> {
+        let text_area = self.text_area;
+        (move || text_area.with(|t| t.lines().join("\n"))).signal()
+    }
+
+    /// The cursor position as a `(row, column)` pair. The column is measured in characters.
+    pub fn cursor(&self) -> Signal<(usize, usize)> {
+        let text_area = self.text_area;
+        (move || text_area.with(|t| t.cursor())).signal()
+    }
+
+    /// The start and end positions of the selected text, if any.
+    pub fn selection(&self) -> Signal<Option<((usize, usize), (usize, usize))>> {
+        let text_area = self.text_area;
+        (move || text_area.with(|t| t.selection_range())).signal()
+    }
+
+    /// Replaces the contents of the editor. This also clears the undo history.
+    pub fn set_text(&self, text: impl AsRef<str>) {
+        let lines = split_lines(text.as_ref());
+        self.text_area.update(|t| {
+            let tab_length = t.tab_length();
+            *t = TextArea::new(lines);
+            t.set_tab_length(tab_length);
+        });
+    }
+
+    pub fn insert_str(&self, text: impl AsRef<str>) -> bool {
+        self.text_area.try_update(|t| t.insert_str(text)).unwrap()
+    }
+
+    pub fn undo(&self) -> bool {
+        self.text_area.try_update(|t| t.undo()).unwrap()
+    }
+
+    pub fn redo(&self) -> bool {
+        self.text_area.try_update(|t| t.redo()).unwrap()
+    }
+
+    pub fn select_all(&self) {
+        self.text_area.update(|t| t.select_all());
+    }
+
+    pub fn cancel_selection(&self) {
+        self.text_area.update(|t| t.cancel_selection());
+    }
+
+    pub fn copy(&self) {
+        self.text_area.update(|t| t.copy());
+    }
+
+    pub fn cut(&self) -> bool {
+        self.text_area.try_update(|t| t.cut()).unwrap()
+    }
+
+    pub fn paste(&self) -> bool {
+        self.text_area.try_update(|t| t.paste()).unwrap()
+    }
+
+    pub fn move_cursor(&self, cursor_move: CursorMove) {
+        self.text_area.update(|t| t.move_cursor(cursor_move));
+    }
+
+    pub fn with_lines<F, T>(&self, f: F) -> T
+    where
+        F: FnOnce(&[String]) -> T,
+    {
+        self.text_area.with(|t| f(t.lines()))
+    }
+}
+
+fn split_lines(text: &str) -> Vec<String> {
+    text.split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line).to_string())
+        .collect()
+}
+
+/// A multi-line text editor with line numbers, soft wrapping, undo/redo, and pluggable syntax
+/// highlighting.
+///
+/// Text can be selected with <kbd>Shift</kbd> and the arrow keys or by dragging with the mouse.
+/// Clicking moves the cursor and <kbd>Shift</kbd>+click extends the selection. <kbd>Ctrl+Z</kbd>
+/// and <kbd>Ctrl+Y</kbd> undo and redo changes in addition to the default bindings from
+/// [`ratatui_textarea`].
+pub struct TextEditor {
+    layout_props: LayoutProps,
+    style: Signal<Style>,
+    cursor_style: Signal<Style>,
+    selection_style: Signal<Style>,
+    line_number_style: Signal<Style>,
+    line_numbers: Signal<bool>,
+    soft_wrap: Signal<bool>,
+    tab_length: u8,
+    highlighter: Arc<dyn Highlighter>,
+    on_key_down: Box<dyn KeyHandler>,
+    initial_value: String,
+}
+
+impl Default for TextEditor {
+    fn default() -> Self {
+        Self {
+            layout_props: LayoutProps::default(),
+            style: Style::default().into(),
+            cursor_style: Style::reset().reversed().into(),
+            selection_style: Style::default().bg_disabled_dark(),
+            line_number_style: Style::default().fg_disabled_dark(),
+            line_numbers: true.into(),
+            soft_wrap: true.into(),
+            tab_length: 4,
+            highlighter: Arc::new(PlainText),
+            on_key_down: Box::new(|_| {}),
+            initial_value: String::new(),
+        }
+    }
+}
+
+impl UpdateLayoutProps for TextEditor {
+    fn layout_props(&self) -> LayoutProps {
+        self.layout_props.clone()
+    }
+
+    fn update_props(mut self, props: LayoutProps) -> Self {
+        self.layout_props = props;
+        self
+    }
+}
+
+impl TextEditor {
+    pub fn style<M>(mut self, style: impl IntoReactiveValue<Signal<Style>, M>) -> Self {
+        self.style = style.into_reactive_value();
+        self
+    }
+
+    pub fn cursor_style<M>(
+        mut self,
+        cursor_style: impl IntoReactiveValue<Signal<Style>, M>,
+    ) -> Self {
+        self.cursor_style = cursor_style.into_reactive_value();
+        self
+    }
+
+    pub fn selection_style<M>(
+        mut self,
+        selection_style: impl IntoReactiveValue<Signal<Style>, M>,
+    ) -> Self {
+        self.selection_style = selection_style.into_reactive_value();
+        self
+    }
+
+    pub fn line_number_style<M>(
+        mut self,
+        line_number_style: impl IntoReactiveValue<Signal<Style>, M>,
+    ) -> Self {
+        self.line_number_style = line_number_style.into_reactive_value();
+        self
+    }
+
+    pub fn line_numbers<M>(
+        mut self,
+        line_numbers: impl IntoReactiveValue<Signal<bool>, M>,
+    ) -> Self {
+        self.line_numbers = line_numbers.into_reactive_value();
+        self
+    }
+
+    /// Wraps lines that are wider than the editor. When disabled, the editor scrolls
+    /// horizontally to keep the cursor visible.
+    pub fn soft_wrap<M>(mut self, soft_wrap: impl IntoReactiveValue<Signal<bool>, M>) -> Self {
+        self.soft_wrap = soft_wrap.into_reactive_value();
+        self
+    }
+
+    pub fn tab_length(mut self, tab_length: u8) -> Self {
+        self.tab_length = tab_length;
+        self
+    }
+
+    pub fn highlighter(mut self, highlighter: impl Highlighter + 'static) -> Self {
+        self.highlighter = Arc::new(highlighter);
+        self
+    }
+
+    /// Called before the editor handles a key press.
+    /// If the handler stops propagation, the editor won't process the key.
+    pub fn on_key_down(mut self, on_key_down: impl KeyHandler + 'static) -> Self {
+        self.on_key_down = Box::new(on_key_down);
+        self
+    }
+
+    pub fn initial_value(mut self, initial_value: impl Into<String>) -> Self {
+        self.initial_value = initial_value.into();
+        self
+    }
+
+    pub fn get_ref() -> TextEditorRef {
+        TextEditorRef {
+            text_area: RwSignal::new(TextArea::default()),
+        }
+    }
+
+    pub fn render(self, editor_ref: TextEditorRef) -> impl Render {
+        let Self {
+            layout_props,
+            style,
+            cursor_style,
+            selection_style,
+            line_number_style,
+            line_numbers,
+            soft_wrap,
+            tab_length,
+            highlighter,
+            mut on_key_down,
+            initial_value,
+        } = self;
+
+        let text_area = editor_ref.text_area;
+        text_area.update_untracked(|t| {
+            if !initial_value.is_empty() {
+                *t = TextArea::new(split_lines(&initial_value));
+            }
+            t.set_tab_length(tab_length);
+        });
+
+        let viewport = StoredValue::new(Viewport::default());
+        // Re-renders the editor when the viewport is scrolled with the mouse
+        let scroll_trigger = Trigger::new();
+        let (focused, set_focused) = signal(false);
+
+        on_cleanup(|| {
+            set_editing(false);
+        });
+
+        let key_down = move |mut props: KeyEventProps| {
+            on_key_down.handle(props.clone());
+            if props.handle.get_stop_propagation() {
+                return;
+            }
+            let modifiers = props.event.modifiers;
+            let ctrl = modifiers == KeyModifiers::CTRL;
+            let ctrl_shift = modifiers == KeyModifiers::CTRL | KeyModifiers::SHIFT;
+            if modifiers.is_empty() || modifiers == KeyModifiers::SHIFT {
+                // Consume text input so it doesn't trigger keybindings on a parent
+                props.handle.stop_propagation();
+            }
+
+            match props.event.code {
+                KeyCode::Char('z') if ctrl => {
+                    props.handle.stop_propagation();
+                    editor_ref.undo();
+                }
+                KeyCode::Char('y') if ctrl => {
+                    props.handle.stop_propagation();
+                    editor_ref.redo();
+                }
+                KeyCode::Char('z' | 'Z') if ctrl_shift => {
+                    props.handle.stop_propagation();
+                    editor_ref.redo();
+                }
+                _ => {
+                    text_area.update(|t| {
+                        if let Some(event) = to_input(Event::Key(props.event)) {
+                            t.input(event);
+                        }
+                    });
+                }
+            }
+        };
+
+        let paste = move |text: String, _, _| {
+            text_area.update(|t| {
+                t.insert_str(text);
+            });
+        };
+
+        let text_position = move |column: u16, row: u16| {
+            text_area.with_untracked(|t| {
+                viewport.with_value(|v| v.text_position(Position::new(column, row), t.lines()))
+            })
+        };
+
+        let click = move |props: ClickEventProps| {
+            let Some((row, col)) = text_position(props.event.column, props.event.row) else {
+                return;
+            };
+            text_area.update(|t| {
+                if props.event.modifiers.contains(KeyModifiers::SHIFT) {
+                    if t.selection_range().is_none() {
+                        t.start_selection();
+                    }
+                } else {
+                    t.cancel_selection();
+                }
+                t.move_cursor(jump(row, col));
+            });
+        };
+
+        let drag = move |props: DragEventProps| {
+            if props.event.button != MouseButton::Left {
+                return;
+            }
+            let (Some(start), Some(end)) = (
+                text_position(props.event.start_column, props.event.start_row),
+                text_position(props.event.column, props.event.row),
+            ) else {
+                return;
+            };
+            text_area.update(|t| {
+                t.cancel_selection();
+                t.move_cursor(jump(start.0, start.1));
+                if start != end {
+                    t.start_selection();
+                    t.move_cursor(jump(end.0, end.1));
+                }
+            });
+        };
+
+        let scroll = move |direction: ScrollDirection, _, _| {
+            viewport.update_value(|v| v.scroll_by(-direction.delta().y as isize));
+            scroll_trigger.notify();
+        };
+
+        DomWidget::new(move || {
+            scroll_trigger.track();
+            let lines = text_area.with(|t| {
+                let mut lines = highlighter.highlight(t.lines());
+                // Fall back to plain text for any lines the highlighter didn't handle
+                lines.truncate(t.lines().len());
+                lines.extend(
+                    t.lines()[lines.len()..]
+                        .iter()
+                        .map(|line| Line::raw(line.clone())),
+                );
+                lines
+            });
+            RenderTextEditor {
+                text_area,
+                lines,
+                viewport,
+                focused: focused.get(),
+                line_numbers: line_numbers.get(),
+                soft_wrap: soft_wrap.get(),
+                style: style.get(),
+                cursor_style: cursor_style.get(),
+                selection_style: selection_style.get(),
+                line_number_style: line_number_style.get(),
+            }
+        })
+        .layout_props(layout_props)
+        .on_key_down(key_down)
+        .on_click(click)
+        .on_mouse_drag(drag)
+        .on_scroll(scroll)
+        .on_paste(paste)
+        .on_direct_focus(move |_, _, _| {
+            set_editing(true);
+            set_focused.set(true);
+        })
+        .on_direct_blur(move |_, _, _| {
+            set_editing(false);
+            set_focused.set(false);
+        })
+    }
+}
+
+fn jump(row: usize, col: usize) -> CursorMove {
+    CursorMove::Jump(
+        row.try_into().unwrap_or(u16::MAX),
+        col.try_into().unwrap_or(u16::MAX),
+    )
+}
+
+/// The part of the text that was visible the last time the editor was rendered.
+#[derive(Clone, Default)]
+struct Viewport {
+    // First visible row and column
+    top: usize,
+    left: usize,
+    // The viewport only follows the cursor when it moves so it can be scrolled with the mouse
+    cursor: (usize, usize),
+    // Where the text was drawn, excluding line numbers
+    area: Rect,
+    // Line index and starting character of each visual row
+    rows: Vec<(usize, usize)>,
+}
+
+impl Viewport {
+    fn scroll_by(&mut self, delta: isize) {
+        let max_top = self.rows.len().saturating_sub(self.area.height as usize);
+        self.top = self.top.saturating_add_signed(delta).min(max_top);
+    }
+
+    /// Converts a position on the screen to a `(row, column)` position in the text.
+    fn text_position(&self, position: Position, lines: &[String]) -> Option<(usize, usize)> {
+        let last_row = self.rows.len().checked_sub(1)?;
+        let row = (self.top + position.y.saturating_sub(self.area.y) as usize).min(last_row);
+        let (line, start) = self.rows[row];
+        // Wrapped rows end where the next row of the same line starts
+        let end = self
+            .rows
+            .get(row + 1)
+            .filter(|(next_line, _)| *next_line == line)
+            .map(|(_, next_start)| next_start - 1);
+        let target = self.left + position.x.saturating_sub(self.area.x) as usize;
+        let mut width = 0;
+        let mut col = start;
+        for c in lines.get(line)?.chars().skip(start) {
+            if end.is_some_and(|end| col >= end) {
+                break;
+            }
+            width += str_width(c.encode_utf8(&mut [0; 4]));
+            if width > target {
+                break;
+            }
+            col += 1;
+        }
+        Some((line, col))
+    }
+}
+
+struct VisualRow {
+    // Index of the line this row belongs to
+    line: usize,
+    // Character offset of the start of the row within the line
+    start: usize,
+    content: Line<'static>,
+}
+
+struct RenderTextEditor {
+    text_area: RwSignal<TextArea<'static>>,
+    lines: Vec<Line<'static>>,
+    viewport: StoredValue<Viewport>,
+    focused: bool,
+    line_numbers: bool,
+    soft_wrap: bool,
+    style: Style,
+    cursor_style: Style,
+    selection_style: Style,
+    line_number_style: Style,
+}
+
+impl RenderTextEditor {
+    fn gutter_width(&self) -> usize {
+        if self.line_numbers {
+            // Line number followed by a space
+            self.lines.len().max(1).to_string().len() + 1
+        } else {
+            0
+        }
+    }
+
+    fn visual_rows(
+        &self,
+        width: usize,
+        selection: Option<((usize, usize), (usize, usize))>,
+    ) -> Vec<VisualRow> {
+        let mut rows = Vec::new();
+        for (i, line) in self.lines.iter().enumerate() {
+            let line = match selection {
+                Some(((start_row, start_col), (end_row, end_col)))
+                    if (start_row..=end_row).contains(&i) =>
+                {
+                    let start = if i == start_row { start_col } else { 0 };
+                    let end = if i == end_row { end_col } else { usize::MAX };
+                    patch_range(line.clone(), start..end, self.selection_style)
+                }
+                _ => line.clone(),
+            };
+            if self.soft_wrap {
+                rows.extend(wrap_rows(&line, width).into_iter().map(|(start, content)| {
+                    VisualRow {
+                        line: i,
+                        start,
+                        content,
+                    }
+                }));
+            } else {
+                rows.push(VisualRow {
+                    line: i,
+                    start: 0,
+                    content: line,
+                });
+            }
+        }
+        rows
+    }
+}
+
+impl WidgetRole for RenderTextEditor {
+    fn widget_role() -> Option<Role> {
+        Some(Role::MultilineTextInput)
+    }
+}
+
+impl RenderNode for RenderTextEditor {
+    fn render(&mut self, area: Rect, frame: &mut Frame) {
+        let buf = frame.buffer_mut();
+        buf.set_style(area, self.style);
+
+        let gutter_width = self.gutter_width();
+        let text_width = (area.width as usize).saturating_sub(gutter_width);
+        let height = area.height as usize;
+        if text_width == 0 || height == 0 {
+            return;
+        }
+
+        let ((cursor_line, cursor_char), selection) = self
+            .text_area
+            .with_untracked(|t| (t.cursor(), t.selection_range()));
+        let rows = self.visual_rows(text_width, selection);
+
+        let cursor_row = rows
+            .iter()
+            .rposition(|row| row.line == cursor_line && row.start <= cursor_char)
+            .unwrap_or(0);
+        let cursor_col = self.lines.get(cursor_line).map_or(0, |line| {
+            let row_start = rows.get(cursor_row).map_or(0, |row| row.start);
+            str_width(
+                &line_text(line)
+                    .chars()
+                    .skip(row_start)
+                    .take(cursor_char.saturating_sub(row_start))
+                    .collect::<String>(),
+            )
+        });
+
+        let Viewport {
+            mut top,
+            mut left,
+            cursor,
+            ..
+        } = self.viewport.get_value();
+        if cursor != (cursor_line, cursor_char) {
+            // Scroll just enough to keep the cursor visible
+            top = top.clamp(
+                (cursor_row + 1).saturating_sub(height),
+                cursor_row.min(rows.len().saturating_sub(1)),
+            );
+        } else {
+            top = top.min(rows.len().saturating_sub(height));
+        }
+        left = if self.soft_wrap {
+            0
+        } else {
+            left.clamp((cursor_col + 1).saturating_sub(text_width), cursor_col)
+        };
+        self.viewport.set_value(Viewport {
+            top,
+            left,
+            cursor: (cursor_line, cursor_char),
+            area: Rect {
+                x: area.x + gutter_width as u16,
+                width: text_width as u16,
+                ..area
+            },
+            rows: rows.iter().map(|row| (row.line, row.start)).collect(),
+        });
+
+        let line_number_style = self.style.patch(self.line_number_style);
+        for (y, row) in (area.y..).zip(rows.into_iter().skip(top).take(height)) {
+            if self.line_numbers && row.start == 0 {
+                buf.set_stringn(
+                    area.x,
+                    y,
+                    format!("{:>1$} ", row.line + 1, gutter_width - 1),
+                    gutter_width,
+                    line_number_style,
+                );
+            }
+            skip_columns(row.content, left).render(
+                Rect {
+                    x: area.x + gutter_width as u16,
+                    y,
+                    width: text_width as u16,
+                    height: 1,
+                },
+                buf,
+            );
+        }
+
+        if self.focused && cursor_row >= top && cursor_row < top + height {
+            let x = area.x + (gutter_width + (cursor_col - left).min(text_width - 1)) as u16;
+            let y = area.y + (cursor_row - top) as u16;
+            if let Some(cell) = buf.cell_mut((x, y)) {
+                cell.set_style(self.cursor_style);
+            }
+        }
+    }
+}
+
+impl MeasureNode for RenderTextEditor {
+    fn measure(
+        &self,
+        _known_dimensions: taffy::Size<Option<f32>>,
+        _available_space: taffy::Size<taffy::AvailableSpace>,
+        _style: &taffy::Style,
+    ) -> taffy::Size<f32> {
+        self.estimate_size()
+    }
+
+    fn estimate_size(&self) -> taffy::Size<f32> {
+        let max_width = self.lines.iter().map(|l| l.width()).max().unwrap_or(0);
+        taffy::Size {
+            // +1 for cursor
+            width: (self.gutter_width() + max_width + 1) as f32,
+            height: self.lines.len().max(1) as f32,
+        }
+    }
+}
+
+fn line_text(line: &Line<'_>) -> String {
+    line.spans
+        .iter()
+        .map(|span| span.content.as_ref())
+        .collect()
+}
+
+fn str_width(text: &str) -> usize {
+    Span::raw(text).width()
+}
+
+// Wraps a line to the given width, returning each row along with the character offset where it
+// starts in the original line
+fn wrap_rows(line: &Line<'static>, width: usize) -> Vec<(usize, Line<'static>)> {
+    wrap_line_with_offsets(
+        line,
+        Options {
+            width,
+            initial_indent: Span::default(),
+            subsequent_indent: Span::default(),
+            break_words: true,
+        },
+    )
+    .into_iter()
+    .map(|(start, row)| {
+        let row = Line::from(
+            row.spans
+                .into_iter()
+                .map(|span| Span::styled(span.content.into_owned(), span.style))
+                .collect::<Vec<_>>(),
+        );
+        (start, row)
+    })
+    .collect()
+}
+
+// Applies a style to the characters within the given range
+fn patch_range(line: Line<'static>, range: Range<usize>, style: Style) -> Line<'static> {
+    let mut spans = Vec::new();
+    let mut pos = 0;
+    for span in line.spans {
+        let len = span.content.chars().count();
+        let start = range.start.clamp(pos, pos + len) - pos;
+        let end = range.end.clamp(pos, pos + len) - pos;
+        pos += len;
+        if start == end {
+            spans.push(span);
+            continue;
+        }
+        let content = span.content.as_ref();
+        let byte_index = |i: usize| {
+            content
+                .char_indices()
+                .nth(i)
+                .map_or(content.len(), |(b, _)| b)
+        };
+        let (start, end) = (byte_index(start), byte_index(end));
+        for (text, selected) in [
+            (&content[..start], false),
+            (&content[start..end], true),
+            (&content[end..], false),
+        ] {
+            if !text.is_empty() {
+                let span_style = if selected {
+                    span.style.patch(style)
+                } else {
+                    span.style
+                };
+                spans.push(Span::styled(text.to_string(), span_style));
+            }
+        }
+    }
+    Line::from(spans).style(line.style)
+}
+
+// Removes the given number of columns from the start of the line
+fn skip_columns(line: Line<'static>, columns: usize) -> Line<'static> {
+    if columns == 0 {
+        return line;
+    }
+    let mut skipped = 0;
+    let spans: Vec<_> = line
+        .spans
+        .into_iter()
+        .filter_map(|span| {
+            if skipped >= columns {
+                return Some(span);
+            }
+            let content = span.content.as_ref();
+            let mut start = content.len();
+            for (i, c) in content.char_indices() {
+                if skipped >= columns {
+                    start = i;
+                    break;
+                }
+                skipped += str_width(&content[i..i + c.len_utf8()]);
+            }
+            (start < content.len()).then(|| Span::styled(content[start..].to_string(), span.style))
+        })
+        .collect();
+    Line::from(spans).style(line.style)
+}
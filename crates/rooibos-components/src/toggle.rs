@@ -0,0 +1,149 @@
+use ratatui::text::{Line, Span};
+use rooibos_dom::events::KeyEventProps;
+use rooibos_dom::widgets::Role;
+use rooibos_reactive::dom::layout::checked;
+use rooibos_reactive::dom::{LayoutProps, Render, UpdateLayoutProps};
+use rooibos_reactive::graph::IntoReactiveValue;
+use rooibos_reactive::graph::owner::StoredValue;
+use rooibos_reactive::graph::signal::RwSignal;
+use rooibos_reactive::graph::traits::{Get, GetUntracked, Set, UpdateValue};
+use rooibos_reactive::graph::wrappers::read::Signal;
+use rooibos_reactive::{StateProp, use_state_prop, wgt};
+use rooibos_theme::Style;
+
+use crate::ColorThemeColorTheme;
+use crate::checkbox::{CheckableRole, CheckableWidget, default_checkable_style, is_activation_key};
+
+struct SwitchRole;
+
+impl CheckableRole for SwitchRole {
+    const ROLE: Role = Role::Switch;
+}
+
+/// An on/off switch.
+pub struct Toggle {
+    on: Option<RwSignal<bool>>,
+    on_change: Box<dyn FnMut(bool)>,
+    style: StateProp<Style>,
+    on_style: Signal<Style>,
+    on_symbol: Signal<&'static str>,
+    off_symbol: Signal<&'static str>,
+    layout_props: LayoutProps,
+}
+
+impl Default for Toggle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UpdateLayoutProps for Toggle {
+    fn layout_props(&self) -> LayoutProps {
+        self.layout_props.clone()
+    }
+
+    fn update_props(mut self, props: LayoutProps) -> Self {
+        self.layout_props = props;
+        self
+    }
+}
+
+impl Toggle {
+    pub fn new() -> Self {
+        Self {
+            on: None,
+            on_change: Box::new(|_| {}),
+            style: default_checkable_style(),
+            on_style: Style::default().fg_active(),
+            on_symbol: "━━●".into(),
+            off_symbol: "●━━".into(),
+            layout_props: LayoutProps::default(),
+        }
+    }
+
+    /// Signal holding the state of the switch. This is updated when the switch is toggled and can
+    /// be set to change the state programmatically.
+    pub fn on(mut self, on: RwSignal<bool>) -> Self {
+        self.on = Some(on);
+        self
+    }
+
+    pub fn on_change(mut self, on_change: impl FnMut(bool) + 'static) -> Self {
+        self.on_change = Box::new(on_change);
+        self
+    }
+
+    pub fn style(mut self, style: StateProp<Style>) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Style applied to the switch symbol while it's turned on.
+    pub fn on_style<M>(mut self, on_style: impl IntoReactiveValue<Signal<Style>, M>) -> Self {
+        self.on_style = on_style.into_reactive_value();
+        self
+    }
+
+    pub fn on_symbol<M>(
+        mut self,
+        on_symbol: impl IntoReactiveValue<Signal<&'static str>, M>,
+    ) -> Self {
+        self.on_symbol = on_symbol.into_reactive_value();
+        self
+    }
+
+    pub fn off_symbol<M>(
+        mut self,
+        off_symbol: impl IntoReactiveValue<Signal<&'static str>, M>,
+    ) -> Self {
+        self.off_symbol = off_symbol.into_reactive_value();
+        self
+    }
+
+    pub fn render<M>(self, label: impl IntoReactiveValue<Signal<Line<'static>>, M>) -> impl Render {
+        let Self {
+            on,
+            on_change,
+            style,
+            on_style,
+            on_symbol,
+            off_symbol,
+            layout_props,
+        } = self;
+        let label: Signal<Line<'static>> = label.into_reactive_value();
+        let on = on.unwrap_or_else(|| RwSignal::new(false));
+        let (style, set_style_state) = use_state_prop(style);
+
+        let on_change = StoredValue::new_local(on_change);
+
+        let toggle = move || {
+            let value = !on.get_untracked();
+            on.set(value);
+            on_change.update_value(|on_change| on_change(value));
+        };
+
+        let content = move || {
+            let style = style.get();
+            let symbol = if on.get() {
+                Span::styled(on_symbol.get(), style.patch(on_style.get()))
+            } else {
+                Span::styled(off_symbol.get(), style)
+            };
+            let mut line = label.get().patch_style(style);
+            line.spans.insert(0, symbol);
+            line.spans.insert(1, Span::raw(" "));
+            CheckableWidget::<SwitchRole>::new(line)
+        };
+
+        wgt!(style(checked(on)), content())
+            .layout_props(layout_props)
+            .on_state_change(set_style_state)
+            .on_click(move |_| toggle())
+            .on_key_down(move |props: KeyEventProps| {
+                if is_activation_key(&props) {
+                    props.handle.stop_propagation();
+                    toggle();
+                }
+            })
+    }
+}
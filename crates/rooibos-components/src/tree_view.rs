@@ -0,0 +1,686 @@
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::hash::Hash;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, HighlightSpacing, List, ListItem, ListState};
+use rooibos_dom::KeyCode;
+use rooibos_dom::events::{
+    BlurEvent, ClickEventProps, EventData, EventHandle, FocusEvent, KeyEventProps, KeyHandler,
+};
+use rooibos_reactive::dom::{LayoutProps, Render, UpdateLayoutProps};
+use rooibos_reactive::graph::IntoReactiveValue;
+use rooibos_reactive::graph::computed::Memo;
+use rooibos_reactive::graph::computed::suspense::SuspenseContext;
+use rooibos_reactive::graph::effect::Effect;
+use rooibos_reactive::graph::owner::{StoredValue, use_context};
+use rooibos_reactive::graph::signal::RwSignal;
+use rooibos_reactive::graph::traits::{
+    Get, GetUntracked, Set, Update, UpdateValue, With, WithUntracked, WithValue,
+};
+use rooibos_reactive::graph::wrappers::read::Signal;
+use rooibos_reactive::{IntoSignal, wgt};
+use rooibos_theme::Style;
+use wasm_compat::futures::spawn_local;
+
+use crate::{Keyed, VirtualListState};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TreeChildren<T> {
+    /// The node can't be expanded.
+    Leaf,
+    Loaded(Vec<TreeNode<T>>),
+    /// The children will be fetched with [`TreeView::load_children`] the first time the node is
+    /// expanded.
+    Lazy,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeNode<T> {
+    item: T,
+    children: TreeChildren<T>,
+}
+
+impl<T> TreeNode<T> {
+    pub fn leaf(item: T) -> Self {
+        Self {
+            item,
+            children: TreeChildren::Leaf,
+        }
+    }
+
+    pub fn branch(item: T, children: impl IntoIterator<Item = TreeNode<T>>) -> Self {
+        Self {
+            item,
+            children: TreeChildren::Loaded(children.into_iter().collect()),
+        }
+    }
+
+    pub fn lazy(item: T) -> Self {
+        Self {
+            item,
+            children: TreeChildren::Lazy,
+        }
+    }
+
+    pub fn item(&self) -> &T {
+        &self.item
+    }
+
+    pub fn children(&self) -> &TreeChildren<T> {
+        &self.children
+    }
+}
+
+/// A node in the tree after it's been flattened into the list of visible rows.
+#[derive(Clone, Debug)]
+struct TreeRow<T> {
+    item: T,
+    depth: usize,
+    parent: Option<usize>,
+    expandable: bool,
+    expanded: bool,
+    lazy: bool,
+    loading: bool,
+}
+
+/// Expansion, selection, and lazy loading state for a [`TreeView`].
+pub struct TreeViewState<T>
+where
+    T: Keyed + Send + Sync + 'static,
+    T::Key: Send + Sync + 'static,
+{
+    cursor: VirtualListState,
+    selected: RwSignal<Option<T::Key>>,
+    expanded: RwSignal<HashSet<T::Key>>,
+    loading: RwSignal<HashSet<T::Key>>,
+    loaded: RwSignal<HashMap<T::Key, Vec<TreeNode<T>>>>,
+    /// Incremented each time a node is invalidated so loads that were started before that can
+    /// be discarded.
+    generations: StoredValue<HashMap<T::Key, u64>>,
+}
+
+impl<T> Clone for TreeViewState<T>
+where
+    T: Keyed + Send + Sync + 'static,
+    T::Key: Send + Sync + 'static,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TreeViewState<T>
+where
+    T: Keyed + Send + Sync + 'static,
+    T::Key: Send + Sync + 'static,
+{
+}
+
+impl<T> Default for TreeViewState<T>
+where
+    T: Keyed + Send + Sync + 'static,
+    T::Key: Clone + Hash + Send + Sync + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> TreeViewState<T>
+where
+    T: Keyed + Send + Sync + 'static,
+    T::Key: Clone + Hash + Send + Sync + 'static,
+{
+    pub fn new() -> Self {
+        Self {
+            cursor: VirtualListState::new(),
+            selected: RwSignal::new(None),
+            expanded: RwSignal::new(HashSet::new()),
+            loading: RwSignal::new(HashSet::new()),
+            loaded: RwSignal::new(HashMap::new()),
+            generations: StoredValue::new(HashMap::new()),
+        }
+    }
+
+    /// Key of the selected node.
+    pub fn selected(&self) -> Signal<Option<T::Key>> {
+        self.selected.into()
+    }
+
+    pub fn select(&self, key: Option<T::Key>) {
+        self.selected.set(key);
+    }
+
+    pub fn expanded(&self) -> Signal<HashSet<T::Key>> {
+        self.expanded.into()
+    }
+
+    pub fn is_expanded(&self, key: &T::Key) -> bool {
+        self.expanded.with_untracked(|e| e.contains(key))
+    }
+
+    pub fn expand(&self, key: T::Key) {
+        self.expanded.update(|e| {
+            e.insert(key);
+        });
+    }
+
+    pub fn collapse(&self, key: &T::Key) {
+        self.expanded.update(|e| {
+            e.remove(key);
+        });
+    }
+
+    pub fn toggle(&self, key: T::Key) {
+        if self.is_expanded(&key) {
+            self.collapse(&key);
+        } else {
+            self.expand(key);
+        }
+    }
+
+    /// Discards any lazily loaded children for the node so they're fetched again the next time
+    /// it's expanded. The result of a load that's still in progress is ignored.
+    pub fn invalidate(&self, key: &T::Key) {
+        self.generations.update_value(|g| {
+            *g.entry(key.clone()).or_default() += 1;
+        });
+        self.loaded.update(|l| {
+            l.remove(key);
+        });
+        self.loading.update(|l| {
+            l.remove(key);
+        });
+        if self.is_expanded(key) {
+            self.collapse(key);
+        }
+    }
+
+    fn generation(&self, key: &T::Key) -> u64 {
+        self.generations
+            .with_value(|g| g.get(key).copied().unwrap_or_default())
+    }
+
+    fn flatten(&self, roots: &[TreeNode<T>]) -> Vec<TreeRow<T>>
+    where
+        T: Clone,
+    {
+        let mut rows = Vec::new();
+        self.expanded.with(|expanded| {
+            self.loading.with(|loading| {
+                self.loaded.with(|loaded| {
+                    flatten_nodes(roots, None, 0, expanded, loading, loaded, &mut rows);
+                })
+            })
+        });
+        rows
+    }
+}
+
+fn flatten_nodes<T>(
+    nodes: &[TreeNode<T>],
+    parent: Option<usize>,
+    depth: usize,
+    expanded: &HashSet<T::Key>,
+    loading: &HashSet<T::Key>,
+    loaded: &HashMap<T::Key, Vec<TreeNode<T>>>,
+    rows: &mut Vec<TreeRow<T>>,
+) where
+    T: Keyed + Clone,
+    T::Key: Hash,
+{
+    for node in nodes {
+        let key = node.item.key();
+        let is_expanded = expanded.contains(key);
+        let children = match &node.children {
+            TreeChildren::Leaf => None,
+            TreeChildren::Loaded(children) => Some(children.as_slice()),
+            TreeChildren::Lazy => Some(loaded.get(key).map(Vec::as_slice).unwrap_or_default()),
+        };
+        let index = rows.len();
+        rows.push(TreeRow {
+            item: node.item.clone(),
+            depth,
+            parent,
+            expandable: children.is_some(),
+            expanded: is_expanded,
+            lazy: matches!(node.children, TreeChildren::Lazy),
+            loading: loading.contains(key),
+        });
+        if is_expanded && let Some(children) = children {
+            flatten_nodes(
+                children,
+                Some(index),
+                depth + 1,
+                expanded,
+                loading,
+                loaded,
+                rows,
+            );
+        }
+    }
+}
+
+type LoadChildrenFn<T> = dyn Fn(T) -> Pin<Box<dyn Future<Output = Vec<TreeNode<T>>>>>;
+
+pub struct TreeView<T>
+where
+    T: Keyed + Send + Sync + 'static,
+    T::Key: Send + Sync + 'static,
+{
+    style: Signal<Style>,
+    highlight_style: Signal<Style>,
+    block: Option<Signal<Block<'static>>>,
+    highlight_spacing: Signal<HighlightSpacing>,
+    highlight_symbol: Option<Signal<&'static str>>,
+    expanded_symbol: Signal<&'static str>,
+    collapsed_symbol: Signal<&'static str>,
+    loading_symbol: Signal<&'static str>,
+    indent: Signal<u16>,
+    load_children: Option<Rc<LoadChildrenFn<T>>>,
+    on_item_click: Box<dyn FnMut(T)>,
+    on_key_down: Box<dyn KeyHandler>,
+    on_direct_focus: Box<dyn FnMut(FocusEvent, EventData, EventHandle)>,
+    on_direct_blur: Box<dyn FnMut(BlurEvent, EventData, EventHandle)>,
+    state: Option<TreeViewState<T>>,
+    layout_props: LayoutProps,
+}
+
+impl<T> Default for TreeView<T>
+where
+    T: Keyed + Send + Sync + 'static,
+    T::Key: Send + Sync + 'static,
+{
+    fn default() -> Self {
+        Self {
+            style: Default::default(),
+            highlight_style: Style::default().reversed().into(),
+            block: Default::default(),
+            highlight_spacing: Default::default(),
+            highlight_symbol: Default::default(),
+            expanded_symbol: "▼ ".into(),
+            collapsed_symbol: "▶ ".into(),
+            loading_symbol: "… ".into(),
+            indent: 2.into(),
+            load_children: None,
+            on_item_click: Box::new(move |_| {}),
+            on_key_down: Box::new(move |_| {}),
+            on_direct_focus: Box::new(move |_, _, _| {}),
+            on_direct_blur: Box::new(move |_, _, _| {}),
+            state: None,
+            layout_props: LayoutProps::default(),
+        }
+    }
+}
+
+impl<T> UpdateLayoutProps for TreeView<T>
+where
+    T: Keyed + Send + Sync + 'static,
+    T::Key: Send + Sync + 'static,
+{
+    fn layout_props(&self) -> LayoutProps {
+        self.layout_props.clone()
+    }
+
+    fn update_props(mut self, props: LayoutProps) -> Self {
+        self.layout_props = props;
+        self
+    }
+}
+
+impl<T> TreeView<T>
+where
+    T: Keyed + Clone + Send + Sync + 'static,
+    T::Key: Clone + Hash + Send + Sync + 'static,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn state(mut self, state: TreeViewState<T>) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    pub fn style<M>(mut self, style: impl IntoReactiveValue<Signal<Style>, M>) -> Self {
+        self.style = style.into_reactive_value();
+        self
+    }
+
+    pub fn highlight_style<M>(
+        mut self,
+        highlight_style: impl IntoReactiveValue<Signal<Style>, M>,
+    ) -> Self {
+        self.highlight_style = highlight_style.into_reactive_value();
+        self
+    }
+
+    pub fn block<M>(mut self, block: impl IntoReactiveValue<Signal<Block<'static>>, M>) -> Self {
+        self.block = Some(block.into_reactive_value());
+        self
+    }
+
+    pub fn highlight_spacing<M>(
+        mut self,
+        highlight_spacing: impl IntoReactiveValue<Signal<HighlightSpacing>, M>,
+    ) -> Self {
+        self.highlight_spacing = highlight_spacing.into_reactive_value();
+        self
+    }
+
+    pub fn highlight_symbol<M>(
+        mut self,
+        highlight_symbol: impl IntoReactiveValue<Signal<&'static str>, M>,
+    ) -> Self {
+        self.highlight_symbol = Some(highlight_symbol.into_reactive_value());
+        self
+    }
+
+    pub fn expanded_symbol<M>(
+        mut self,
+        expanded_symbol: impl IntoReactiveValue<Signal<&'static str>, M>,
+    ) -> Self {
+        self.expanded_symbol = expanded_symbol.into_reactive_value();
+        self
+    }
+
+    pub fn collapsed_symbol<M>(
+        mut self,
+        collapsed_symbol: impl IntoReactiveValue<Signal<&'static str>, M>,
+    ) -> Self {
+        self.collapsed_symbol = collapsed_symbol.into_reactive_value();
+        self
+    }
+
+    pub fn loading_symbol<M>(
+        mut self,
+        loading_symbol: impl IntoReactiveValue<Signal<&'static str>, M>,
+    ) -> Self {
+        self.loading_symbol = loading_symbol.into_reactive_value();
+        self
+    }
+
+    /// Number of columns to indent each level of the tree.
+    pub fn indent<M>(mut self, indent: impl IntoReactiveValue<Signal<u16>, M>) -> Self {
+        self.indent = indent.into_reactive_value();
+        self
+    }
+
+    /// Fetches the children of nodes created with [`TreeNode::lazy`].
+    ///
+    /// If the tree is rendered inside of a `suspense` or `transition` boundary, pending loads are
+    /// registered with it like any other async resource.
+    pub fn load_children<F, Fut>(mut self, load_children: F) -> Self
+    where
+        F: Fn(T) -> Fut + 'static,
+        Fut: Future<Output = Vec<TreeNode<T>>> + 'static,
+    {
+        self.load_children = Some(Rc::new(move |item| Box::pin(load_children(item))));
+        self
+    }
+
+    pub fn on_item_click(mut self, on_item_click: impl FnMut(T) + 'static) -> Self {
+        self.on_item_click = Box::new(on_item_click);
+        self
+    }
+
+    pub fn on_key_down(mut self, on_key_down: impl KeyHandler + 'static) -> Self {
+        self.on_key_down = Box::new(on_key_down);
+        self
+    }
+
+    pub fn on_direct_focus(
+        mut self,
+        on_direct_focus: impl FnMut(FocusEvent, EventData, EventHandle) + 'static,
+    ) -> Self {
+        self.on_direct_focus = Box::new(on_direct_focus);
+        self
+    }
+
+    pub fn on_direct_blur(
+        mut self,
+        on_blur: impl FnMut(BlurEvent, EventData, EventHandle) + 'static,
+    ) -> Self {
+        self.on_direct_blur = Box::new(on_blur);
+        self
+    }
+
+    pub fn render<M, F>(
+        self,
+        roots: impl IntoReactiveValue<Signal<Vec<TreeNode<T>>>, M>,
+        render_item: F,
+    ) -> impl Render
+    where
+        F: Fn(&T) -> Line<'static> + 'static,
+    {
+        let Self {
+            style,
+            highlight_style,
+            block,
+            highlight_spacing,
+            highlight_symbol,
+            expanded_symbol,
+            collapsed_symbol,
+            loading_symbol,
+            indent,
+            load_children,
+            mut on_item_click,
+            mut on_key_down,
+            on_direct_focus,
+            on_direct_blur,
+            state,
+            layout_props,
+        } = self;
+        let roots: Signal<Vec<TreeNode<T>>> = roots.into_reactive_value();
+        let state = state.unwrap_or_default();
+        let suspense_context = use_context::<SuspenseContext>();
+
+        // Rows don't implement PartialEq, so treat every recalculation as a change
+        let rows = Memo::new_with_compare(
+            move |_| roots.with(|roots| state.flatten(roots)),
+            |_, _| true,
+        );
+
+        let row_count = (move || rows.with(Vec::len)).signal();
+        let visible_rows = state.cursor.visible_rows(row_count, |_| 1);
+
+        // Keep the cursor on the selected node when rows are added or removed above it
+        Effect::new(move || {
+            let row = state.selected.with(|selected| {
+                selected.as_ref().and_then(|selected| {
+                    rows.with(|rows| rows.iter().position(|r| r.item.key() == selected))
+                })
+            });
+            state.cursor.select(row);
+        });
+
+        let expand = Rc::new(move |row: &TreeRow<T>| {
+            let key = row.item.key().clone();
+            state.expand(key.clone());
+            let Some(load_children) = &load_children else {
+                return;
+            };
+            let needs_load = row.lazy
+                && !state.loaded.with_untracked(|l| l.contains_key(&key))
+                && !state.loading.with_untracked(|l| l.contains(&key));
+            if !needs_load {
+                return;
+            }
+            state.loading.update(|l| {
+                l.insert(key.clone());
+            });
+            let task = suspense_context.as_ref().map(|s| s.task_id());
+            let generation = state.generation(&key);
+            let children = load_children(row.item.clone());
+            spawn_local(async move {
+                let children = children.await;
+                // The node was invalidated while loading
+                if state.generation(&key) != generation {
+                    return;
+                }
+                state.loaded.update(|l| {
+                    l.insert(key.clone(), children);
+                });
+                state.loading.update(|l| {
+                    l.remove(&key);
+                });
+                drop(task);
+            });
+        });
+
+        let list_state = move || {
+            let visible = visible_rows.get();
+            ListState::default().with_selected(
+                state
+                    .cursor
+                    .selected()
+                    .get()
+                    .filter(|s| visible.contains(s))
+                    .map(|s| s - visible.start),
+            )
+        };
+
+        wgt!(list_state(), {
+            let indent = indent.get() as usize;
+            let items = rows.with(|rows| {
+                visible_rows
+                    .get()
+                    .map(|i| {
+                        let row = &rows[i];
+                        let marker = if row.loading {
+                            loading_symbol.get()
+                        } else if !row.expandable {
+                            ""
+                        } else if row.expanded {
+                            expanded_symbol.get()
+                        } else {
+                            collapsed_symbol.get()
+                        };
+                        let marker_width = Span::raw(expanded_symbol.get())
+                            .width()
+                            .max(Span::raw(collapsed_symbol.get()).width());
+                        let padding = marker_width.saturating_sub(Span::raw(marker).width());
+                        let mut line = render_item(&row.item);
+                        line.spans.insert(
+                            0,
+                            Span::raw(format!(
+                                "{}{marker}{}",
+                                " ".repeat(row.depth * indent),
+                                " ".repeat(padding)
+                            )),
+                        );
+                        ListItem::new(line)
+                    })
+                    .collect::<Vec<_>>()
+            });
+            let mut list = List::new(items)
+                .highlight_style(highlight_style.get())
+                .highlight_spacing(highlight_spacing.get())
+                .style(style.get());
+            if let Some(block) = &block {
+                list = list.block(block.get());
+            }
+            if let Some(highlight_symbol) = highlight_symbol {
+                list = list.highlight_symbol(highlight_symbol.get());
+            }
+            list
+        })
+        .on_size_change(move |rect: Rect| {
+            let inner = match &block {
+                Some(block) => block.with_untracked(|b| b.inner(rect)),
+                None => rect,
+            };
+            state.cursor.set_viewport_height(inner.height);
+        })
+        .on_click({
+            let expand = expand.clone();
+            move |props: ClickEventProps| {
+                let inner_y = match &block {
+                    Some(block) => block.with_untracked(|b| b.inner(props.data.rect).y),
+                    None => props.data.rect.y,
+                };
+                let Some(line) = props.event.row.checked_sub(inner_y) else {
+                    return;
+                };
+                let Some(clicked) = state
+                    .cursor
+                    .row_at(visible_rows.get_untracked(), line, |_| 1)
+                else {
+                    return;
+                };
+                let Some(row) = rows.with_untracked(|rows| rows.get(clicked).cloned()) else {
+                    return;
+                };
+                let key = row.item.key().clone();
+                // Clicking on a node that's already selected toggles it
+                let was_selected = state.selected.with_untracked(|s| s.as_ref() == Some(&key));
+                state.selected.set(Some(key.clone()));
+                if was_selected && row.expandable {
+                    if row.expanded {
+                        state.collapse(&key);
+                    } else {
+                        expand(&row);
+                    }
+                }
+                on_item_click(row.item);
+            }
+        })
+        .layout_props(layout_props)
+        .on_key_down(move |mut props: KeyEventProps| {
+            on_key_down.handle(props.clone());
+            if !props.event.modifiers.is_empty() {
+                return;
+            }
+            let rows = rows.get_untracked();
+            let current = state.cursor.selected().get_untracked();
+            let current_row = current.and_then(|c| rows.get(c));
+            match (props.event.code, current_row) {
+                (KeyCode::Right, Some(row)) if row.expandable => {
+                    if row.expanded {
+                        // Move to the first child
+                        if let Some(child) = rows.get(current.unwrap_or_default() + 1)
+                            && child.depth > row.depth
+                        {
+                            state.selected.set(Some(child.item.key().clone()));
+                        }
+                    } else {
+                        expand(row);
+                    }
+                    props.handle.stop_propagation();
+                }
+                (KeyCode::Left, Some(row)) => {
+                    if row.expanded {
+                        state.collapse(row.item.key());
+                        props.handle.stop_propagation();
+                    } else if let Some(parent) = row.parent {
+                        state.selected.set(Some(rows[parent].item.key().clone()));
+                        props.handle.stop_propagation();
+                    }
+                }
+                (KeyCode::Enter | KeyCode::Char(' '), Some(row)) if row.expandable => {
+                    if row.expanded {
+                        state.collapse(row.item.key());
+                    } else {
+                        expand(row);
+                    }
+                    props.handle.stop_propagation();
+                }
+                _ => {
+                    state.cursor.handle_key(&mut props);
+                    let new_row = state.cursor.selected().get_untracked();
+                    if new_row != current
+                        && let Some(row) = new_row.and_then(|r| rows.get(r))
+                    {
+                        state.selected.set(Some(row.item.key().clone()));
+                    }
+                }
+            }
+        })
+        .on_direct_focus(on_direct_focus)
+        .on_direct_blur(on_direct_blur)
+    }
+}
@@ -0,0 +1,539 @@
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::Arc;
+
+use ratatui::layout::Rect;
+use ratatui::widgets::{Block, HighlightSpacing, List, ListItem, ListState};
+use rooibos_dom::events::{
+    BlurEvent, ClickEventProps, EventData, EventHandle, FocusEvent, KeyEventProps, KeyHandler,
+};
+use rooibos_dom::{KeyCode, ScrollDirection};
+use rooibos_reactive::dom::{LayoutProps, Render, UpdateLayoutProps};
+use rooibos_reactive::graph::IntoReactiveValue;
+use rooibos_reactive::graph::computed::Memo;
+use rooibos_reactive::graph::effect::Effect;
+use rooibos_reactive::graph::owner::StoredValue;
+use rooibos_reactive::graph::signal::RwSignal;
+use rooibos_reactive::graph::traits::{
+    Get, GetUntracked, Set, SetValue, Track, UpdateValue, WithUntracked, WithValue,
+};
+use rooibos_reactive::graph::wrappers::read::Signal;
+use rooibos_reactive::wgt;
+use rooibos_theme::Style;
+
+type RowHeightFn = dyn Fn(usize) -> u16 + Send + Sync;
+
+/// Selection and scroll state for virtualized components like [`VirtualList`] and
+/// [`VirtualTable`](crate::VirtualTable).
+#[derive(Clone, Copy)]
+pub struct VirtualListState {
+    selected: RwSignal<Option<usize>>,
+    offset: RwSignal<usize>,
+    item_count: RwSignal<usize>,
+    viewport_height: RwSignal<u16>,
+    row_height: StoredValue<Arc<RowHeightFn>>,
+}
+
+impl Default for VirtualListState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VirtualListState {
+    pub fn new() -> Self {
+        Self {
+            selected: RwSignal::new(None),
+            offset: RwSignal::new(0),
+            item_count: RwSignal::new(0),
+            viewport_height: RwSignal::new(0),
+            row_height: StoredValue::new(Arc::new(|_| 1)),
+        }
+    }
+
+    pub fn with_selected(self, selected: Option<usize>) -> Self {
+        self.selected.set(selected);
+        self
+    }
+
+    pub fn selected(&self) -> Signal<Option<usize>> {
+        self.selected.into()
+    }
+
+    /// Index of the first row that's currently visible.
+    pub fn offset(&self) -> Signal<usize> {
+        self.offset.into()
+    }
+
+    /// Height of the area that rows are drawn in.
+    pub fn viewport_height(&self) -> Signal<u16> {
+        self.viewport_height.into()
+    }
+
+    pub fn select(&self, index: Option<usize>) {
+        let count = self.item_count.get_untracked();
+        self.selected
+            .set(index.filter(|_| count > 0).map(|i| i.min(count - 1)));
+    }
+
+    pub fn select_next(&self) {
+        self.select_relative(1);
+    }
+
+    pub fn select_previous(&self) {
+        self.select_relative(-1);
+    }
+
+    pub fn select_first(&self) {
+        self.select(Some(0));
+    }
+
+    pub fn select_last(&self) {
+        self.select(Some(usize::MAX));
+    }
+
+    pub fn page_down(&self) {
+        self.select_relative(self.page_size() as isize);
+    }
+
+    pub fn page_up(&self) {
+        self.select_relative(-(self.page_size() as isize));
+    }
+
+    /// Scrolls the view so that `index` is the first visible row, or as close as possible if
+    /// there aren't enough rows after it to fill the view. This doesn't change the selection, so
+    /// the selected row may be scrolled out of view. It's scrolled back into view the next time
+    /// the selection changes.
+    pub fn scroll_to(&self, index: usize) {
+        self.offset.set(index.min(self.max_offset()));
+    }
+
+    pub fn scroll_by(&self, delta: isize) {
+        let offset = self.offset.get_untracked();
+        self.scroll_to(offset.saturating_add_signed(delta));
+    }
+
+    // Rows that would be visible if the view started at `offset`
+    fn rows_from(&self, offset: usize) -> Range<usize> {
+        self.row_height.with_value(|row_height| {
+            visible_range(
+                offset,
+                None,
+                self.item_count.get_untracked(),
+                self.viewport_height.get_untracked(),
+                &**row_height,
+            )
+        })
+    }
+
+    fn page_size(&self) -> usize {
+        self.rows_from(self.offset.get_untracked()).len().max(1)
+    }
+
+    // The last offset that still fills the view
+    fn max_offset(&self) -> usize {
+        let count = self.item_count.get_untracked();
+        let viewport_height = self.viewport_height.get_untracked();
+        self.row_height.with_value(|row_height| {
+            let mut offset = count.saturating_sub(1);
+            let mut height = 0u16;
+            while offset > 0 {
+                height = height.saturating_add(row_height(offset));
+                let prev_height = row_height(offset - 1);
+                if height.saturating_add(prev_height) > viewport_height {
+                    break;
+                }
+                offset -= 1;
+            }
+            offset
+        })
+    }
+
+    fn select_relative(&self, delta: isize) {
+        let next = match self.selected.get_untracked() {
+            Some(selected) => selected.saturating_add_signed(delta),
+            None => self.offset.get_untracked(),
+        };
+        self.select(Some(next));
+    }
+
+    pub(crate) fn handle_key(&self, props: &mut KeyEventProps) {
+        if !props.event.modifiers.is_empty() {
+            return;
+        }
+        match props.event.code {
+            KeyCode::Down => self.select_next(),
+            KeyCode::Up => self.select_previous(),
+            KeyCode::PageDown => self.page_down(),
+            KeyCode::PageUp => self.page_up(),
+            KeyCode::Home => self.select_first(),
+            KeyCode::End => self.select_last(),
+            _ => return,
+        }
+        props.handle.stop_propagation();
+    }
+
+    pub(crate) fn handle_scroll(&self, direction: ScrollDirection) {
+        self.scroll_by(-direction.delta().y as isize);
+    }
+
+    /// Keeps track of the number of items and returns the range of rows that should be rendered.
+    /// The offset is adjusted so the selected row is in view whenever the selection changes.
+    pub(crate) fn visible_rows<F>(
+        &self,
+        item_count: Signal<usize>,
+        row_height: F,
+    ) -> Memo<Range<usize>>
+    where
+        F: Fn(usize) -> u16 + Send + Sync + 'static,
+    {
+        let this = *self;
+        let row_height: Arc<RowHeightFn> = Arc::new(row_height);
+        this.row_height.set_value(row_height.clone());
+        Effect::new(move || {
+            let count = item_count.get();
+            this.item_count.set(count);
+            if let Some(selected) = this.selected.get_untracked()
+                && selected >= count
+            {
+                this.selected.set(count.checked_sub(1));
+            }
+        });
+
+        // Scrolling doesn't track the selection, so the selected row is only brought into view
+        // when it changes
+        Effect::new({
+            let row_height = row_height.clone();
+            move || {
+                let selected = this.selected.get();
+                let viewport_height = this.viewport_height.get();
+                let offset = this.offset.get_untracked();
+                let start = visible_range(
+                    offset,
+                    selected,
+                    item_count.get_untracked(),
+                    viewport_height,
+                    &*row_height,
+                )
+                .start;
+                if start != offset {
+                    this.offset.set(start);
+                }
+            }
+        });
+
+        let visible = Memo::new(move |_| {
+            visible_range(
+                this.offset.get(),
+                None,
+                item_count.get(),
+                this.viewport_height.get(),
+                &*row_height,
+            )
+        });
+
+        Effect::new(move || {
+            let start = visible.get().start;
+            if this.offset.get_untracked() != start {
+                this.offset.set(start);
+            }
+        });
+
+        visible
+    }
+
+    pub(crate) fn set_viewport_height(&self, height: u16) {
+        if self.viewport_height.get_untracked() != height {
+            self.viewport_height.set(height);
+        }
+    }
+
+    /// Finds the index of the row at the given line, relative to the top of the visible area.
+    pub(crate) fn row_at<F>(&self, rows: Range<usize>, line: u16, row_height: F) -> Option<usize>
+    where
+        F: Fn(usize) -> u16,
+    {
+        let mut total_height = 0u16;
+        for i in rows {
+            total_height = total_height.saturating_add(row_height(i));
+            if line < total_height {
+                return Some(i);
+            }
+        }
+        None
+    }
+}
+
+fn visible_range<F>(
+    offset: usize,
+    selected: Option<usize>,
+    item_count: usize,
+    viewport_height: u16,
+    row_height: F,
+) -> Range<usize>
+where
+    F: Fn(usize) -> u16,
+{
+    if item_count == 0 || viewport_height == 0 {
+        return 0..0;
+    }
+    let mut offset = offset.min(item_count - 1);
+
+    if let Some(selected) = selected.map(|s| s.min(item_count - 1)) {
+        if selected < offset {
+            offset = selected;
+        } else {
+            // Walk backwards from the selected row to find the earliest offset that still keeps
+            // the selection in view
+            let mut first = selected;
+            let mut height = row_height(selected);
+            while first > offset {
+                let prev_height = row_height(first - 1);
+                if height.saturating_add(prev_height) > viewport_height {
+                    break;
+                }
+                height += prev_height;
+                first -= 1;
+            }
+            offset = first;
+        }
+    }
+
+    let mut end = offset;
+    let mut height = 0u16;
+    while end < item_count && height < viewport_height {
+        height = height.saturating_add(row_height(end));
+        end += 1;
+    }
+    offset..end
+}
+
+type RowFn = dyn Fn(usize) -> ListItem<'static> + Send + Sync;
+
+pub struct VirtualList {
+    style: Signal<Style>,
+    on_item_click: Box<dyn FnMut(usize)>,
+    on_key_down: Box<dyn KeyHandler>,
+    on_direct_focus: Box<dyn FnMut(FocusEvent, EventData, EventHandle)>,
+    on_direct_blur: Box<dyn FnMut(BlurEvent, EventData, EventHandle)>,
+    highlight_style: Signal<Style>,
+    block: Option<Signal<Block<'static>>>,
+    highlight_spacing: Signal<HighlightSpacing>,
+    highlight_symbol: Option<Signal<&'static str>>,
+    state: Option<VirtualListState>,
+    layout_props: LayoutProps,
+}
+
+impl Default for VirtualList {
+    fn default() -> Self {
+        Self {
+            style: Default::default(),
+            on_item_click: Box::new(move |_| {}),
+            on_key_down: Box::new(move |_| {}),
+            on_direct_focus: Box::new(move |_, _, _| {}),
+            on_direct_blur: Box::new(move |_, _, _| {}),
+            highlight_style: Style::default().into(),
+            block: Default::default(),
+            highlight_spacing: Default::default(),
+            highlight_symbol: Default::default(),
+            state: None,
+            layout_props: LayoutProps::default(),
+        }
+    }
+}
+
+impl UpdateLayoutProps for VirtualList {
+    fn layout_props(&self) -> LayoutProps {
+        self.layout_props.clone()
+    }
+
+    fn update_props(mut self, props: LayoutProps) -> Self {
+        self.layout_props = props;
+        self
+    }
+}
+
+impl VirtualList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn state(mut self, state: VirtualListState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    pub fn style<M>(mut self, style: impl IntoReactiveValue<Signal<Style>, M>) -> Self {
+        self.style = style.into_reactive_value();
+        self
+    }
+
+    pub fn on_item_click(mut self, on_item_click: impl FnMut(usize) + 'static) -> Self {
+        self.on_item_click = Box::new(on_item_click);
+        self
+    }
+
+    pub fn on_key_down(mut self, on_key_down: impl KeyHandler + 'static) -> Self {
+        self.on_key_down = Box::new(on_key_down);
+        self
+    }
+
+    pub fn on_direct_focus(
+        mut self,
+        on_direct_focus: impl FnMut(FocusEvent, EventData, EventHandle) + 'static,
+    ) -> Self {
+        self.on_direct_focus = Box::new(on_direct_focus);
+        self
+    }
+
+    pub fn on_direct_blur(
+        mut self,
+        on_blur: impl FnMut(BlurEvent, EventData, EventHandle) + 'static,
+    ) -> Self {
+        self.on_direct_blur = Box::new(on_blur);
+        self
+    }
+
+    pub fn highlight_style<M>(
+        mut self,
+        highlight_style: impl IntoReactiveValue<Signal<Style>, M>,
+    ) -> Self {
+        self.highlight_style = highlight_style.into_reactive_value();
+        self
+    }
+
+    pub fn block<M>(mut self, block: impl IntoReactiveValue<Signal<Block<'static>>, M>) -> Self {
+        self.block = Some(block.into_reactive_value());
+        self
+    }
+
+    pub fn highlight_spacing<M>(
+        mut self,
+        highlight_spacing: impl IntoReactiveValue<Signal<HighlightSpacing>, M>,
+    ) -> Self {
+        self.highlight_spacing = highlight_spacing.into_reactive_value();
+        self
+    }
+
+    pub fn highlight_symbol<M>(
+        mut self,
+        highlight_symbol: impl IntoReactiveValue<Signal<&'static str>, M>,
+    ) -> Self {
+        self.highlight_symbol = Some(highlight_symbol.into_reactive_value());
+        self
+    }
+
+    /// Renders a list of `item_count` rows. Only the rows that fit in the visible area are built
+    /// by calling `row` with the index of each row.
+    pub fn render<M, F>(
+        self,
+        item_count: impl IntoReactiveValue<Signal<usize>, M>,
+        row: F,
+    ) -> impl Render
+    where
+        F: Fn(usize) -> ListItem<'static> + Send + Sync + 'static,
+    {
+        let Self {
+            style,
+            mut on_item_click,
+            mut on_key_down,
+            on_direct_focus,
+            on_direct_blur,
+            highlight_style,
+            block,
+            highlight_spacing,
+            highlight_symbol,
+            state,
+            layout_props,
+        } = self;
+        let item_count: Signal<usize> = item_count.into_reactive_value();
+        let state = state.unwrap_or_default();
+        let row: Arc<RowFn> = Arc::new(row);
+
+        // Building a row just to measure it is expensive, so heights are cached. Rows that are
+        // rendered update their height, so the cache only goes stale for rows that are out of
+        // view.
+        let heights = StoredValue::new(HashMap::<usize, u16>::new());
+        Effect::new(move || {
+            item_count.track();
+            heights.update_value(|h| h.clear());
+        });
+        let row_height = {
+            let row = row.clone();
+            move |i: usize| {
+                if let Some(height) = heights.with_value(|h| h.get(&i).copied()) {
+                    return height;
+                }
+                let height = row(i).height() as u16;
+                heights.update_value(|h| {
+                    h.insert(i, height);
+                });
+                height
+            }
+        };
+        let visible_rows = state.visible_rows(item_count, row_height.clone());
+
+        let list_state = move || {
+            let visible = visible_rows.get();
+            ListState::default().with_selected(
+                state
+                    .selected
+                    .get()
+                    .filter(|s| visible.contains(s))
+                    .map(|s| s - visible.start),
+            )
+        };
+
+        wgt!(list_state(), {
+            let items: Vec<_> = visible_rows.get().map(|i| row(i)).collect();
+            heights.update_value(|h| {
+                for (i, item) in visible_rows.get_untracked().zip(&items) {
+                    h.insert(i, item.height() as u16);
+                }
+            });
+            let mut list = List::new(items)
+                .highlight_style(highlight_style.get())
+                .highlight_spacing(highlight_spacing.get())
+                .style(style.get());
+            if let Some(block) = &block {
+                list = list.block(block.get());
+            }
+            if let Some(highlight_symbol) = highlight_symbol {
+                list = list.highlight_symbol(highlight_symbol.get());
+            }
+            list
+        })
+        .on_size_change(move |rect: Rect| {
+            let inner = match &block {
+                Some(block) => block.with_untracked(|b| b.inner(rect)),
+                None => rect,
+            };
+            state.set_viewport_height(inner.height);
+        })
+        .on_click(move |props: ClickEventProps| {
+            let inner_y = match &block {
+                Some(block) => block.with_untracked(|b| b.inner(props.data.rect).y),
+                None => props.data.rect.y,
+            };
+            let Some(line) = props.event.row.checked_sub(inner_y) else {
+                return;
+            };
+            let clicked = state.row_at(visible_rows.get_untracked(), line, &row_height);
+            if let Some(clicked) = clicked {
+                state.select(Some(clicked));
+                on_item_click(clicked);
+            }
+        })
+        .layout_props(layout_props)
+        .on_key_down(move |mut props: KeyEventProps| {
+            on_key_down.handle(props.clone());
+            if props.handle.get_stop_propagation() {
+                return;
+            }
+            state.handle_key(&mut props);
+        })
+        .on_scroll(move |direction, _, _| state.handle_scroll(direction))
+        .on_direct_focus(on_direct_focus)
+        .on_direct_blur(on_direct_blur)
+    }
+}
@@ -0,0 +1,289 @@
+use std::sync::Arc;
+
+use ratatui::layout::{Constraint, Rect};
+use ratatui::widgets::{Block, HighlightSpacing, Row, Table, TableState};
+use rooibos_dom::events::{
+    BlurEvent, ClickEventProps, EventData, EventHandle, FocusEvent, KeyEventProps, KeyHandler,
+};
+use rooibos_reactive::dom::{LayoutProps, Render, UpdateLayoutProps};
+use rooibos_reactive::graph::IntoReactiveValue;
+use rooibos_reactive::graph::traits::{Get, GetUntracked, WithUntracked};
+use rooibos_reactive::graph::wrappers::read::Signal;
+use rooibos_reactive::wgt;
+use rooibos_theme::Style;
+
+use crate::VirtualListState;
+
+type RowFn = dyn Fn(usize) -> Row<'static> + Send + Sync;
+
+pub struct VirtualTable {
+    style: Signal<Style>,
+    on_row_click: Box<dyn FnMut(usize)>,
+    on_key_down: Box<dyn KeyHandler>,
+    on_direct_focus: Box<dyn FnMut(FocusEvent, EventData, EventHandle)>,
+    on_direct_blur: Box<dyn FnMut(BlurEvent, EventData, EventHandle)>,
+    row_highlight_style: Signal<Style>,
+    block: Option<Signal<Block<'static>>>,
+    header: Option<Signal<Row<'static>>>,
+    header_height: Signal<u16>,
+    widths: Signal<Vec<Constraint>>,
+    column_spacing: Signal<u16>,
+    row_height: Signal<u16>,
+    highlight_spacing: Signal<HighlightSpacing>,
+    highlight_symbol: Option<Signal<&'static str>>,
+    state: Option<VirtualListState>,
+    layout_props: LayoutProps,
+}
+
+impl Default for VirtualTable {
+    fn default() -> Self {
+        Self {
+            style: Default::default(),
+            on_row_click: Box::new(move |_| {}),
+            on_key_down: Box::new(move |_| {}),
+            on_direct_focus: Box::new(move |_, _, _| {}),
+            on_direct_blur: Box::new(move |_, _, _| {}),
+            row_highlight_style: Style::default().into(),
+            block: Default::default(),
+            header: Default::default(),
+            header_height: 1.into(),
+            widths: Default::default(),
+            column_spacing: 1.into(),
+            row_height: 1.into(),
+            highlight_spacing: Default::default(),
+            highlight_symbol: Default::default(),
+            state: None,
+            layout_props: LayoutProps::default(),
+        }
+    }
+}
+
+impl UpdateLayoutProps for VirtualTable {
+    fn layout_props(&self) -> LayoutProps {
+        self.layout_props.clone()
+    }
+
+    fn update_props(mut self, props: LayoutProps) -> Self {
+        self.layout_props = props;
+        self
+    }
+}
+
+impl VirtualTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn state(mut self, state: VirtualListState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    pub fn style<M>(mut self, style: impl IntoReactiveValue<Signal<Style>, M>) -> Self {
+        self.style = style.into_reactive_value();
+        self
+    }
+
+    pub fn on_row_click(mut self, on_row_click: impl FnMut(usize) + 'static) -> Self {
+        self.on_row_click = Box::new(on_row_click);
+        self
+    }
+
+    pub fn on_key_down(mut self, on_key_down: impl KeyHandler + 'static) -> Self {
+        self.on_key_down = Box::new(on_key_down);
+        self
+    }
+
+    pub fn on_direct_focus(
+        mut self,
+        on_direct_focus: impl FnMut(FocusEvent, EventData, EventHandle) + 'static,
+    ) -> Self {
+        self.on_direct_focus = Box::new(on_direct_focus);
+        self
+    }
+
+    pub fn on_direct_blur(
+        mut self,
+        on_blur: impl FnMut(BlurEvent, EventData, EventHandle) + 'static,
+    ) -> Self {
+        self.on_direct_blur = Box::new(on_blur);
+        self
+    }
+
+    pub fn row_highlight_style<M>(
+        mut self,
+        row_highlight_style: impl IntoReactiveValue<Signal<Style>, M>,
+    ) -> Self {
+        self.row_highlight_style = row_highlight_style.into_reactive_value();
+        self
+    }
+
+    pub fn block<M>(mut self, block: impl IntoReactiveValue<Signal<Block<'static>>, M>) -> Self {
+        self.block = Some(block.into_reactive_value());
+        self
+    }
+
+    pub fn header<M>(mut self, header: impl IntoReactiveValue<Signal<Row<'static>>, M>) -> Self {
+        self.header = Some(header.into_reactive_value());
+        self
+    }
+
+    /// Height of the header row. Ratatui doesn't expose the height of a [`Row`], so this needs
+    /// to be set if the header takes up more than one line.
+    pub fn header_height<M>(
+        mut self,
+        header_height: impl IntoReactiveValue<Signal<u16>, M>,
+    ) -> Self {
+        self.header_height = header_height.into_reactive_value();
+        self
+    }
+
+    pub fn widths<M>(mut self, widths: impl IntoReactiveValue<Signal<Vec<Constraint>>, M>) -> Self {
+        self.widths = widths.into_reactive_value();
+        self
+    }
+
+    pub fn column_spacing<M>(
+        mut self,
+        column_spacing: impl IntoReactiveValue<Signal<u16>, M>,
+    ) -> Self {
+        self.column_spacing = column_spacing.into_reactive_value();
+        self
+    }
+
+    /// Height of each row. All rows in a virtual table have the same height so the visible range
+    /// can be computed without building every row.
+    pub fn row_height<M>(mut self, row_height: impl IntoReactiveValue<Signal<u16>, M>) -> Self {
+        self.row_height = row_height.into_reactive_value();
+        self
+    }
+
+    pub fn highlight_spacing<M>(
+        mut self,
+        highlight_spacing: impl IntoReactiveValue<Signal<HighlightSpacing>, M>,
+    ) -> Self {
+        self.highlight_spacing = highlight_spacing.into_reactive_value();
+        self
+    }
+
+    pub fn highlight_symbol<M>(
+        mut self,
+        highlight_symbol: impl IntoReactiveValue<Signal<&'static str>, M>,
+    ) -> Self {
+        self.highlight_symbol = Some(highlight_symbol.into_reactive_value());
+        self
+    }
+
+    /// Renders a table of `row_count` rows. Only the rows that fit in the visible area are built
+    /// by calling `row` with the index of each row.
+    pub fn render<M, F>(
+        self,
+        row_count: impl IntoReactiveValue<Signal<usize>, M>,
+        row: F,
+    ) -> impl Render
+    where
+        F: Fn(usize) -> Row<'static> + Send + Sync + 'static,
+    {
+        let Self {
+            style,
+            mut on_row_click,
+            mut on_key_down,
+            on_direct_focus,
+            on_direct_blur,
+            row_highlight_style,
+            block,
+            header,
+            header_height,
+            widths,
+            column_spacing,
+            row_height,
+            highlight_spacing,
+            highlight_symbol,
+            state,
+            layout_props,
+        } = self;
+        let row_count: Signal<usize> = row_count.into_reactive_value();
+        let state = state.unwrap_or_default();
+        let row: Arc<RowFn> = Arc::new(row);
+
+        let visible_rows = state.visible_rows(row_count, move |_| row_height.get().max(1));
+
+        let table_state = move || {
+            let visible = visible_rows.get();
+            TableState::default().with_selected(
+                state
+                    .selected()
+                    .get()
+                    .filter(|s| visible.contains(s))
+                    .map(|s| s - visible.start),
+            )
+        };
+
+        let header_height = move || {
+            if header.is_some() {
+                header_height.get_untracked()
+            } else {
+                0
+            }
+        };
+
+        wgt!(table_state(), {
+            let height = row_height.get().max(1);
+            let mut table = Table::new(
+                visible_rows.get().map(|i| row(i).height(height)),
+                widths.get(),
+            )
+            .row_highlight_style(row_highlight_style.get())
+            .highlight_spacing(highlight_spacing.get())
+            .column_spacing(column_spacing.get())
+            .style(style.get());
+            if let Some(header) = header {
+                table = table.header(header.get().height(header_height.get()));
+            }
+            if let Some(block) = &block {
+                table = table.block(block.get());
+            }
+            if let Some(highlight_symbol) = highlight_symbol {
+                table = table.highlight_symbol(highlight_symbol.get());
+            }
+            table
+        })
+        .on_size_change(move |rect: Rect| {
+            let inner = match &block {
+                Some(block) => block.with_untracked(|b| b.inner(rect)),
+                None => rect,
+            };
+            state.set_viewport_height(inner.height.saturating_sub(header_height()));
+        })
+        .on_click(move |props: ClickEventProps| {
+            let inner_y = match &block {
+                Some(block) => block.with_untracked(|b| b.inner(props.data.rect).y),
+                None => props.data.rect.y,
+            };
+            let Some(line) = props
+                .event
+                .row
+                .checked_sub(inner_y.saturating_add(header_height()))
+            else {
+                return;
+            };
+            let height = row_height.get_untracked().max(1);
+            let clicked = state.row_at(visible_rows.get_untracked(), line, |_| height);
+            if let Some(clicked) = clicked {
+                state.select(Some(clicked));
+                on_row_click(clicked);
+            }
+        })
+        .layout_props(layout_props)
+        .on_key_down(move |mut props: KeyEventProps| {
+            on_key_down.handle(props.clone());
+            if props.handle.get_stop_propagation() {
+                return;
+            }
+            state.handle_key(&mut props);
+        })
+        .on_scroll(move |direction, _, _| state.handle_scroll(direction))
+        .on_direct_focus(on_direct_focus)
+        .on_direct_blur(on_direct_blur)
+    }
+}
@@ -0,0 +1,124 @@
+//! Source of time for timers and animations.
+//!
+//! Time comes from the system clock by default. Tests can switch the current thread to a virtual
+//! clock with [`enable_virtual`] so that time only moves forward when it's explicitly advanced,
+//! and switch back with [`disable_virtual`].
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use tokio::sync::oneshot;
+
+thread_local! {
+    static VIRTUAL_CLOCK: RefCell<Option<VirtualClock>> = const { RefCell::new(None) };
+}
+
+struct VirtualClock {
+    // Real time when the clock was enabled, so timestamps from now() stay close to the real
+    // values
+    start_millis: f64,
+    elapsed: Duration,
+    next_id: usize,
+    // Timers are sent the time they still have left to wait on the system clock, which is zero
+    // unless the virtual clock was disabled before they fired
+    timers: BTreeMap<(Duration, usize), oneshot::Sender<Duration>>,
+}
+
+/// Switches the current thread to a virtual clock that starts at the current time.
+///
+/// Timers started before this is called still use the system clock.
+pub fn enable_virtual() {
+    VIRTUAL_CLOCK.with(|c| {
+        c.borrow_mut().get_or_insert_with(|| VirtualClock {
+            start_millis: wasm_compat::time::now(),
+            elapsed: Duration::ZERO,
+            next_id: 0,
+            timers: BTreeMap::new(),
+        });
+    });
+}
+
+/// Switches the current thread back to the system clock.
+///
+/// Timers that are waiting on the virtual clock wait for the rest of their duration on the system
+/// clock instead.
+pub fn disable_virtual() {
+    let Some(clock) = VIRTUAL_CLOCK.with(|c| c.borrow_mut().take()) else {
+        return;
+    };
+    for ((deadline, _), tx) in clock.timers {
+        let _ = tx.send(deadline - clock.elapsed);
+    }
+}
+
+/// Returns `true` if the current thread is using a virtual clock.
+pub fn is_virtual() -> bool {
+    VIRTUAL_CLOCK.with(|c| c.borrow().is_some())
+}
+
+/// Time that has passed on the virtual clock since it was enabled.
+pub fn virtual_elapsed() -> Option<Duration> {
+    VIRTUAL_CLOCK.with(|c| c.borrow().as_ref().map(|c| c.elapsed))
+}
+
+/// Time on the virtual clock when the next pending timer fires.
+pub fn next_deadline() -> Option<Duration> {
+    VIRTUAL_CLOCK.with(|c| {
+        let mut clock = c.borrow_mut();
+        let clock = clock.as_mut()?;
+        // Timers that were dropped before firing don't need to be woken up
+        clock.timers.retain(|_, tx| !tx.is_closed());
+        clock.timers.keys().next().map(|(deadline, _)| *deadline)
+    })
+}
+
+/// Moves the virtual clock forward to `elapsed` and fires any timers that are due. The clock
+/// never moves backwards.
+///
+/// Timers are only woken up here. The tasks waiting on them run the next time the executor
+/// polls them.
+pub fn advance_to(elapsed: Duration) {
+    VIRTUAL_CLOCK.with(|c| {
+        let mut clock = c.borrow_mut();
+        let Some(clock) = clock.as_mut() else {
+            return;
+        };
+        clock.elapsed = clock.elapsed.max(elapsed);
+        let pending = clock.timers.split_off(&(clock.elapsed, usize::MAX));
+        for (_, tx) in std::mem::replace(&mut clock.timers, pending) {
+            let _ = tx.send(Duration::ZERO);
+        }
+    });
+}
+
+/// Waits until `duration` has passed.
+pub async fn sleep(duration: Duration) {
+    let timer = VIRTUAL_CLOCK.with(|c| {
+        let mut clock = c.borrow_mut();
+        let clock = clock.as_mut()?;
+        let (tx, rx) = oneshot::channel();
+        let id = clock.next_id;
+        clock.next_id += 1;
+        clock.timers.insert((clock.elapsed + duration, id), tx);
+        Some(rx)
+    });
+    match timer {
+        Some(timer) => {
+            if let Ok(remaining) = timer.await
+                && !remaining.is_zero()
+            {
+                wasm_compat::futures::sleep(remaining).await;
+            }
+        }
+        None => wasm_compat::futures::sleep(duration).await,
+    }
+}
+
+/// Current time in milliseconds.
+pub fn now() -> f64 {
+    VIRTUAL_CLOCK.with(|c| match c.borrow().as_ref() {
+        Some(clock) => clock.start_millis + clock.elapsed.as_secs_f64() * 1000.0,
+        None => wasm_compat::time::now(),
+    })
+}
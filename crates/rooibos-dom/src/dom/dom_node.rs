@@ -5,7 +5,7 @@ use std::sync::atomic::{AtomicBool, Ordering};
 
 use ratatui::Frame;
 use ratatui::layout::{Position, Rect};
-use terminput::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use terminput::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind, ScrollDirection};
 
 use super::{DomNodeKey, FocusMode, FocusScope, NodeProperties, RenderProps, unmount_child};
 use crate::events::{
@@ -37,13 +37,13 @@ impl AsDomNode for Box<dyn AsDomNode> {
     }
 }
 
-#[derive(Clone, PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 enum NodeIdInner {
     Auto(u32),
     Manual(String),
 }
 
-#[derive(Clone, PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct NodeId(NodeIdInner);
 
 impl NodeId {
@@ -132,6 +132,9 @@ pub struct DomNodeRepr {
     key: DomNodeKey,
     rect: Rect,
     node_type: NodeTypeRepr,
+    id: Option<NodeId>,
+    role: Option<Role>,
+    label_for: Option<NodeId>,
 }
 
 impl DomNodeRepr {
@@ -147,6 +150,12 @@ impl DomNodeRepr {
                 NodeType::Widget(_) => NodeTypeRepr::Widget,
                 NodeType::Placeholder => NodeTypeRepr::Placeholder,
             },
+            id: node.id.clone(),
+            role: match &node.node_type {
+                NodeType::Widget(widget_node) => widget_node.role,
+                _ => None,
+            },
+            label_for: node.label_for.clone(),
         }
     }
 
@@ -158,6 +167,19 @@ impl DomNodeRepr {
         self.node_type.clone()
     }
 
+    pub fn id(&self) -> Option<NodeId> {
+        self.id.clone()
+    }
+
+    pub fn role(&self) -> Option<Role> {
+        self.role
+    }
+
+    /// The id of the node this node is a label for.
+    pub fn label_for(&self) -> Option<NodeId> {
+        self.label_for.clone()
+    }
+
     pub fn find<F>(&self, mut f: F) -> Option<DomNodeRepr>
     where
         F: FnMut(&DomNodeRepr) -> bool,
@@ -572,6 +594,14 @@ impl DomNode {
         self
     }
 
+    pub fn on_scroll<F>(self, handler: F) -> Self
+    where
+        F: FnMut(ScrollDirection, EventData, EventHandle) + 'static,
+    {
+        self.update_event_handlers(|h| h.on_scroll(handler));
+        self
+    }
+
     pub fn on_state_change<F>(self, handler: F) -> Self
     where
         F: FnMut(StateChangeEvent, EventData, EventHandle) + 'static,
@@ -616,6 +646,13 @@ impl DomNode {
         self
     }
 
+    pub fn label_for(self, id: impl Into<NodeId>) -> Self {
+        with_nodes_mut(|n| {
+            n.set_label_for(self.key, Some(id.into()));
+        });
+        self
+    }
+
     pub fn focusable(self, focusable: bool) -> Self {
         with_nodes_mut(|nodes| {
             nodes.set_focus_mode(
@@ -695,6 +732,10 @@ impl DomNode {
     }
 
     pub fn render(&self, window: Rect, frame: &mut Frame) {
+        self.render_damaged(window, frame, None);
+    }
+
+    pub(crate) fn render_damaged(&self, window: Rect, frame: &mut Frame, damage: Option<&[Rect]>) {
         with_nodes(|nodes| {
             nodes[self.key].render(RenderProps {
                 frame,
@@ -703,6 +744,9 @@ impl DomNode {
                 parent_scroll_offset: Position::ORIGIN,
                 key: self.key,
                 dom_nodes: nodes,
+                damage,
+                // Focusable nodes can only change when the whole tree is redrawn
+                register_focusables: damage.is_none(),
             });
         });
         reset_mouse_position();
@@ -15,7 +15,7 @@ use wasm_compat::cell::BoolCell;
 
 use super::{DomNodeKey, with_nodes_mut};
 use crate::widgets::{Role, WidgetRole};
-use crate::{next_node_id, refresh_dom};
+use crate::{next_node_id, refresh_node};
 
 pub trait BuildNodeRenderer {
     type Output: RenderNode + MeasureNode + WidgetRole + 'static;
@@ -397,7 +397,7 @@ impl DomWidgetNode {
     pub fn build(&self) {
         let props = (self.build_render_node)();
         *self.widget_fn.borrow_mut() = props;
-        refresh_dom();
+        refresh_node(self.key);
     }
 
     pub fn estimate_size(&self) {
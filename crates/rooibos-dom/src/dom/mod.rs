@@ -9,6 +9,8 @@ pub use node_properties::*;
 pub use node_tree::*;
 use ratatui::Frame;
 use ratatui::backend::Backend;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Position;
 use ratatui::text::Line;
 use ratatui::widgets::{Paragraph, Widget, Wrap};
 use tokio::sync::watch;
@@ -20,6 +22,9 @@ mod dom_node;
 mod dom_widget;
 mod node_properties;
 mod node_tree;
+#[cfg(test)]
+#[path = "./render_test.rs"]
+mod render_test;
 
 // Reference for focus impl https://github.com/reactjs/rfcs/pull/109/files
 
@@ -45,6 +50,8 @@ thread_local! {
     };
     static PRINT_DOM: AtomicBool = const { AtomicBool::new(false) };
     static PENDING_RESIZE: AtomicBool = const { AtomicBool::new(true) };
+    static FULL_REDRAW: AtomicBool = const { AtomicBool::new(true) };
+    static LAST_FRAME: RefCell<Option<Buffer>> = const { RefCell::new(None) };
     static PENDING_EVENTS: RefCell<Vec<Event>> = const { RefCell::new(Vec::new()) };
     static TREE_UPDATES: RefCell<Vec<TreeUpdate>> = const { RefCell::new(Vec::new()) };
     static ON_WINDOW_FOCUS_CHANGE: RefCell<Box<dyn FnMut(bool)>> = {
@@ -125,6 +132,9 @@ pub fn cleanup_removed_nodes(node: &DomNodeKey, remove: bool) {
         // We need to make sure we drop the removed node after we release the borrow
         // because the drop impl needs to borrow the node list as well
         drop(removed);
+        // Nodes with a z-index are mounted as their own root
+        let root = with_nodes_mut(|nodes| nodes.take_root(*node));
+        drop(root);
     }
 }
 
@@ -148,9 +158,11 @@ pub fn unmount_child(child: DomNodeKey, cleanup: bool) {
 }
 
 pub fn print_dom() -> Paragraph<'static> {
-    let lines =
-        with_nodes(|nodes| print_dom_inner(nodes, nodes.root(0).as_dom_node().get_key(), ""));
-    Paragraph::new(lines.clone()).wrap(Wrap { trim: false })
+    Paragraph::new(print_dom_lines()).wrap(Wrap { trim: false })
+}
+
+pub fn print_dom_lines() -> Vec<Line<'static>> {
+    with_nodes(|nodes| print_dom_inner(nodes, nodes.root(0).as_dom_node().get_key(), ""))
 }
 
 pub fn root() -> DomNodeRepr {
@@ -173,6 +185,17 @@ fn print_dom_inner(dom_ref: &NodeTree, key: DomNodeKey, indent: &str) -> Vec<Lin
         "{indent}<{node_name} type={name} key={key:?} parent={:?}",
         node.parent
     );
+    if let Some(id) = &node.id {
+        line += &format!(" id={id}");
+    }
+    if let NodeType::Widget(widget) = &node.node_type
+        && let Some(role) = widget.role
+    {
+        line += &format!(" role={role:?}");
+    }
+    if let Some(label_for) = &node.label_for {
+        line += &format!(" label_for={label_for}");
+    }
 
     line += &format!(" display={:?}", dom_ref.try_style(key).map(|s| s.display));
     line += &format!(" layout={:?}>", dom_ref.try_rect(key));
@@ -191,7 +214,24 @@ fn print_dom_inner(dom_ref: &NodeTree, key: DomNodeKey, indent: &str) -> Vec<Lin
     lines
 }
 
+/// Marks the entire DOM as damaged and schedules a re-render. This should be used for any change
+/// that can affect the layout or the structure of the tree.
 pub(crate) fn refresh_dom() {
+    FULL_REDRAW.with(|f| f.store(true, Ordering::Relaxed));
+    notify_dom_update();
+}
+
+/// Marks only the area occupied by `key` as damaged and schedules a re-render.
+pub(crate) fn refresh_node(key: DomNodeKey) {
+    if try_with_nodes(|nodes| nodes.mark_dirty(key)).is_none() {
+        // The tree is currently being modified so we can't know which areas will be affected
+        refresh_dom();
+        return;
+    }
+    notify_dom_update();
+}
+
+pub(crate) fn notify_dom_update() {
     DOM_UPDATE_TX.with(|tx| {
         tx.borrow().send_modify(|_| {});
     });
@@ -206,6 +246,7 @@ where
 
 pub fn unmount() {
     with_nodes_mut(|d| *d = NodeTree::new());
+    refresh_dom();
 }
 
 pub fn render_dom(frame: &mut Frame) {
@@ -232,18 +273,59 @@ pub fn render_dom(frame: &mut Frame) {
 
     if PRINT_DOM.with(|p| p.load(Ordering::Relaxed)) {
         print_dom().render(buf.area, buf);
-    } else {
-        with_nodes_mut(|nodes| {
-            nodes.recompute_full_layout(render_size);
-            nodes.clear_focusables();
-        });
+        // The DOM output overwrites the whole screen, so we can't reuse this frame
+        LAST_FRAME.with(|f| *f.borrow_mut() = None);
+        return;
+    }
+
+    let window_area = *frame.buffer_mut().area();
+    let full_redraw = FULL_REDRAW.with(|f| f.swap(false, Ordering::Relaxed));
+    let damage = LAST_FRAME.with(|last_frame| {
+        let mut last_frame = last_frame.borrow_mut();
+        match last_frame.as_mut() {
+            Some(last_frame) if !full_redraw && last_frame.area == window_area => {
+                // Start from the contents of the previous frame and only redraw what changed
+                mem::swap(frame.buffer_mut(), last_frame);
+                Some(with_nodes(|nodes| nodes.take_damage()))
+            }
+            _ => {
+                with_nodes(|nodes| nodes.clear_damage());
+                None
+            }
+        }
+    });
 
+    match &damage {
+        Some(damage) => {
+            let buf = frame.buffer_mut();
+            for region in damage {
+                for pos in region.intersection(buf.area).positions() {
+                    buf[pos].reset();
+                }
+            }
+        }
+        None => {
+            with_nodes_mut(|nodes| {
+                nodes.recompute_full_layout(render_size);
+                nodes.clear_focusables();
+            });
+        }
+    }
+
+    if damage.as_ref().is_none_or(|d| !d.is_empty()) {
         let roots = with_nodes(|nodes| nodes.roots_asc());
-        let window_area = *frame.buffer_mut().area();
         for root in roots {
-            root.render(window_area, frame);
+            root.render_damaged(window_area, frame, damage.as_deref());
         }
     }
+
+    LAST_FRAME.with(|last_frame| {
+        let mut last_frame = last_frame.borrow_mut();
+        match last_frame.as_mut() {
+            Some(last_frame) => last_frame.clone_from(frame.buffer_mut()),
+            None => *last_frame = Some(frame.buffer_mut().clone()),
+        }
+    });
 }
 
 pub async fn render_terminal<B>(terminal: &mut NonblockingTerminal<B>) -> Result<(), io::Error>
@@ -298,6 +380,71 @@ pub fn try_focus_id(id: impl Into<NodeId>) -> Result<(), NodeNotFound> {
     })
 }
 
+pub fn focus_first_child(id: impl Into<NodeId>) {
+    try_focus_first_child(id).expect("node not found")
+}
+
+/// Focuses the first focusable node inside of the node with the given id.
+/// Focus is cleared if there are no focusable nodes so that key events are sent to the roots.
+pub fn try_focus_first_child(id: impl Into<NodeId>) -> Result<(), NodeNotFound> {
+    let id = id.into();
+    let key = with_nodes(|nodes| nodes.get_key(id.clone())).ok_or(NodeNotFound(id))?;
+    let first = key.traverse(
+        |key, inner| inner.focusable().then_some(key),
+        MatchBehavior::StopOnFistMatch,
+    );
+    with_nodes_mut(|nodes| {
+        nodes.set_focused(first.first().copied());
+        nodes.scroll_focused_into_view();
+    });
+    Ok(())
+}
+
+/// Returns the key of the currently focused node so it can be passed to [`restore_focus`] later.
+pub fn focused_key() -> Option<DomNodeKey> {
+    with_nodes(|nodes| nodes.focused_key())
+}
+
+/// Moves focus back to a node returned from [`focused_key`].
+/// Focus is cleared if the node has been removed since then.
+pub fn restore_focus(key: Option<DomNodeKey>) {
+    with_nodes_mut(|nodes| {
+        let key = key.filter(|key| nodes.contains_key(*key) && nodes[*key].focusable());
+        nodes.set_focused(key);
+        nodes.scroll_focused_into_view();
+    })
+}
+
+pub fn scroll_to(id: impl Into<NodeId>, offset: Position) {
+    try_scroll_to(id, offset).expect("node not found")
+}
+
+pub fn try_scroll_to(id: impl Into<NodeId>, offset: Position) -> Result<(), NodeNotFound> {
+    let id = id.into();
+    with_nodes_mut(|nodes| {
+        let key = nodes.get_key(id.clone()).ok_or(NodeNotFound(id))?;
+        nodes.scroll_to(key, offset);
+        Ok(())
+    })
+}
+
+pub fn scroll_by(id: impl Into<NodeId>, x: i32, y: i32) {
+    try_scroll_by(id, x, y).expect("node not found")
+}
+
+pub fn try_scroll_by(id: impl Into<NodeId>, x: i32, y: i32) -> Result<(), NodeNotFound> {
+    let id = id.into();
+    with_nodes_mut(|nodes| {
+        let key = nodes.get_key(id.clone()).ok_or(NodeNotFound(id))?;
+        nodes.scroll_by(key, x, y);
+        Ok(())
+    })
+}
+
+pub fn scroll_position(id: impl Into<NodeId>) -> Option<ScrollPosition> {
+    with_nodes(|nodes| nodes.get_key(id).map(|key| nodes.scroll_position(key)))
+}
+
 pub fn focus_accesskit_id(id: accesskit::NodeId) {
     with_nodes_mut(|nodes| {
         let found_node = nodes.iter_layout_nodes().find_map(|(key, layout_key, _)| {
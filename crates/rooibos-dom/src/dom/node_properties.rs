@@ -17,7 +17,9 @@ use tachyonfx::EffectRenderer;
 use terminput::ScrollDirection;
 
 use super::node_tree::{DomNodeKey, NodeTree};
-use super::{ContentRect, NodeId, NodeType, next_node_id, refresh_dom};
+#[cfg(feature = "effects")]
+use super::notify_dom_update;
+use super::{ContentRect, NodeId, NodeType, next_node_id};
 use crate::Borders;
 use crate::events::EventHandlers;
 
@@ -36,6 +38,14 @@ impl FocusScope {
             id: next_node_id(),
         }
     }
+
+    pub(crate) fn contain(&self) -> bool {
+        self.contain
+    }
+
+    pub(crate) fn set_contain(&mut self, contain: bool) {
+        self.contain = contain;
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -76,6 +86,7 @@ pub struct NodeProperties {
     pub(crate) parent: Option<DomNodeKey>,
     pub(crate) id: Option<NodeId>,
     pub(crate) class: Vec<String>,
+    pub(crate) label_for: Option<NodeId>,
     pub(crate) focus_mode: FocusMode,
     #[educe(Debug(ignore))]
     pub(crate) event_handlers: EventHandlers,
@@ -87,6 +98,7 @@ pub struct NodeProperties {
     pub(crate) scroll_offset: Position,
     pub(crate) ancestor_scroll_offset: Position,
     pub(crate) max_scroll_offset: Position,
+    pub(crate) stick_to_bottom: bool,
     #[cfg(feature = "effects")]
     pub(crate) effects: Option<RefCell<EffectProperties>>,
     #[educe(Default = true)]
@@ -98,6 +110,23 @@ pub struct NodeProperties {
     pub(crate) visible: AtomicBool,
 }
 
+/// Scroll state of a node with `overflow: scroll`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ScrollPosition {
+    pub offset: Position,
+    pub max_offset: Position,
+}
+
+impl ScrollPosition {
+    pub fn at_top(&self) -> bool {
+        self.offset.y == 0
+    }
+
+    pub fn at_bottom(&self) -> bool {
+        self.offset.y >= self.max_offset.y
+    }
+}
+
 pub(crate) struct RenderProps<'a, 'b> {
     pub(crate) frame: &'a mut Frame<'b>,
     pub(crate) window: Rect,
@@ -105,13 +134,16 @@ pub(crate) struct RenderProps<'a, 'b> {
     pub(crate) parent_scroll_offset: Position,
     pub(crate) key: DomNodeKey,
     pub(crate) dom_nodes: &'a NodeTree,
+    /// Regions of the screen that need to be redrawn. `None` means everything is redrawn.
+    pub(crate) damage: Option<&'a [Rect]>,
+    pub(crate) register_focusables: bool,
 }
 
 #[cfg(feature = "effects")]
 #[derive(Debug, Clone)]
 pub struct EffectProperties {
     effect: tachyonfx::Effect,
-    last_tick: std::time::Instant,
+    last_tick: f64,
 }
 
 #[cfg(feature = "effects")]
@@ -119,13 +151,14 @@ impl EffectProperties {
     pub(crate) fn new(effect: tachyonfx::Effect) -> Self {
         Self {
             effect,
-            last_tick: std::time::Instant::now(),
+            last_tick: crate::clock::now(),
         }
     }
 
     fn tick(&mut self) -> tachyonfx::Duration {
-        let elapsed = self.last_tick.elapsed();
-        self.last_tick = std::time::Instant::now();
+        let now = crate::clock::now();
+        let elapsed = std::time::Duration::from_secs_f64((now - self.last_tick).max(0.0) / 1000.0);
+        self.last_tick = now;
         elapsed.into()
     }
 }
@@ -179,22 +212,37 @@ impl NodeProperties {
 
     pub(crate) fn scroll(&mut self, direction: ScrollDirection) -> taffy::Point<i32> {
         let delta = direction.delta();
-        let mut x = self.scroll_offset.x as i32 - delta.x;
-        let mut y = self.scroll_offset.y as i32 - delta.y;
+        self.scroll_by(-delta.x, -delta.y)
+    }
+
+    pub(crate) fn scroll_by(&mut self, x: i32, y: i32) -> taffy::Point<i32> {
+        let x = (self.scroll_offset.x as i32 + x).clamp(0, u16::MAX as i32) as u16;
+        let y = (self.scroll_offset.y as i32 + y).clamp(0, u16::MAX as i32) as u16;
+        self.set_scroll_offset(Position::new(x, y))
+    }
 
-        x = x.min(self.max_scroll_offset.x as i32).max(0);
-        y = y.min(self.max_scroll_offset.y as i32).max(0);
+    /// Sets the scroll offset, clamped to the max offset, and returns how much it changed.
+    pub(crate) fn set_scroll_offset(&mut self, offset: Position) -> taffy::Point<i32> {
+        let x = offset.x.min(self.max_scroll_offset.x);
+        let y = offset.y.min(self.max_scroll_offset.y);
 
-        let x_change = x - self.scroll_offset.x as i32;
-        let y_change = y - self.scroll_offset.y as i32;
-        self.scroll_offset.x = x as u16;
-        self.scroll_offset.y = y as u16;
+        let x_change = x as i32 - self.scroll_offset.x as i32;
+        let y_change = y as i32 - self.scroll_offset.y as i32;
+        self.scroll_offset.x = x;
+        self.scroll_offset.y = y;
         taffy::Point {
             x: x_change,
             y: y_change,
         }
     }
 
+    pub(crate) fn scroll_position(&self) -> ScrollPosition {
+        ScrollPosition {
+            offset: self.scroll_offset,
+            max_offset: self.max_scroll_offset,
+        }
+    }
+
     pub(crate) fn update_ancestor_scroll_offsets(&mut self, change: taffy::Point<i32>) {
         self.ancestor_scroll_offset.x =
             (self.ancestor_scroll_offset.x as i32 + change.x).max(0) as u16;
@@ -210,6 +258,8 @@ impl NodeProperties {
             parent_bounds,
             parent_scroll_offset,
             dom_nodes,
+            damage,
+            register_focusables,
         } = props;
 
         let prev_rect = *self.rect.borrow();
@@ -222,16 +272,22 @@ impl NodeProperties {
                     parent_scroll_offset,
                     key: *key,
                     dom_nodes,
+                    damage,
+                    register_focusables,
                 });
             });
             return;
         };
 
-        if self.focusable() {
+        if self.focusable() && register_focusables {
             dom_nodes.add_focusable(key, self.focus_mode);
         }
 
         let render_bounds = content_rect.render_bounds();
+        // Nodes outside of the damaged regions still hold their contents from the previous
+        // frame, so we only need to descend into their children
+        let damaged =
+            damage.is_none_or(|damage| damage.iter().any(|r| r.intersects(render_bounds)));
 
         let height_above_parent = (render_bounds.y + render_bounds.height)
             .saturating_sub(parent_bounds.y + parent_bounds.height)
@@ -251,7 +307,7 @@ impl NodeProperties {
             < parent_bounds.x + parent_bounds.width + parent_scroll_offset.x)
             && (visible_bounds.y < parent_bounds.y + parent_bounds.height + parent_scroll_offset.y);
 
-        if self.clear {
+        if self.clear && damaged {
             Clear.render(render_bounds, frame.buffer_mut());
         }
 
@@ -261,6 +317,7 @@ impl NodeProperties {
         if needs_render {
             self.visible.store(true, Ordering::Relaxed);
             match &self.node_type {
+                NodeType::Layout if !damaged && temp_buf.is_some() => {}
                 NodeType::Layout => {
                     if let Some(temp_buf) = &mut temp_buf {
                         temp_buf.area.x = visible_bounds.x;
@@ -283,6 +340,8 @@ impl NodeProperties {
                                 }
                             }
 
+                            // The temporary buffer starts out empty, so the whole subtree needs to
+                            // be drawn
                             dom_nodes[*key].render(RenderProps {
                                 frame,
                                 window,
@@ -290,6 +349,8 @@ impl NodeProperties {
                                 parent_scroll_offset: content_rect.scroll_offset(),
                                 key: *key,
                                 dom_nodes,
+                                damage: None,
+                                register_focusables,
                             });
                         });
                         content_rect.apply_scroll(frame.buffer_mut().area, frame.buffer_mut());
@@ -320,12 +381,17 @@ impl NodeProperties {
                                 parent_scroll_offset: content_rect.scroll_offset(),
                                 key: *key,
                                 dom_nodes,
+                                damage,
+                                register_focusables,
                             });
                         });
-                        self.render_block(render_bounds, frame.buffer_mut());
+                        if damaged {
+                            self.render_block(render_bounds, frame.buffer_mut());
+                        }
                     }
                 }
                 NodeType::FocusScope(_) => {}
+                NodeType::Widget(_) if !damaged => {}
                 NodeType::Widget(widget) => {
                     self.visible.store(true, Ordering::Relaxed);
                     let mut inner = content_rect.inner_bounds();
@@ -360,14 +426,17 @@ impl NodeProperties {
                 NodeType::Placeholder => {}
             }
             #[cfg(feature = "effects")]
-            if let Some(effects) = &self.effects {
+            if let Some(effects) = &self.effects
+                && damaged
+            {
                 let mut effects = effects.borrow_mut();
 
                 if effects.effect.running() {
                     let tick = effects.tick();
 
                     frame.render_effect(&mut effects.effect, render_bounds, tick);
-                    refresh_dom();
+                    dom_nodes.mark_dirty(key);
+                    notify_dom_update();
                 }
             }
         } else {
@@ -440,6 +509,7 @@ impl NodeProperties {
             scroll_offset,
             ancestor_scroll_offset: _ancestor_scroll_offset,
             max_scroll_offset,
+            stick_to_bottom,
             #[cfg(feature = "effects")]
             effects,
             z_index: _z_index,
@@ -462,6 +532,7 @@ impl NodeProperties {
         let enabled = *enabled;
         let scroll_offset = *scroll_offset;
         let max_scroll_offset = *max_scroll_offset;
+        let stick_to_bottom = *stick_to_bottom;
         #[cfg(feature = "effects")]
         let effects = effects.clone();
 
@@ -479,6 +550,7 @@ impl NodeProperties {
         self.enabled = enabled;
         self.scroll_offset = scroll_offset;
         self.max_scroll_offset = max_scroll_offset;
+        self.stick_to_bottom = stick_to_bottom;
 
         if let NodeType::Widget(widget) = &mut self.node_type {
             widget.set_key(new_key);
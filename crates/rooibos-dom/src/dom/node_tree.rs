@@ -13,7 +13,7 @@ use slotmap::{SlotMap, new_key_type};
 use taffy::{AvailableSpace, NodeId, Overflow, Point, Size, Style, TaffyTree};
 use terminput::{KeyCode, KeyEvent, KeyEventKind, SHIFT, ScrollDirection, key};
 
-use super::{FocusMode, MeasureNode, NodeProperties, dom_node, refresh_dom};
+use super::{FocusMode, MeasureNode, NodeProperties, ScrollPosition, dom_node, refresh_dom};
 use crate::events::{Event, EventHandlers, queue_event};
 use crate::{AsDomNode, Borders, DomNode, NodeType, push_accesskit_tree_update};
 
@@ -37,6 +37,13 @@ where
     DOM_NODES.with(|n| f(&mut n.borrow_mut()))
 }
 
+pub(crate) fn try_with_nodes<F, R>(f: F) -> Option<R>
+where
+    F: FnOnce(&NodeTree) -> R,
+{
+    DOM_NODES.with(|n| n.try_borrow().ok().map(|n| f(&n)))
+}
+
 pub(crate) fn tree_is_accessible() -> bool {
     DOM_NODES.try_with(|_| {}).is_ok()
 }
@@ -216,7 +223,7 @@ impl Default for Context {
 
 static ROOT_ID: AtomicU32 = AtomicU32::new(1);
 
-#[derive(PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 struct RootId {
     z_index: i32,
     id: u32,
@@ -301,8 +308,10 @@ pub struct NodeTree {
     focused_key: Option<DomNodeKey>,
     hovered_key: Option<DomNodeKey>,
     focusable_nodes: Rc<RefCell<Vec<FocusableNode>>>,
+    dirty_nodes: RefCell<Vec<DomNodeKey>>,
     on_window_size_change: Box<dyn Fn(ViewportSize)>,
     on_focus_change: Box<dyn Fn(Option<crate::NodeId>)>,
+    on_scroll_change: Box<dyn Fn(crate::NodeId, ScrollPosition)>,
 }
 
 impl Index<DomNodeKey> for NodeTree {
@@ -337,9 +346,11 @@ impl NodeTree {
             focused_key: None,
             hovered_key: None,
             focusable_nodes: Default::default(),
+            dirty_nodes: Default::default(),
             viewport_size: ViewportSize::default(),
             on_window_size_change: Box::new(move |_| {}),
             on_focus_change: Box::new(move |_| {}),
+            on_scroll_change: Box::new(move |_, _| {}),
         }
     }
 
@@ -397,10 +408,114 @@ impl NodeTree {
         self.layout_tree
             .mark_dirty(self.dom_nodes[key].layout_id.unwrap())
             .unwrap();
+        // Size changes can move any of the surrounding nodes
+        refresh_dom();
+    }
+
+    pub(crate) fn mark_dirty(&self, key: DomNodeKey) {
+        self.dirty_nodes.borrow_mut().push(key);
+    }
+
+    pub(crate) fn clear_damage(&self) {
+        self.dirty_nodes.borrow_mut().clear();
+    }
+
+    /// Returns the regions of the screen that need to be redrawn since the last render.
+    ///
+    /// Any node that paints over an area overlapping a damaged region gets its own area added as
+    /// well, otherwise re-rendering the overlapping node could overwrite cells outside of the
+    /// damaged region that belong to a different node.
+    pub(crate) fn take_damage(&self) -> Vec<Rect> {
+        let dirty_nodes = mem::take(&mut *self.dirty_nodes.borrow_mut());
+        let mut damage = Vec::new();
+        for key in dirty_nodes {
+            if self.dom_nodes.contains_key(key) {
+                add_damage_region(&mut damage, self.damage_bounds(key));
+            }
+        }
+        if damage.is_empty() {
+            return damage;
+        }
+
+        let painted_regions: Vec<_> = self
+            .iter_layout_nodes()
+            .filter(|(key, _, node)| {
+                node.inner.visible()
+                    && !node.inner.unmounted.load(Ordering::Relaxed)
+                    && self.paints_full_bounds(*key)
+            })
+            .map(|(key, _, _)| self.damage_bounds(key))
+            .collect();
+
+        loop {
+            let mut changed = false;
+            for region in &painted_regions {
+                if damage.iter().any(|d| d.intersects(*region))
+                    && add_damage_region(&mut damage, *region)
+                {
+                    changed = true;
+                }
+            }
+            if !changed {
+                return damage;
+            }
+        }
+    }
+
+    /// The area on the screen that will change if the node is redrawn. Nodes that are rendered
+    /// to a temporary buffer (scrollable or overflowing nodes) redraw their entire area, so any
+    /// descendant of one of those is treated as damaging the whole area.
+    fn damage_bounds(&self, key: DomNodeKey) -> Rect {
+        let mut bounds = *self.dom_nodes[key].inner.rect.borrow();
+        let mut current = key;
+        while let Some(parent) = self.dom_nodes[current].inner.parent {
+            if self.renders_offscreen(parent) {
+                bounds = *self.dom_nodes[parent].inner.rect.borrow();
+            }
+            current = parent;
+        }
+        bounds
+    }
+
+    /// Whether rendering the node writes to every cell in its bounds rather than just the cells
+    /// that it has content for.
+    fn paints_full_bounds(&self, key: DomNodeKey) -> bool {
+        let node = &self.dom_nodes[key].inner;
+        #[cfg(feature = "effects")]
+        if node.effects.is_some() {
+            return true;
+        }
+        matches!(node.node_type, NodeType::Widget(_)) || node.clear || self.renders_offscreen(key)
+    }
+
+    fn renders_offscreen(&self, key: DomNodeKey) -> bool {
+        let Some(content_rect) = self.try_rect(key) else {
+            return false;
+        };
+        if content_rect.can_scroll() {
+            return true;
+        }
+        let (parent_bounds, parent_scroll_offset) = match self.dom_nodes[key]
+            .inner
+            .parent
+            .and_then(|p| self.nearest_layout_parent(p))
+        {
+            Some(parent) => {
+                let parent_rect = self.rect(parent);
+                (parent_rect.child_bounds(), parent_rect.scroll_offset())
+            }
+            None => (self.viewport_size.window_size, Position::ORIGIN),
+        };
+        let bounds = content_rect.render_bounds();
+        (bounds.y + bounds.height)
+            .saturating_sub(parent_bounds.y + parent_bounds.height)
+            .saturating_sub(parent_scroll_offset.y)
+            > 0
     }
 
     pub fn set_root(&mut self, z_index: i32, root: impl AsDomNode + 'static) {
         self.roots.insert(RootId::new(z_index), Box::new(root));
+        refresh_dom();
     }
 
     pub fn on_window_size_change<F>(&mut self, f: F)
@@ -417,6 +532,24 @@ impl NodeTree {
         self.on_focus_change = Box::new(f);
     }
 
+    pub fn on_scroll_change<F>(&mut self, f: F)
+    where
+        F: Fn(crate::NodeId, ScrollPosition) + 'static,
+    {
+        self.on_scroll_change = Box::new(f);
+    }
+
+    /// Removes the root entry for `key`, if there is one.
+    /// The returned node must be dropped after the tree is released.
+    pub(crate) fn take_root(&mut self, key: DomNodeKey) -> Option<Box<dyn AsDomNode>> {
+        let root_id = self
+            .roots
+            .iter()
+            .find(|(_, root)| root.as_dom_node().get_key() == key)
+            .map(|(id, _)| *id)?;
+        self.roots.remove(&root_id)
+    }
+
     pub(crate) fn root(&self, z_index: i32) -> &dyn AsDomNode {
         let key = &self.roots.keys().find(|k| k.z_index == z_index).unwrap();
         &self.roots[key]
@@ -426,6 +559,7 @@ impl NodeTree {
         let viewport_size = self.viewport_size;
         *self = Self::new();
         self.viewport_size = viewport_size;
+        refresh_dom();
     }
 
     pub fn roots_asc(&self) -> Vec<DomNode> {
@@ -452,7 +586,7 @@ impl NodeTree {
             .store(unmounted, Ordering::Relaxed);
     }
 
-    pub(crate) fn focused_key(&self) -> Option<DomNodeKey> {
+    pub fn focused_key(&self) -> Option<DomNodeKey> {
         self.focused_key
     }
 
@@ -603,6 +737,7 @@ impl NodeTree {
         let scroll_width =
             (layout.scroll_width() as u16).saturating_sub(layout.scrollbar_size.width as u16);
 
+        let prev_position = self.dom_nodes[key].inner.scroll_position();
         let viewport = self.viewport_size.viewport();
         if (layout.size.height as u16 > viewport.height
             && self.style(key).overflow.y == Overflow::Scroll)
@@ -627,6 +762,19 @@ impl NodeTree {
             self.dom_nodes[key].inner.max_scroll_offset = Position::default();
             self.dom_nodes[key].inner.scroll_offset = Position::default();
         }
+        let inner = &mut self.dom_nodes[key].inner;
+        if inner.stick_to_bottom && prev_position.at_bottom() {
+            inner.scroll_offset.y = inner.max_scroll_offset.y;
+        }
+        let position = inner.scroll_position();
+        if position != prev_position {
+            let change = taffy::Point {
+                x: position.offset.x as i32 - prev_position.offset.x as i32,
+                y: position.offset.y as i32 - prev_position.offset.y as i32,
+            };
+            self.update_ancestor_scroll_offsets(key, change);
+            self.notify_scroll_change(key);
+        }
         let context = self
             .layout_tree
             .get_node_context(layout_node)
@@ -875,16 +1023,7 @@ impl NodeTree {
             updates.push((context.accesskit_node_id, context.accesskit_node.clone()));
             children.push(context.accesskit_node_id);
         }
-        let focused = self
-            .focused_key
-            .map(|k| {
-                let layout_id = self.dom_nodes[k].layout_id.unwrap();
-                self.layout_tree
-                    .get_node_context(layout_id)
-                    .unwrap()
-                    .accesskit_node_id
-            })
-            .unwrap_or(ACCESSKIT_WINDOW_ID);
+        let focused = self.accesskit_focus();
         if let Some(parent_layout_id) = parent_layout_id {
             let parent_context = self
                 .layout_tree
@@ -986,6 +1125,54 @@ impl NodeTree {
         refresh_dom();
     }
 
+    pub fn set_contain_focus(&mut self, node: DomNodeKey, contain: bool) {
+        if let NodeType::FocusScope(scope) = &mut self.dom_nodes[node].inner.node_type {
+            scope.set_contain(contain);
+        }
+        refresh_dom();
+    }
+
+    /// Sets the checked state reported to assistive technologies for nodes like checkboxes and
+    /// radio buttons.
+    pub fn set_checked(&mut self, node: DomNodeKey, checked: bool) {
+        let Some(layout_id) = self.dom_nodes[node].layout_id else {
+            return;
+        };
+        let tree_id = self.dom_nodes[node]
+            .inner
+            .parent
+            .and_then(|parent| self.nearest_layout_parent(parent))
+            .and_then(|parent| self.dom_nodes[parent].layout_id)
+            .and_then(|parent| self.layout_tree.get_node_context(parent))
+            .and_then(|context| context.accesskit_node.tree_id())
+            .unwrap_or(accesskit::TreeId::ROOT);
+        let focused = self.accesskit_focus();
+        let context = self.layout_tree.get_node_context_mut(layout_id).unwrap();
+        context.accesskit_node.set_toggled(if checked {
+            accesskit::Toggled::True
+        } else {
+            accesskit::Toggled::False
+        });
+        push_accesskit_tree_update(TreeUpdate {
+            nodes: vec![(context.accesskit_node_id, context.accesskit_node.clone())],
+            tree: None,
+            tree_id,
+            focus: focused,
+        });
+    }
+
+    fn accesskit_focus(&self) -> accesskit::NodeId {
+        self.focused_key
+            .map(|k| {
+                let layout_id = self.dom_nodes[k].layout_id.unwrap();
+                self.layout_tree
+                    .get_node_context(layout_id)
+                    .unwrap()
+                    .accesskit_node_id
+            })
+            .unwrap_or(ACCESSKIT_WINDOW_ID)
+    }
+
     pub fn set_focus_mode(&mut self, node: DomNodeKey, focus_mode: FocusMode) {
         self.dom_nodes[node].inner.set_focus_mode(focus_mode);
         refresh_dom();
@@ -993,10 +1180,97 @@ impl NodeTree {
 
     pub(crate) fn scroll(&mut self, node: DomNodeKey, direction: ScrollDirection) {
         let change = self.dom_nodes[node].inner.scroll(direction);
+        self.apply_scroll_change(node, change);
+    }
+
+    pub fn scroll_position(&self, node: DomNodeKey) -> ScrollPosition {
+        self.dom_nodes[node].inner.scroll_position()
+    }
+
+    pub fn scroll_to(&mut self, node: DomNodeKey, offset: Position) {
+        let change = self.dom_nodes[node].inner.set_scroll_offset(offset);
+        self.apply_scroll_change(node, change);
+    }
+
+    pub fn scroll_by(&mut self, node: DomNodeKey, x: i32, y: i32) {
+        let change = self.dom_nodes[node].inner.scroll_by(x, y);
+        self.apply_scroll_change(node, change);
+    }
+
+    /// Keeps the node scrolled to the bottom when its content grows, as long as it was already at
+    /// the bottom.
+    pub fn set_stick_to_bottom(&mut self, node: DomNodeKey, stick_to_bottom: bool) {
+        self.dom_nodes[node].inner.stick_to_bottom = stick_to_bottom;
+        if stick_to_bottom {
+            let max_y = self.dom_nodes[node].inner.max_scroll_offset.y;
+            let x = self.dom_nodes[node].inner.scroll_offset.x;
+            self.scroll_to(node, Position::new(x, max_y));
+        }
+    }
+
+    pub(crate) fn scroll_focused_into_view(&mut self) {
+        if let Some(focused) = self.focused_key {
+            self.scroll_into_view(focused);
+        }
+    }
+
+    pub(crate) fn is_scrollable(&self, node: DomNodeKey) -> bool {
+        self.dom_nodes[node].inner.max_scroll_offset != Position::ORIGIN
+    }
+
+    /// Number of rows of content that are visible at once in a scroll container.
+    pub(crate) fn scroll_page_height(&self, node: DomNodeKey) -> u16 {
+        self.try_rect(node)
+            .map(|r| r.child_bounds().height.min(r.visible_bounds().height))
+            .unwrap_or_default()
+            .max(1)
+    }
+
+    /// Scrolls each scrollable ancestor of the node so the node is visible.
+    pub(crate) fn scroll_into_view(&mut self, node: DomNodeKey) {
+        let Some(node_rect) = self.try_rect(node).map(|r| r.render_bounds()) else {
+            return;
+        };
+        let mut key = node;
+        while let Some(parent) = self.dom_nodes[key].inner.parent {
+            key = parent;
+            if !self.is_scrollable(parent) {
+                continue;
+            }
+            let Some(parent_rect) = self.try_rect(parent) else {
+                continue;
+            };
+            let mut view = parent_rect.child_bounds();
+            let visible = parent_rect.visible_bounds();
+            view.width = view.width.min(visible.width);
+            view.height = view.height.min(visible.height);
+            let offset = parent_rect.scroll_offset();
+
+            let new_offset = Position::new(
+                scroll_into_range(offset.x, node_rect.x, node_rect.width, view.x, view.width),
+                scroll_into_range(offset.y, node_rect.y, node_rect.height, view.y, view.height),
+            );
+            if new_offset != offset {
+                self.scroll_to(parent, new_offset);
+            }
+        }
+    }
+
+    fn apply_scroll_change(&mut self, node: DomNodeKey, change: taffy::Point<i32>) {
+        if change.x == 0 && change.y == 0 {
+            return;
+        }
         self.update_ancestor_scroll_offsets(node, change);
+        self.notify_scroll_change(node);
         refresh_dom();
     }
 
+    fn notify_scroll_change(&self, node: DomNodeKey) {
+        if let Some(id) = &self.dom_nodes[node].inner.id {
+            (self.on_scroll_change)(id.clone(), self.dom_nodes[node].inner.scroll_position());
+        }
+    }
+
     fn update_ancestor_scroll_offsets(&mut self, node: DomNodeKey, change: taffy::Point<i32>) {
         for child in self.dom_nodes[node].inner.children.clone() {
             self.dom_nodes[child]
@@ -1072,6 +1346,14 @@ impl NodeTree {
         refresh_dom();
     }
 
+    pub fn set_label_for(&mut self, node: DomNodeKey, label_for: Option<dom_node::NodeId>) {
+        let inner = &mut self.dom_nodes[node].inner;
+        if inner.label_for != label_for {
+            inner.label_for = label_for;
+            refresh_dom();
+        }
+    }
+
     pub fn set_borders(&mut self, node: DomNodeKey, borders: Borders) {
         self.dom_nodes[node].inner.borders = Some(borders);
         refresh_dom();
@@ -1187,24 +1469,69 @@ impl NodeTree {
         self.hovered_key = Some(node_key);
     }
 
-    pub(crate) fn focus_next(&mut self) {
-        if let Some(focused) = self.focused_key {
-            let current_focused = self
-                .focusable_nodes
-                .borrow()
-                .iter()
-                .position(|n| n.key == focused)
-                .unwrap();
+    fn containing_scope(&self, key: DomNodeKey) -> Option<DomNodeKey> {
+        let mut current = self.dom_nodes[key].inner.parent;
+        while let Some(parent) = current {
+            if let NodeType::FocusScope(scope) = &self.dom_nodes[parent].inner.node_type
+                && scope.contain()
+            {
+                return Some(parent);
+            }
+            current = self.dom_nodes[parent].inner.parent;
+        }
+        None
+    }
 
-            let last = self.focusable_nodes.borrow().len() - 1;
-            if current_focused < last {
-                let next = self.focusable_nodes.borrow()[current_focused + 1];
-                self.set_focused(Some(next.key));
-                return;
+    /// Returns the focus scope that tab navigation is currently trapped inside of.
+    /// If the focused node isn't inside of a containing scope, the most recently rendered
+    /// containing scope takes over so focus can't escape from an overlay that was just opened.
+    fn active_focus_scope(&self) -> Option<DomNodeKey> {
+        if let Some(scope) = self
+            .focused_key
+            .and_then(|focused| self.containing_scope(focused))
+        {
+            return Some(scope);
+        }
+        self.focusable_nodes
+            .borrow()
+            .iter()
+            .rev()
+            .find_map(|n| self.containing_scope(n.key))
+    }
+
+    fn is_descendant(&self, key: DomNodeKey, ancestor: DomNodeKey) -> bool {
+        let mut current = Some(key);
+        while let Some(node) = current {
+            if node == ancestor {
+                return true;
             }
+            current = self.dom_nodes[node].inner.parent;
+        }
+        false
+    }
+
+    fn tab_focusables(&self) -> Vec<FocusableNode> {
+        let focusable_nodes = self.focusable_nodes.borrow();
+        match self.active_focus_scope() {
+            Some(scope) => focusable_nodes
+                .iter()
+                .filter(|n| self.is_descendant(n.key, scope))
+                .copied()
+                .collect(),
+            None => focusable_nodes.clone(),
         }
-        let next = self.focusable_nodes.borrow().first().cloned();
-        if let Some(next) = next {
+    }
+
+    pub(crate) fn focus_next(&mut self) {
+        let focusables = self.tab_focusables();
+        if let Some(focused) = self.focused_key
+            && let Some(current_focused) = focusables.iter().position(|n| n.key == focused)
+            && current_focused < focusables.len() - 1
+        {
+            self.set_focused(Some(focusables[current_focused + 1].key));
+            return;
+        }
+        if let Some(next) = focusables.first() {
             self.set_focused(Some(next.key));
         }
     }
@@ -1241,21 +1568,15 @@ impl NodeTree {
     }
 
     pub(crate) fn focus_prev(&mut self) {
-        if let Some(focused) = self.focused_key {
-            let current_focused = self
-                .focusable_nodes
-                .borrow()
-                .iter()
-                .position(|n| n.key == focused)
-                .unwrap();
-            if current_focused > 0 {
-                let prev = self.focusable_nodes.borrow()[current_focused - 1];
-                self.set_focused(Some(prev.key));
-                return;
-            }
+        let focusables = self.tab_focusables();
+        if let Some(focused) = self.focused_key
+            && let Some(current_focused) = focusables.iter().position(|n| n.key == focused)
+            && current_focused > 0
+        {
+            self.set_focused(Some(focusables[current_focused - 1].key));
+            return;
         }
-        let prev = self.focusable_nodes.borrow().last().cloned();
-        if let Some(prev) = prev {
+        if let Some(prev) = focusables.last() {
             self.set_focused(Some(prev.key));
         }
     }
@@ -1294,20 +1615,54 @@ impl NodeTree {
     }
 }
 
+fn add_damage_region(damage: &mut Vec<Rect>, region: Rect) -> bool {
+    if region.is_empty() || damage.iter().any(|d| d.union(region) == *d) {
+        return false;
+    }
+    damage.push(region);
+    true
+}
+
+/// Returns the scroll offset needed to bring the range `start..start + len` into a view that
+/// begins at `view_start` and is `view_len` cells long.
+fn scroll_into_range(offset: u16, start: u16, len: u16, view_start: u16, view_len: u16) -> u16 {
+    // Position of the node relative to the start of the scrollable content
+    let start = start.saturating_sub(view_start);
+    if start < offset {
+        start
+    } else if start + len > offset + view_len {
+        (start + len).saturating_sub(view_len).min(start)
+    } else {
+        offset
+    }
+}
+
 pub fn focus_next() {
-    with_nodes_mut(|n| n.focus_next())
+    with_nodes_mut(|n| {
+        n.focus_next();
+        n.scroll_focused_into_view();
+    })
 }
 
 pub fn focus_next_list() {
-    with_nodes_mut(|n| n.focus_next_list())
+    with_nodes_mut(|n| {
+        n.focus_next_list();
+        n.scroll_focused_into_view();
+    })
 }
 
 pub fn focus_prev() {
-    with_nodes_mut(|n| n.focus_prev())
+    with_nodes_mut(|n| {
+        n.focus_prev();
+        n.scroll_focused_into_view();
+    })
 }
 
 pub fn focus_prev_list() {
-    with_nodes_mut(|n| n.focus_prev_list())
+    with_nodes_mut(|n| {
+        n.focus_prev_list();
+        n.scroll_focused_into_view();
+    })
 }
 
 pub fn clear_focus() {
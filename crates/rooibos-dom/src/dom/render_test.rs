@@ -0,0 +1,143 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::atomic::Ordering;
+
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+
+use super::{
+    FULL_REDRAW, mount, refresh_dom, render_dom, set_pending_resize, unmount_child, with_nodes,
+};
+use crate::widgets::RenderWidgetRef;
+use crate::{Borders, DomNode, DomWidgetNode, NodeType};
+
+fn text_node(text: &Rc<RefCell<String>>) -> DomNode {
+    let text = text.clone();
+    let widget = DomWidgetNode::new(move || RenderWidgetRef::new(text.borrow().clone()));
+    widget.build();
+    DomNode::widget(widget)
+}
+
+// Rebuilds the widget the same way a signal change would
+fn rebuild(node: &DomNode) {
+    let widget = with_nodes(|nodes| match &nodes[node.get_key()].node_type {
+        NodeType::Widget(widget) => widget.clone(),
+        _ => panic!("not a widget"),
+    });
+    widget.build();
+}
+
+fn draw(terminal: &mut Terminal<TestBackend>) -> Buffer {
+    terminal.draw(|frame| render_dom(frame)).unwrap();
+    terminal.backend().buffer().clone()
+}
+
+// Renders the current DOM from scratch on a new terminal
+fn draw_full(terminal: &Terminal<TestBackend>) -> Buffer {
+    let area = terminal.backend().buffer().area;
+    let mut full = Terminal::new(TestBackend::new(area.width, area.height)).unwrap();
+    refresh_dom();
+    draw(&mut full)
+}
+
+fn text(buffer: &Buffer) -> String {
+    buffer.content().iter().map(|cell| cell.symbol()).collect()
+}
+
+fn is_full_redraw() -> bool {
+    FULL_REDRAW.with(|f| f.load(Ordering::Relaxed))
+}
+
+fn setup() -> (Vec<Rc<RefCell<String>>>, Vec<DomNode>, DomNode) {
+    let texts: Vec<_> = ["first", "second", "third"]
+        .into_iter()
+        .map(|t| Rc::new(RefCell::new(t.to_string())))
+        .collect();
+    let nodes: Vec<_> = texts.iter().map(text_node).collect();
+    let col = DomNode::flex_col();
+    col.append(&nodes[0]);
+    col.append(&nodes[1].clone().borders(Borders::all()));
+    col.append(&nodes[2]);
+    mount(col.clone());
+    (texts, nodes, col)
+}
+
+#[test]
+fn partial_update() {
+    let (texts, nodes, _col) = setup();
+    let mut terminal = Terminal::new(TestBackend::new(20, 8)).unwrap();
+    draw(&mut terminal);
+
+    // Shorter text makes sure the old contents are cleared
+    *texts[1].borrow_mut() = "2nd".to_string();
+    rebuild(&nodes[1]);
+    assert!(!is_full_redraw());
+    let partial = draw(&mut terminal);
+
+    assert_eq!(partial, draw_full(&terminal));
+    assert!(text(&partial).contains("2nd"));
+    assert!(!text(&partial).contains("second"));
+}
+
+#[test]
+fn partial_update_without_changes() {
+    let (_texts, nodes, _col) = setup();
+    let mut terminal = Terminal::new(TestBackend::new(20, 8)).unwrap();
+    let initial = draw(&mut terminal);
+
+    rebuild(&nodes[0]);
+    rebuild(&nodes[2]);
+    assert!(!is_full_redraw());
+    let partial = draw(&mut terminal);
+
+    assert_eq!(partial, initial);
+    assert_eq!(partial, draw_full(&terminal));
+}
+
+#[test]
+fn resize() {
+    let (texts, nodes, _col) = setup();
+    let mut terminal = Terminal::new(TestBackend::new(20, 8)).unwrap();
+    draw(&mut terminal);
+
+    terminal.backend_mut().resize(30, 10);
+    set_pending_resize();
+    let resized = draw(&mut terminal);
+    assert_eq!(resized, draw_full(&terminal));
+
+    // Partial updates should still work after the previous frame is discarded
+    *texts[2].borrow_mut() = "3rd".to_string();
+    rebuild(&nodes[2]);
+    let partial = draw(&mut terminal);
+    assert_eq!(partial, draw_full(&terminal));
+    assert_eq!(partial.area.width, 30);
+
+    terminal.backend_mut().resize(12, 6);
+    set_pending_resize();
+    let resized = draw(&mut terminal);
+    assert_eq!(resized, draw_full(&terminal));
+}
+
+#[test]
+fn node_removal() {
+    let (texts, nodes, _col) = setup();
+    let mut terminal = Terminal::new(TestBackend::new(20, 8)).unwrap();
+    draw(&mut terminal);
+
+    // Damage a node and remove it before the next render
+    *texts[1].borrow_mut() = "gone".to_string();
+    rebuild(&nodes[1]);
+    unmount_child(nodes[1].get_key(), true);
+    assert!(is_full_redraw());
+    let removed = draw(&mut terminal);
+
+    assert_eq!(removed, draw_full(&terminal));
+    assert!(!text(&removed).contains("gone"));
+    assert!(text(&removed).contains("third"));
+
+    *texts[2].borrow_mut() = "last".to_string();
+    rebuild(&nodes[2]);
+    let partial = draw(&mut terminal);
+    assert_eq!(partial, draw_full(&terminal));
+}
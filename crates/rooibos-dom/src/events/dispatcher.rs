@@ -1,4 +1,4 @@
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 
 use ratatui::layout::{Position, Rect};
 use terminput::{
@@ -7,12 +7,12 @@ use terminput::{
 };
 
 use super::{
-    BlurEvent, ClickEvent, ClickEventFn, ClickEventProps, Event, EventData, EventHandle,
-    EventHandlers, FocusEvent, KeyEventProps,
+    BlurEvent, ClickEvent, ClickEventFn, ClickEventProps, DragEvent, DragEventProps, Event,
+    EventData, EventHandle, EventHandlers, FocusEvent, KeyEventProps,
 };
 use crate::{
     DomNodeKey, FocusEventType, MatchBehavior, NodeId, NodeProperties, NodeType, focus_next,
-    focus_next_list, focus_prev, focus_prev_list, push_pending_event, refresh_dom,
+    focus_next_list, focus_prev, focus_prev_list, notify_dom_update, push_pending_event,
     set_pending_resize, toggle_print_dom, trigger_window_focus_changed, with_nodes, with_nodes_mut,
 };
 
@@ -22,6 +22,7 @@ thread_local! {
 
 struct EventDispatcher {
     last_mouse_position: Position,
+    drag_target: Option<(DomNodeKey, Position)>,
 }
 
 pub fn dispatch_event(event: Event) {
@@ -30,7 +31,7 @@ pub fn dispatch_event(event: Event) {
 
 pub fn queue_event(event: Event) {
     push_pending_event(event);
-    refresh_dom();
+    notify_dom_update();
 }
 
 pub(crate) fn reset_mouse_position() {
@@ -44,6 +45,7 @@ impl EventDispatcher {
                 x: u16::MAX,
                 y: u16::MAX,
             },
+            drag_target: None,
         }
     }
 
@@ -104,10 +106,17 @@ impl EventDispatcher {
         };
         match mouse_event.kind {
             MouseEventKind::Down(mouse_button) => {
+                self.drag_target = find_drag_target(position).map(|key| (key, position));
                 dispatch_mouse_down(position, mouse_event.modifiers, mouse_button);
             }
-            MouseEventKind::Up(_) => {}
-            MouseEventKind::Drag(_) => {}
+            MouseEventKind::Up(_) => {
+                self.drag_target = None;
+            }
+            MouseEventKind::Drag(mouse_button) => {
+                if let Some((key, start)) = self.drag_target {
+                    dispatch_drag(key, start, position, mouse_event.modifiers, mouse_button);
+                }
+            }
             MouseEventKind::Moved => {
                 self.dispatch_mouse_moved(position);
             }
@@ -219,13 +228,18 @@ impl EventDispatcher {
     }
 
     fn dispatch_scroll_event(&self, position: Position, direction: ScrollDirection) {
+        // Nodes with their own scroll handler, like virtualized lists, manage scrolling
+        // themselves without being a scroll container
         if let Some(current_hovered) = hit_test(position, |props| {
             props.max_scroll_offset != Position::ORIGIN
+                || !props.event_handlers.on_scroll.is_empty()
         })
         .last()
         {
             with_nodes_mut(|n| {
-                n.scroll(*current_hovered, direction);
+                if n.is_scrollable(*current_hovered) {
+                    n.scroll(*current_hovered, direction);
+                }
             });
             bubble_event(
                 *current_hovered,
@@ -261,11 +275,15 @@ fn dispatch_key_event(key_event: KeyEvent) {
             return;
         }
         Some(FocusEventType::NextList) => {
-            focus_next_list();
+            if !focused_key_event_handled(key_event) {
+                focus_next_list();
+            }
             return;
         }
         Some(FocusEventType::PreviousList) => {
-            focus_prev_list();
+            if !focused_key_event_handled(key_event) {
+                focus_prev_list();
+            }
             return;
         }
         None => {}
@@ -274,18 +292,75 @@ fn dispatch_key_event(key_event: KeyEvent) {
     if key_event.code == KeyCode::Char('x') && key_event.modifiers.contains(KeyModifiers::CTRL) {
         toggle_print_dom();
     } else if let Some(key) = with_nodes(|nodes| nodes.focused_key()) {
-        bubble_key_event(key, key_event);
+        let stopped = Cell::new(false);
+        bubble_key_event(key, key_event, &stopped);
+        // Keyboard scrolling is only a fallback for keys that none of the handlers consumed
+        if !stopped.get() {
+            scroll_focused_container(key, key_event);
+        }
     } else {
         // No focused node, send the event to the root nodes instead
         let roots = with_nodes(|nodes| nodes.roots_desc());
         for root in roots {
-            if bubble_key_event(root.get_key(), key_event) {
+            if bubble_key_event(root.get_key(), key_event, &Cell::new(false)) {
                 return;
             }
         }
     }
 }
 
+/// Handles keyboard scrolling if the focused node is a scroll container.
+fn scroll_focused_container(key: DomNodeKey, key_event: KeyEvent) {
+    if key_event.kind == KeyEventKind::Release || !key_event.modifiers.is_empty() {
+        return;
+    }
+    with_nodes_mut(|nodes| {
+        if !nodes.is_scrollable(key) {
+            return;
+        }
+        let page = nodes.scroll_page_height(key) as i32;
+        let offset = nodes.scroll_position(key).offset;
+        match key_event.code {
+            KeyCode::PageUp => nodes.scroll_by(key, 0, -page),
+            KeyCode::PageDown => nodes.scroll_by(key, 0, page),
+            KeyCode::Home => nodes.scroll_to(key, Position::new(offset.x, 0)),
+            KeyCode::End => nodes.scroll_to(key, Position::new(offset.x, u16::MAX)),
+            _ => {}
+        }
+    })
+}
+
+/// Gives the focused node a chance to handle keys used for list navigation.
+/// If the node stops propagation, the key is treated as handled and the focus won't move.
+fn focused_key_event_handled(key_event: KeyEvent) -> bool {
+    let Some(key) = with_nodes(|nodes| nodes.focused_key()) else {
+        return false;
+    };
+    let (rect, node_id, handlers) = with_nodes(|nodes| {
+        (
+            *nodes[key].rect.borrow(),
+            nodes[key].id.clone(),
+            nodes[key].event_handlers.on_key_down.clone(),
+        )
+    });
+    if handlers.is_empty() {
+        return false;
+    }
+    let handle = EventHandle::default();
+    for handler in handlers {
+        handler.borrow_mut().handle(KeyEventProps {
+            event: key_event,
+            data: EventData {
+                rect,
+                target: node_id.clone(),
+                is_direct: true,
+            },
+            handle: handle.clone(),
+        });
+    }
+    handle.get_stop_propagation()
+}
+
 fn dispatch_node_enable(key: DomNodeKey) {
     bubble_event(
         key,
@@ -376,7 +451,9 @@ fn dispatch_node_blur(key: DomNodeKey, new_target: Option<NodeId>) {
     );
 }
 
-fn bubble_key_event(key: DomNodeKey, key_event: KeyEvent) -> bool {
+/// Sends the key event to the node and its ancestors. `stopped` is set if one of the handlers
+/// stopped propagation.
+fn bubble_key_event(key: DomNodeKey, key_event: KeyEvent, stopped: &Cell<bool>) -> bool {
     match key_event.kind {
         KeyEventKind::Press | KeyEventKind::Repeat => bubble_event(
             key,
@@ -393,6 +470,9 @@ fn bubble_key_event(key: DomNodeKey, key_event: KeyEvent) -> bool {
                         handle: handle.clone(),
                     });
                 }
+                if handle.get_stop_propagation() {
+                    stopped.set(true);
+                }
             },
             AllowDisabled::Disallow,
             true,
@@ -412,6 +492,9 @@ fn bubble_key_event(key: DomNodeKey, key_event: KeyEvent) -> bool {
                         handle: handle.clone(),
                     });
                 }
+                if handle.get_stop_propagation() {
+                    stopped.set(true);
+                }
             },
             AllowDisabled::Disallow,
             true,
@@ -499,6 +582,51 @@ where
     vec![]
 }
 
+fn find_drag_target(position: Position) -> Option<DomNodeKey> {
+    hit_test(position, |props| {
+        !props.event_handlers.on_mouse_drag.is_empty()
+    })
+    .last()
+    .copied()
+}
+
+fn dispatch_drag(
+    key: DomNodeKey,
+    start: Position,
+    position: Position,
+    modifiers: KeyModifiers,
+    button: MouseButton,
+) {
+    // The drag target is the node that was under the cursor when the drag started, even if the
+    // cursor has since moved outside of it
+    bubble_event(
+        key,
+        |props| props.event_handlers.on_mouse_drag.clone(),
+        |event, node_id, rect, handle, is_direct| {
+            for handler in event {
+                handler.borrow_mut().handle(DragEventProps {
+                    event: DragEvent {
+                        button,
+                        column: position.x,
+                        row: position.y,
+                        start_column: start.x,
+                        start_row: start.y,
+                        modifiers,
+                    },
+                    data: EventData {
+                        rect,
+                        target: node_id.clone(),
+                        is_direct,
+                    },
+                    handle: handle.clone(),
+                });
+            }
+        },
+        AllowDisabled::Disallow,
+        true,
+    );
+}
+
 fn dispatch_mouse_down(position: Position, modifiers: KeyModifiers, mouse_button: MouseButton) {
     match mouse_button {
         MouseButton::Left | MouseButton::Unknown => {
@@ -0,0 +1,98 @@
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::layout::Position;
+use taffy::Overflow;
+use terminput::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::{Event, KeyEventProps, dispatch_event};
+use crate::widgets::RenderWidgetRef;
+use crate::{DomNode, DomWidgetNode, mount, render_dom, with_nodes, with_nodes_mut};
+
+// A scroll container with 20 lines of text
+fn scroll_container(on_key_down: impl FnMut(KeyEventProps) + 'static) -> DomNode {
+    let col = DomNode::flex_col().focusable(true).on_key_down(on_key_down);
+    with_nodes_mut(|nodes| {
+        nodes.update_layout(col.get_key(), |style| {
+            style.overflow.y = Overflow::Scroll;
+        })
+    });
+    for i in 1..=20 {
+        let widget = DomWidgetNode::new(move || RenderWidgetRef::new(format!("line {i}")));
+        widget.build();
+        col.append(&DomNode::widget(widget));
+    }
+    col
+}
+
+// Renders the tree in a terminal that's 5 rows high so the container can scroll
+fn mount_focused(root: DomNode, focused: &DomNode) {
+    mount(root);
+    let mut terminal = Terminal::new(TestBackend::new(20, 5)).unwrap();
+    terminal.draw(|frame| render_dom(frame)).unwrap();
+    with_nodes_mut(|nodes| nodes.set_focused(Some(focused.get_key())));
+}
+
+fn press(code: KeyCode) {
+    dispatch_event(Event::Key(KeyEvent::new(code)));
+}
+
+fn offset(node: &DomNode) -> u16 {
+    with_nodes(|nodes| nodes.scroll_position(node.get_key()).offset.y)
+}
+
+#[test]
+fn scrolls_with_unhandled_keys() {
+    let col = scroll_container(|_| {});
+    mount_focused(col.clone(), &col);
+    let max = with_nodes(|nodes| nodes.scroll_position(col.get_key()).max_offset);
+    assert_eq!(max, Position::new(0, 15));
+
+    press(KeyCode::PageDown);
+    assert_eq!(offset(&col), 5);
+    press(KeyCode::End);
+    assert_eq!(offset(&col), 15);
+    press(KeyCode::PageUp);
+    assert_eq!(offset(&col), 10);
+    press(KeyCode::Home);
+    assert_eq!(offset(&col), 0);
+
+    // Modified keys don't scroll
+    dispatch_event(Event::Key(
+        KeyEvent::new(KeyCode::End).modifiers(KeyModifiers::CTRL),
+    ));
+    assert_eq!(offset(&col), 0);
+}
+
+#[test]
+fn handled_keys_dont_scroll() {
+    let col = scroll_container(|mut props: KeyEventProps| {
+        if props.event.code == KeyCode::End {
+            props.handle.stop_propagation();
+        }
+    });
+    mount_focused(col.clone(), &col);
+
+    press(KeyCode::End);
+    assert_eq!(offset(&col), 0);
+
+    // Keys the handler sees without stopping propagation still scroll
+    press(KeyCode::PageDown);
+    assert_eq!(offset(&col), 5);
+}
+
+#[test]
+fn handled_by_ancestor() {
+    let col = scroll_container(|_| {});
+    let parent = DomNode::flex_col().on_key_down(|mut props: KeyEventProps| {
+        if props.event.code == KeyCode::PageDown {
+            props.handle.stop_propagation();
+        }
+    });
+    parent.append(&col);
+    mount_focused(parent, &col);
+
+    press(KeyCode::PageDown);
+    assert_eq!(offset(&col), 0);
+    press(KeyCode::End);
+    assert_eq!(offset(&col), 15);
+}
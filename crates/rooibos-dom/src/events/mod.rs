@@ -10,6 +10,9 @@ use terminput::{KeyEvent, KeyModifiers, MouseButton};
 use crate::{DomNodeKey, NodeId};
 
 mod dispatcher;
+#[cfg(test)]
+#[path = "./dispatcher_test.rs"]
+mod dispatcher_test;
 mod event_handler;
 
 #[derive(Debug, Clone, Default)]
@@ -22,7 +25,7 @@ impl EventHandle {
         self.stop_propagation.store(true, Ordering::Relaxed)
     }
 
-    pub(crate) fn get_stop_propagation(&self) -> bool {
+    pub fn get_stop_propagation(&self) -> bool {
         self.stop_propagation.load(Ordering::Relaxed)
     }
 }
@@ -112,6 +115,10 @@ pub struct DragEvent {
     pub button: MouseButton,
     pub column: u16,
     pub row: u16,
+    /// Column where the mouse button was pressed down to start the drag
+    pub start_column: u16,
+    /// Row where the mouse button was pressed down to start the drag
+    pub start_row: u16,
     pub modifiers: KeyModifiers,
 }
 
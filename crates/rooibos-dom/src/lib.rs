@@ -1,4 +1,5 @@
 mod borders;
+pub mod clock;
 mod dom;
 pub mod events;
 mod macros;
@@ -7,7 +8,7 @@ mod nonblocking_terminal;
 #[cfg(target_arch = "wasm32")]
 mod nonblocking_terminal_wasm;
 pub mod widgets;
-mod wrap;
+pub mod wrap;
 
 use std::borrow::Cow;
 use std::cell::{LazyCell, OnceCell};
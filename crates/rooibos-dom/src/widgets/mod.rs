@@ -15,12 +15,20 @@ use taffy::Size;
 
 use crate::{MeasureNode, RenderNode};
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Role {
     Button,
     TextInput,
+    MultilineTextInput,
     Image,
     Text,
+    ComboBox,
+    EditableComboBox,
+    ListBox,
+    CheckBox,
+    RadioButton,
+    Switch,
+    ProgressIndicator,
 }
 
 impl From<Role> for accesskit::Role {
@@ -28,8 +36,16 @@ impl From<Role> for accesskit::Role {
         match value {
             Role::Button => accesskit::Role::Button,
             Role::TextInput => accesskit::Role::TextInput,
+            Role::MultilineTextInput => accesskit::Role::MultilineTextInput,
             Role::Image => accesskit::Role::Image,
             Role::Text => accesskit::Role::Label,
+            Role::ComboBox => accesskit::Role::ComboBox,
+            Role::EditableComboBox => accesskit::Role::EditableComboBox,
+            Role::ListBox => accesskit::Role::ListBox,
+            Role::CheckBox => accesskit::Role::CheckBox,
+            Role::RadioButton => accesskit::Role::RadioButton,
+            Role::Switch => accesskit::Role::Switch,
+            Role::ProgressIndicator => accesskit::Role::ProgressIndicator,
         }
     }
 }
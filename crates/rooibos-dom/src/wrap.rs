@@ -56,8 +56,7 @@ pub struct Options<'a> {
 /// Wraps a [`Text`] block.
 ///
 /// `text` should be broken up into lines at the time it's passed in.
-#[expect(unused)]
-pub(crate) fn wrap_text<'a>(text: &'a Text<'_>, options: Options<'a>) -> Text<'a> {
+pub fn wrap_text<'a>(text: &'a Text<'_>, options: Options<'a>) -> Text<'a> {
     let mut lines = Vec::new();
     // We currently assume that lines in text don't have embedded newlines in
     // them. This assumption might need to be revisited.
@@ -71,13 +70,50 @@ pub(crate) fn wrap_text<'a>(text: &'a Text<'_>, options: Options<'a>) -> Text<'a
 /// Wraps a [`Line`] representing a single line.
 ///
 /// If the text contains multiple lines, use [`wrap_text`] instead.
-#[expect(unused)]
-pub(crate) fn wrap_line<'a>(line: &'a Line<'_>, options: Options<'a>) -> Text<'a> {
+pub fn wrap_line<'a>(line: &'a Line<'_>, options: Options<'a>) -> Text<'a> {
     let mut lines = Vec::new();
     wrap_single_line(line, &options, &mut lines);
     Text::from(lines)
 }
 
+/// Wraps a [`Line`] like [`wrap_line`], also returning the character offset in `line` where
+/// each wrapped row starts.
+///
+/// Whitespace at the end of each row is dropped while wrapping, so the offsets can't be derived
+/// from the row widths alone.
+pub fn wrap_line_with_offsets<'a>(
+    line: &'a Line<'_>,
+    options: Options<'a>,
+) -> Vec<(usize, Line<'a>)> {
+    let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+    let indents = [
+        !options.initial_indent.content.is_empty(),
+        !options.subsequent_indent.content.is_empty(),
+    ];
+    let mut lines = Vec::new();
+    wrap_single_line(line, &options, &mut lines);
+
+    let mut byte_pos = 0;
+    lines
+        .into_iter()
+        .enumerate()
+        .map(|(index, row)| {
+            let has_indent = indents[index.min(1)];
+            let content: String = row
+                .spans
+                .iter()
+                .skip(has_indent as usize)
+                .map(|s| s.content.as_ref())
+                .collect();
+            let start = text[byte_pos..]
+                .find(&content)
+                .map_or(byte_pos, |i| byte_pos + i);
+            byte_pos = start + content.len();
+            (text[..start].chars().count(), row)
+        })
+        .collect()
+}
+
 fn wrap_single_line<'a>(line: &'a Line<'_>, options: &Options<'a>, lines: &mut Vec<Line<'a>>) {
     let indent = if lines.is_empty() {
         options.initial_indent.clone()
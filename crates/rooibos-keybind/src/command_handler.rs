@@ -5,35 +5,32 @@ use std::sync::Arc;
 
 use educe::Educe;
 use modalkit::actions::{
-    Action, CommandAction, CommandBarAction, Commandable, EditAction, EditorAction, PromptAction,
+    Action, CommandAction, CommandBarAction, Commandable, EditorAction, PromptAction,
 };
 use modalkit::commands::{CommandMachine, CommandResult, CommandStep, ParsedCommand};
 use modalkit::editing::application::{
     ApplicationAction, ApplicationContentId, ApplicationInfo, ApplicationStore, ApplicationWindowId,
 };
 use modalkit::editing::context::EditContext;
-use modalkit::editing::key::KeyManager;
 use modalkit::editing::store::Store;
-use modalkit::env::vim::VimMode;
 use modalkit::env::vim::command::{
     CommandContext, CommandDescription, VimCommand, VimCommandMachine,
 };
-use modalkit::env::vim::keybindings::InputStep;
-use modalkit::key::TerminalKey;
-use modalkit::keybindings::{BindingMachine, ModalMachine};
-use modalkit::prelude::{
-    CommandType, CompletionDisplay, CompletionStyle, CompletionType, Count, EditTarget, MoveDir1D,
-    MoveType, RecallFilter, RepeatType, Specifier,
-};
+use modalkit::keybindings::BindingMachine;
+use modalkit::prelude::CommandType;
 use rooibos_reactive::graph::owner::{StoredValue, on_cleanup, provide_context, use_context};
-use rooibos_reactive::graph::traits::{ReadValue, WriteValue};
+use rooibos_reactive::graph::signal::{ReadSignal, RwSignal};
+use rooibos_reactive::graph::traits::{
+    Get, GetUntracked, ReadValue, Set, With, WithValue, WriteValue,
+};
+use rooibos_reactive::graph::wrappers::read::Signal;
 use rooibos_runtime::InputMode;
 use terminput::Event;
 use unicode_width::UnicodeWidthStr;
 use wasm_compat::cell::UsizeCell;
 use wasm_compat::sync::Mutex;
 
-use crate::{keys, parse};
+use crate::{AppInfoManager, KeyMode, ModalBackend, PendingKeys, Vim};
 
 #[cfg(feature = "runtime")]
 pub trait CommandFilter<T>
@@ -111,8 +108,7 @@ pub struct CommandHandler<T>
 where
     T: ApplicationAction,
 {
-    manager: KeyManager<TerminalKey, Action<AppInfo<T>>, RepeatType>,
-    cmds: CommandMachine<VimCommand<AppInfo<T>>>,
+    manager: AppInfoManager<T>,
     context: CommandBarContext<T>,
     command_focused: bool,
     action_stack: VecDeque<(Action<AppInfo<T>>, EditContext)>,
@@ -138,8 +134,24 @@ where
     T: ApplicationAction,
 {
     store: StoredValue<Store<AppInfo<T>>>,
+    cmds: StoredValue<VimCommandMachine<AppInfo<T>>>,
     action_handlers: StoredValue<Vec<Box<CommandHandlerActionFn<T>>>>,
     pub(crate) command_handlers: StoredValue<HashMap<usize, CommandHandlerFn<T>>>,
+    commands: StoredValue<Vec<(String, Vec<String>)>>,
+    pub(crate) key_actions: StoredValue<HashMap<usize, (String, T)>>,
+    pub(crate) pending_keys: RwSignal<Option<PendingKeys<T>>>,
+    pub(crate) backend: RwSignal<Arc<dyn ModalBackend<T>>>,
+    mode: RwSignal<KeyMode>,
+}
+
+/// A command that can be run from the [`CommandPalette`](crate::CommandPalette).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CommandEntry<T> {
+    /// Command registered with [`CommandHandler::add_command`].
+    Command { name: String, aliases: Vec<String> },
+    /// Action registered with a [`KeyMapper`](crate::KeyMapper) along with the keys that are
+    /// currently bound to it.
+    Action { action: T, keys: Vec<String> },
 }
 
 impl<T> CommandBarContext<T>
@@ -161,12 +173,90 @@ where
         self.store
     }
 
+    /// Key sequence that's currently been partially entered, if any.
+    pub fn pending_keys(&self) -> ReadSignal<Option<PendingKeys<T>>> {
+        self.pending_keys.read_only()
+    }
+
+    /// Mode that's currently active. Only bindings for this mode, along with bindings that aren't
+    /// restricted to a mode, are handled.
+    pub fn mode(&self) -> ReadSignal<KeyMode> {
+        self.mode.read_only()
+    }
+
+    pub fn set_mode(&self, mode: impl Into<KeyMode>) {
+        self.mode.set(mode.into());
+    }
+
+    /// Text describing the current mode to show in a status bar. This is [`None`] when the
+    /// backend's initial mode is active.
+    pub fn mode_indicator(&self) -> Signal<Option<String>> {
+        let backend = self.backend;
+        let mode = self.mode;
+        Signal::derive(move || mode.with(|mode| backend.get().mode_indicator(mode)))
+    }
+
     pub fn dispatch(&self, command: T) {
         let mut handlers = self.command_handlers.write_value();
         for handler in handlers.values_mut() {
             handler.handler.lock_mut()(&command);
         }
     }
+
+    /// Parses and runs a command string like the ones entered in the
+    /// [`CommandBar`](crate::CommandBar) and dispatches any resulting actions.
+    pub fn run_command(&self, command: &str) -> Result<(), String> {
+        let actions = {
+            let mut store = self.store.write_value();
+            self.cmds
+                .write_value()
+                .command(
+                    &CommandAction::Run(command.to_string()),
+                    &EditContext::default(),
+                    &mut store.registers,
+                )
+                .map_err(|e| e.to_string())?
+        };
+        for (action, _) in actions {
+            if let Action::Application(action) = action {
+                self.dispatch(action);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns every registered command followed by every action bound with a
+    /// [`KeyMapper`](crate::KeyMapper). Actions bound to multiple keys are only listed once.
+    pub fn entries(&self) -> Vec<CommandEntry<T>> {
+        let mut entries: Vec<_> = self.commands.with_value(|commands| {
+            commands
+                .iter()
+                .map(|(name, aliases)| CommandEntry::Command {
+                    name: name.clone(),
+                    aliases: aliases.clone(),
+                })
+                .collect()
+        });
+        let mut key_actions: Vec<_> = self
+            .key_actions
+            .with_value(|actions| actions.iter().map(|(id, a)| (*id, a.clone())).collect());
+        // Keep actions in the order they were registered
+        key_actions.sort_by_key(|(id, _)| *id);
+
+        let mut actions: Vec<(T, Vec<String>)> = Vec::new();
+        for (_, (key, action)) in key_actions {
+            match actions.iter_mut().find(|(a, _)| *a == action) {
+                Some((_, keys)) => keys.push(key),
+                None => actions.push((action, vec![key])),
+            }
+        }
+        entries.extend(
+            actions
+                .into_iter()
+                .map(|(action, keys)| CommandEntry::Action { action, keys }),
+        );
+        entries
+    }
 }
 
 pub fn use_command_context<T>() -> CommandBarContext<T>
@@ -183,12 +273,34 @@ where
     if use_context::<CommandBarContext<T>>().is_none() {
         provide_context(CommandBarContext {
             store: StoredValue::new(Store::<AppInfo<T>>::new(AppStore {})),
+            cmds: StoredValue::new(VimCommandMachine::default()),
             action_handlers: StoredValue::new(Vec::new()),
             command_handlers: StoredValue::new(HashMap::new()),
+            commands: StoredValue::new(Vec::new()),
+            key_actions: StoredValue::new(HashMap::new()),
+            pending_keys: RwSignal::new(None),
+            backend: RwSignal::new(Arc::new(Vim) as Arc<dyn ModalBackend<T>>),
+            mode: RwSignal::new(ModalBackend::<T>::initial_mode(&Vim)),
         });
     }
 }
 
+/// Changes the [`ModalBackend`] used by every [`KeyMapper`](crate::KeyMapper) and resets the
+/// mode to the backend's initial mode.
+///
+/// The [`CommandHandler`] only picks up the backend when it's created, so this should be called
+/// before creating it. Use [`CommandHandler::with_backend`] to do both at once.
+pub fn set_modal_backend<T, B>(backend: B)
+where
+    T: ApplicationAction + Send + Sync + 'static,
+    B: ModalBackend<T>,
+{
+    provide_command_context::<T>();
+    let context = use_command_context::<T>();
+    context.mode.set(backend.initial_mode());
+    context.backend.set(Arc::new(backend));
+}
+
 pub enum CommandHandlerAction<T>
 where
     T: ApplicationAction,
@@ -209,127 +321,33 @@ impl<T> CommandHandler<T>
 where
     T: ApplicationAction + Send + Sync + 'static,
 {
+    /// Creates a handler using the current [`ModalBackend`], which is [`Vim`](crate::Vim) unless
+    /// it's been changed with [`set_modal_backend`].
     pub fn new() -> Self {
         provide_command_context::<T>();
-        let mut ism = ModalMachine::<TerminalKey, InputStep<AppInfo<T>>>::empty();
-        let colon = parse(":");
-        let esc = parse(keys::ESC);
-        let enter = parse(keys::ENTER);
-        let up = parse(keys::UP);
-        let down = parse(keys::DOWN);
-        let left = parse(keys::LEFT);
-        let right = parse(keys::RIGHT);
-        let tab = parse(keys::TAB);
-        let shift_tab = parse(keys::combine([keys::SHIFT, keys::TAB]));
-        let backspace = parse(keys::BACKSPACE);
-
-        ism.add_mapping(
-            VimMode::Normal,
-            &colon,
-            &InputStep::<AppInfo<T>>::new()
-                .actions(vec![Action::CommandBar(CommandBarAction::Focus(
-                    ":".into(),
-                    CommandType::Command,
-                    Box::new(Action::Command(CommandAction::Execute(Count::Exact(1)))),
-                ))])
-                .goto(VimMode::Command),
-        );
-
-        ism.add_mapping(
-            VimMode::Command,
-            &esc,
-            &InputStep::<AppInfo<T>>::new()
-                .actions(vec![Action::CommandBar(CommandBarAction::Unfocus)])
-                .goto(VimMode::Normal),
-        );
-        ism.add_mapping(
-            VimMode::Command,
-            &enter,
-            &InputStep::<AppInfo<T>>::new()
-                .actions(vec![Action::Prompt(PromptAction::Submit)])
-                .goto(VimMode::Normal),
-        );
-        ism.add_mapping(
-            VimMode::Command,
-            &up,
-            &InputStep::<AppInfo<T>>::new().actions(vec![Action::Prompt(PromptAction::Recall(
-                RecallFilter::All,
-                MoveDir1D::Previous,
-                Count::Contextual,
-            ))]),
-        );
-        ism.add_mapping(
-            VimMode::Command,
-            &down,
-            &InputStep::<AppInfo<T>>::new().actions(vec![Action::Prompt(PromptAction::Recall(
-                RecallFilter::All,
-                MoveDir1D::Next,
-                Count::Contextual,
-            ))]),
-        );
-        ism.add_mapping(
-            VimMode::Command,
-            &left,
-            &InputStep::<AppInfo<T>>::new().actions(vec![Action::Editor(EditorAction::Edit(
-                Specifier::Contextual,
-                EditTarget::Motion(
-                    MoveType::Column(MoveDir1D::Previous, true),
-                    Count::Contextual,
-                ),
-            ))]),
-        );
-        ism.add_mapping(
-            VimMode::Command,
-            &right,
-            &InputStep::<AppInfo<T>>::new().actions(vec![Action::Editor(EditorAction::Edit(
-                Specifier::Contextual,
-                EditTarget::Motion(MoveType::Column(MoveDir1D::Next, true), Count::Contextual),
-            ))]),
-        );
-        ism.add_mapping(
-            VimMode::Command,
-            &backspace,
-            &InputStep::<AppInfo<T>>::new().actions(vec![Action::Editor(EditorAction::Edit(
-                Specifier::Exact(EditAction::Delete),
-                EditTarget::Motion(
-                    MoveType::Column(MoveDir1D::Previous, true),
-                    Count::Contextual,
-                ),
-            ))]),
-        );
-
-        ism.add_mapping(
-            VimMode::Command,
-            &tab,
-            &InputStep::<AppInfo<T>>::new().actions(vec![Action::Editor(EditorAction::Complete(
-                CompletionStyle::List(MoveDir1D::Next),
-                CompletionType::Auto,
-                CompletionDisplay::None,
-            ))]),
-        );
-        ism.add_mapping(
-            VimMode::Command,
-            &shift_tab,
-            &InputStep::<AppInfo<T>>::new().actions(vec![Action::Editor(EditorAction::Complete(
-                CompletionStyle::List(MoveDir1D::Previous),
-                CompletionType::Auto,
-                CompletionDisplay::None,
-            ))]),
-        );
-
-        let cmds = VimCommandMachine::<AppInfo<T>>::default();
-
+        let context = use_context::<CommandBarContext<T>>().unwrap();
         Self {
-            manager: KeyManager::new(ism),
-            context: use_context::<CommandBarContext<T>>().unwrap(),
+            manager: context.backend.get_untracked().command_manager(),
+            context,
             command_focused: false,
             action_stack: VecDeque::new(),
-            cmds,
         }
     }
 
+    pub fn with_backend<B>(backend: B) -> Self
+    where
+        B: ModalBackend<T>,
+    {
+        set_modal_backend::<T, B>(backend);
+        Self::new()
+    }
+
     pub fn add_command(&mut self, cmd: VimCommand<AppInfo<T>>) {
-        self.cmds.add_command(cmd);
+        self.context
+            .commands
+            .write_value()
+            .push((cmd.name.clone(), cmd.aliases.clone()));
+        self.context.cmds.write_value().add_command(cmd);
     }
 
     fn action_pop(&mut self, keyskip: bool) -> Option<(Action<AppInfo<T>>, EditContext)> {
@@ -379,7 +397,9 @@ where
                 Action::Command(command_action) => {
                     let mut store = self.context.store.write_value();
                     let actions = self
+                        .context
                         .cmds
+                        .write_value()
                         .command(&command_action, &context, &mut store.registers)
                         .unwrap();
                     new_actions.push(actions);
@@ -0,0 +1,549 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use modalkit::editing::application::ApplicationAction;
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Widget;
+use rooibos_dom::events::KeyEventProps;
+use rooibos_dom::widgets::{Role, WidgetRole};
+use rooibos_dom::{
+    DomNodeKey, KeyCode, KeyModifiers, MeasureNode, RenderNode, focused_key, restore_focus,
+    set_editing,
+};
+use rooibos_reactive::col;
+use rooibos_reactive::dom::div::taffy;
+use rooibos_reactive::dom::layout::{
+    align_items, center, focusable, full, height, show, width, z_index,
+};
+use rooibos_reactive::dom::{
+    DomWidget, LayoutProps, NodeId, Render, UpdateLayoutProps, after_render, try_focus_id,
+};
+use rooibos_reactive::graph::IntoReactiveValue;
+use rooibos_reactive::graph::computed::Memo;
+use rooibos_reactive::graph::effect::Effect;
+use rooibos_reactive::graph::owner::StoredValue;
+use rooibos_reactive::graph::signal::{RwSignal, signal};
+use rooibos_reactive::graph::traits::{
+    Get, GetUntracked, GetValue, Set, SetValue, Update, UpdateValue, With, WithUntracked, WithValue,
+};
+use rooibos_reactive::graph::wrappers::read::Signal;
+
+use crate::{CommandEntry, use_command_context};
+
+/// The command palette is mounted as its own root at this z-index so it's drawn on top of the rest
+/// of the app.
+pub const COMMAND_PALETTE_Z_INDEX: i32 = 90;
+
+pub(crate) type LabelFn<T> = dyn Fn(&T) -> String + Send + Sync;
+
+/// Result of matching a pattern against a string with [`fuzzy_match`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// Higher scores are better matches.
+    pub score: i64,
+    /// Character indices of the matched characters.
+    pub indices: Vec<usize>,
+}
+
+/// Matches `pattern` against `text` as a case-insensitive subsequence.
+///
+/// Matches at the start of words and runs of consecutive characters are scored higher, while gaps
+/// between matched characters are penalized.
+pub fn fuzzy_match(pattern: &str, text: &str) -> Option<FuzzyMatch> {
+    let text: Vec<char> = text.chars().collect();
+    let mut indices = Vec::new();
+    let mut score = 0;
+    let mut pos = 0;
+
+    for p in pattern.chars().filter(|c| !c.is_whitespace()) {
+        let p = p.to_lowercase().next().unwrap_or(p);
+        let found = (pos..text.len()).find(|&i| text[i].to_lowercase().next() == Some(p))?;
+
+        score += 16;
+        if is_word_start(&text, found) {
+            score += 8;
+        }
+        match indices.last() {
+            Some(&last) if found == last + 1 => score += 12,
+            Some(&last) => score -= (found - last - 1).min(8) as i64,
+            None => score -= found.min(8) as i64,
+        }
+        indices.push(found);
+        pos = found + 1;
+    }
+    // Favor shorter strings when everything else is equal
+    score -= (text.len() / 8) as i64;
+    Some(FuzzyMatch { score, indices })
+}
+
+fn is_word_start(text: &[char], i: usize) -> bool {
+    if i == 0 {
+        return true;
+    }
+    let (prev, current) = (text[i - 1], text[i]);
+    !prev.is_alphanumeric() || (prev.is_lowercase() && current.is_uppercase())
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct PaletteItem<T> {
+    entry: CommandEntry<T>,
+    label: String,
+    keys: String,
+    indices: Vec<usize>,
+}
+
+/// Keeps the items that match `pattern` and sorts them from best to worst match. Recently run
+/// commands (`recent` is ordered from most to least recent) break ties between equal scores.
+///
+/// Scores aren't meaningful without a pattern, so an empty pattern lists recent commands first
+/// followed by everything else in alphabetical order.
+fn rank_items<T>(
+    items: impl IntoIterator<Item = PaletteItem<T>>,
+    pattern: &str,
+    recent: &VecDeque<String>,
+) -> Vec<PaletteItem<T>> {
+    let mut ranked: Vec<_> = items
+        .into_iter()
+        .filter_map(|mut item| {
+            let matched = fuzzy_match(pattern, &item.label)?;
+            item.indices = matched.indices;
+            let recency = recent.iter().position(|r| *r == item.label);
+            Some((matched.score, recency, item))
+        })
+        .collect();
+
+    let by_score = !pattern.trim().is_empty();
+    ranked.sort_by(|(a_score, a_recent, a), (b_score, b_recent, b)| {
+        let by_recency = match (a_recent, b_recent) {
+            (Some(a), Some(b)) => a.cmp(b),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        };
+        let score = if by_score {
+            b_score.cmp(a_score)
+        } else {
+            std::cmp::Ordering::Equal
+        };
+        score.then(by_recency).then_with(|| a.label.cmp(&b.label))
+    });
+    ranked.into_iter().map(|(_, _, item)| item).collect()
+}
+
+/// Searchable list of every command registered with a [`CommandHandler`](crate::CommandHandler)
+/// and every action bound with a [`KeyMapper`](crate::KeyMapper).
+///
+/// Commands are run through [`CommandBarContext::dispatch`](crate::CommandBarContext::dispatch).
+/// Anything typed after the first space in the search query is passed to the selected command as
+/// arguments.
+pub struct CommandPalette<T> {
+    action_label: Arc<LabelFn<T>>,
+    max_results: Signal<usize>,
+    history_size: usize,
+    style: Signal<Style>,
+    match_style: Signal<Style>,
+    selected_style: Signal<Style>,
+    key_style: Signal<Style>,
+    error_style: Signal<Style>,
+    layout_props: LayoutProps,
+}
+
+impl<T> UpdateLayoutProps for CommandPalette<T> {
+    fn layout_props(&self) -> LayoutProps {
+        self.layout_props.clone()
+    }
+
+    fn update_props(mut self, props: LayoutProps) -> Self {
+        self.layout_props = props;
+        self
+    }
+}
+
+impl<T> Default for CommandPalette<T>
+where
+    T: ApplicationAction + Send + Sync + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> CommandPalette<T>
+where
+    T: ApplicationAction + Send + Sync + 'static,
+{
+    pub fn new() -> Self {
+        Self {
+            action_label: Arc::new(|action| format!("{action:?}")),
+            max_results: 10.into(),
+            history_size: 5,
+            style: Style::default().into(),
+            match_style: Style::default().yellow().bold().into(),
+            selected_style: Style::default().reversed().into(),
+            key_style: Style::default().dark_gray().into(),
+            error_style: Style::default().red().into(),
+            layout_props: LayoutProps::default(),
+        }
+    }
+
+    /// Text shown for actions bound with a [`KeyMapper`](crate::KeyMapper). Defaults to the
+    /// [`Debug`] representation of the action.
+    pub fn action_label<F>(mut self, action_label: F) -> Self
+    where
+        F: Fn(&T) -> String + Send + Sync + 'static,
+    {
+        self.action_label = Arc::new(action_label);
+        self
+    }
+
+    /// Maximum number of results shown at once.
+    pub fn max_results<M>(mut self, max_results: impl IntoReactiveValue<Signal<usize>, M>) -> Self {
+        self.max_results = max_results.into_reactive_value();
+        self
+    }
+
+    /// Number of recently run commands to remember. Recent commands are listed first when the
+    /// search query is empty.
+    pub fn history_size(mut self, history_size: usize) -> Self {
+        self.history_size = history_size;
+        self
+    }
+
+    pub fn style<M>(mut self, style: impl IntoReactiveValue<Signal<Style>, M>) -> Self {
+        self.style = style.into_reactive_value();
+        self
+    }
+
+    /// Style applied to the characters that match the search query.
+    pub fn match_style<M>(mut self, match_style: impl IntoReactiveValue<Signal<Style>, M>) -> Self {
+        self.match_style = match_style.into_reactive_value();
+        self
+    }
+
+    pub fn selected_style<M>(
+        mut self,
+        selected_style: impl IntoReactiveValue<Signal<Style>, M>,
+    ) -> Self {
+        self.selected_style = selected_style.into_reactive_value();
+        self
+    }
+
+    /// Style applied to the keybindings shown next to each action.
+    pub fn key_style<M>(mut self, key_style: impl IntoReactiveValue<Signal<Style>, M>) -> Self {
+        self.key_style = key_style.into_reactive_value();
+        self
+    }
+
+    pub fn error_style<M>(mut self, error_style: impl IntoReactiveValue<Signal<Style>, M>) -> Self {
+        self.error_style = error_style.into_reactive_value();
+        self
+    }
+
+    /// Renders the palette. It's shown while `open` is `true` and closes itself after a command
+    /// runs or `Esc` is pressed.
+    pub fn render(self, open: RwSignal<bool>) -> impl Render {
+        let Self {
+            action_label,
+            max_results,
+            history_size,
+            style,
+            match_style,
+            selected_style,
+            key_style,
+            error_style,
+            layout_props,
+        } = self;
+
+        let id = NodeId::new_auto();
+        let context = use_command_context::<T>();
+        let (query, set_query) = signal(String::new());
+        let (selected, set_selected) = signal(0usize);
+        let (error, set_error) = signal(None::<String>);
+        // Labels of recently run commands, most recent first
+        let history = StoredValue::new(VecDeque::<String>::new());
+
+        // Node that had focus before the palette was opened
+        let restore = StoredValue::new(None::<DomNodeKey>);
+
+        Effect::new(move |prev: Option<bool>| {
+            let open = open.get();
+            if open {
+                set_query.set(String::new());
+                set_selected.set(0);
+                set_error.set(None);
+                set_editing(true);
+                // The palette isn't visible until the next render
+                after_render(move || {
+                    restore.set_value(focused_key());
+                    let _ = try_focus_id(id);
+                });
+            } else if prev == Some(true) {
+                set_editing(false);
+                if let Some(key) = restore.get_value() {
+                    after_render(move || restore_focus(Some(key)));
+                }
+            }
+            open
+        });
+
+        let items = Memo::new(move |_| {
+            if !open.get() {
+                return Vec::new();
+            }
+            let pattern = query.with(|q| q.split(' ').next().unwrap_or_default().to_string());
+            let items = context.entries().into_iter().map(|entry| {
+                let (label, keys) = match &entry {
+                    CommandEntry::Command { name, .. } => (name.clone(), String::new()),
+                    CommandEntry::Action { action, keys } => {
+                        (action_label(action), keys.join(", "))
+                    }
+                };
+                PaletteItem {
+                    entry,
+                    label,
+                    keys,
+                    indices: Vec::new(),
+                }
+            });
+            history.with_value(|recent| rank_items(items, &pattern, recent))
+        });
+
+        let close = move || open.set(false);
+
+        let run = move || {
+            let Some(item) =
+                items.with_untracked(|items| items.get(selected.get_untracked()).cloned())
+            else {
+                return;
+            };
+            let result = match item.entry {
+                CommandEntry::Command { name, .. } => {
+                    let args = query.with_untracked(|q| {
+                        q.split_once(' ')
+                            .map(|(_, args)| args.trim().to_string())
+                            .unwrap_or_default()
+                    });
+                    context.run_command(format!("{name} {args}").trim())
+                }
+                CommandEntry::Action { action, .. } => {
+                    context.dispatch(action);
+                    Ok(())
+                }
+            };
+            match result {
+                Ok(()) => {
+                    history.update_value(|history| {
+                        history.retain(|label| *label != item.label);
+                        history.push_front(item.label);
+                        history.truncate(history_size);
+                    });
+                    close();
+                }
+                Err(e) => set_error.set(Some(e)),
+            }
+        };
+
+        let key_down = move |props: KeyEventProps| {
+            let len = items.with_untracked(|items| items.len());
+            let modifiers = props.event.modifiers;
+            let ctrl = modifiers == KeyModifiers::CTRL;
+            match props.event.code {
+                KeyCode::Esc => close(),
+                KeyCode::Enter => run(),
+                KeyCode::Up => set_selected.update(|s| *s = s.saturating_sub(1)),
+                KeyCode::Char('p') if ctrl => set_selected.update(|s| *s = s.saturating_sub(1)),
+                KeyCode::Down => {
+                    set_selected.update(|s| *s = (*s + 1).min(len.saturating_sub(1)));
+                }
+                KeyCode::Char('n') if ctrl => {
+                    set_selected.update(|s| *s = (*s + 1).min(len.saturating_sub(1)));
+                }
+                KeyCode::Backspace => {
+                    set_query.update(|q| {
+                        q.pop();
+                    });
+                    set_selected.set(0);
+                    set_error.set(None);
+                }
+                KeyCode::Char(c) if modifiers.is_empty() || modifiers == KeyModifiers::SHIFT => {
+                    set_query.update(|q| q.push(c));
+                    set_selected.set(0);
+                    set_error.set(None);
+                }
+                _ => return,
+            }
+            props.handle.stop_propagation();
+        };
+
+        col![
+            style(
+                z_index(COMMAND_PALETTE_Z_INDEX),
+                width(full()),
+                height(full()),
+                align_items(center()),
+                show(open),
+                focusable(false),
+            ),
+            DomWidget::new(move || RenderCommandPalette {
+                query: query.get(),
+                items: items.get(),
+                selected: selected.get(),
+                error: error.get(),
+                max_results: max_results.get(),
+                style: style.get(),
+                match_style: match_style.get(),
+                selected_style: selected_style.get(),
+                key_style: key_style.get(),
+                error_style: error_style.get(),
+            })
+            .layout_props(layout_props)
+            .id(id)
+            .clear(true)
+            .on_key_down(key_down)
+            .on_direct_blur(move |_, _, _| {
+                // Focus was moved somewhere else so there's nothing to restore
+                restore.set_value(None);
+                close();
+            })
+        ]
+    }
+}
+
+struct RenderCommandPalette<T> {
+    query: String,
+    items: Vec<PaletteItem<T>>,
+    selected: usize,
+    error: Option<String>,
+    max_results: usize,
+    style: Style,
+    match_style: Style,
+    selected_style: Style,
+    key_style: Style,
+    error_style: Style,
+}
+
+impl<T> RenderCommandPalette<T> {
+    fn header_height(&self) -> usize {
+        1 + self.error.is_some() as usize
+    }
+
+    fn item_line(&self, item: &PaletteItem<T>) -> Line<'static> {
+        let spans: Vec<_> = item
+            .label
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                if item.indices.contains(&i) {
+                    Span::styled(c.to_string(), self.match_style)
+                } else {
+                    Span::raw(c.to_string())
+                }
+            })
+            .collect();
+        Line::from(spans)
+    }
+}
+
+impl<T> WidgetRole for RenderCommandPalette<T> {
+    fn widget_role() -> Option<Role> {
+        Some(Role::ListBox)
+    }
+}
+
+impl<T> RenderNode for RenderCommandPalette<T> {
+    fn render(&mut self, area: Rect, frame: &mut Frame) {
+        let buf = frame.buffer_mut();
+        buf.set_style(area, self.style);
+        if area.is_empty() {
+            return;
+        }
+        let row = |y: usize| Rect {
+            y: area.y + y as u16,
+            height: 1,
+            ..area
+        };
+
+        Line::from(vec![
+            Span::styled("> ", self.key_style),
+            Span::raw(self.query.clone()),
+            Span::raw(" ").reversed(),
+        ])
+        .render(row(0), buf);
+        let header_height = self.header_height().min(area.height as usize);
+        if let Some(error) = &self.error
+            && header_height > 1
+        {
+            Line::styled(error.clone(), self.error_style).render(row(1), buf);
+        }
+
+        let visible = self.max_results.min(area.height as usize - header_height);
+        if visible == 0 {
+            return;
+        }
+        if self.items.is_empty() {
+            Line::styled("No matching commands", self.key_style).render(row(header_height), buf);
+            return;
+        }
+
+        // Scroll just enough to keep the selected item visible
+        let start = (self.selected + 1).saturating_sub(visible);
+        for (y, (i, item)) in
+            (header_height..).zip(self.items.iter().enumerate().skip(start).take(visible))
+        {
+            let row = row(y);
+            let keys = Span::styled(item.keys.clone(), self.key_style);
+            let keys_width = (keys.width() as u16).min(row.width);
+            self.item_line(item).render(
+                Rect {
+                    width: row.width.saturating_sub(keys_width + 1),
+                    ..row
+                },
+                buf,
+            );
+            keys.render(
+                Rect {
+                    x: row.right() - keys_width,
+                    width: keys_width,
+                    ..row
+                },
+                buf,
+            );
+            if i == self.selected {
+                buf.set_style(row, self.selected_style);
+            }
+        }
+    }
+}
+
+impl<T> MeasureNode for RenderCommandPalette<T> {
+    fn measure(
+        &self,
+        _known_dimensions: taffy::Size<Option<f32>>,
+        _available_space: taffy::Size<taffy::AvailableSpace>,
+        _style: &taffy::Style,
+    ) -> taffy::Size<f32> {
+        self.estimate_size()
+    }
+
+    fn estimate_size(&self) -> taffy::Size<f32> {
+        let width = self
+            .items
+            .iter()
+            .map(|item| Span::raw(&item.label).width() + Span::raw(&item.keys).width() + 2)
+            .chain([Span::raw(&self.query).width() + 3])
+            .max()
+            .unwrap_or(0);
+        let rows = self.items.len().clamp(1, self.max_results.max(1));
+        taffy::Size {
+            width: width as f32,
+            height: (self.header_height() + rows) as f32,
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "./command_palette_test.rs"]
+mod command_palette_test;
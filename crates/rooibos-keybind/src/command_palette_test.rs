@@ -0,0 +1,74 @@
+use std::collections::VecDeque;
+
+use super::{PaletteItem, fuzzy_match, rank_items};
+use crate::CommandEntry;
+
+fn score(pattern: &str, text: &str) -> i64 {
+    fuzzy_match(pattern, text).unwrap().score
+}
+
+fn rank(labels: &[&str], pattern: &str, recent: &[&str]) -> Vec<String> {
+    let items = labels.iter().map(|label| PaletteItem::<()> {
+        entry: CommandEntry::Command {
+            name: label.to_string(),
+            aliases: Vec::new(),
+        },
+        label: label.to_string(),
+        keys: String::new(),
+        indices: Vec::new(),
+    });
+    let recent: VecDeque<_> = recent.iter().map(|r| r.to_string()).collect();
+    rank_items(items, pattern, &recent)
+        .into_iter()
+        .map(|item| item.label)
+        .collect()
+}
+
+#[test]
+fn fuzzy_match_indices() {
+    let matched = fuzzy_match("OF", "open file").unwrap();
+    assert_eq!(matched.indices, vec![0, 5]);
+    // Whitespace in the pattern is ignored
+    assert_eq!(fuzzy_match("o f", "open file").unwrap().indices, vec![0, 5]);
+    assert_eq!(fuzzy_match("fo", "open file"), None);
+    assert_eq!(fuzzy_match("xyz", "open"), None);
+}
+
+#[test]
+fn fuzzy_match_ranking() {
+    // Consecutive characters beat scattered ones
+    assert!(score("abc", "abc") > score("abc", "axbxc"));
+    // Matches at the start of a word beat matches in the middle of one
+    assert!(score("op", "open file") > score("op", "stop"));
+    assert!(score("gs", "getState") > score("gs", "gloss"));
+    // Shorter strings win when everything else is equal
+    assert!(score("q", "quit") > score("q", "quit-all-windows"));
+}
+
+#[test]
+fn empty_query_orders_by_recency() {
+    let labels = ["save", "open", "quit", "close-all-windows"];
+    assert_eq!(
+        rank(&labels, "", &["quit", "save"]),
+        vec!["quit", "save", "close-all-windows", "open"]
+    );
+    assert_eq!(
+        rank(&labels, "", &[]),
+        vec!["close-all-windows", "open", "quit", "save"]
+    );
+}
+
+#[test]
+fn query_orders_by_score() {
+    // Recent commands only break ties
+    assert_eq!(
+        rank(&["stop", "open"], "op", &["stop"]),
+        vec!["open", "stop"]
+    );
+    assert_eq!(
+        rank(&["quit", "quiz"], "q", &["quiz"]),
+        vec!["quiz", "quit"]
+    );
+    // Items that don't match are left out
+    assert_eq!(rank(&["save", "open", "quit"], "qu", &[]), vec!["quit"]);
+}
@@ -4,26 +4,27 @@ use std::sync::Arc;
 use modalkit::actions::{Action, MacroAction};
 use modalkit::editing::application::ApplicationAction;
 use modalkit::editing::context::MatchedKey;
-use modalkit::editing::key::KeyManager;
 use modalkit::env::CommonKeyClass;
-use modalkit::env::vim::VimMode;
-use modalkit::env::vim::keybindings::{InputStep, VimMachine};
 use modalkit::key::TerminalKey;
-use modalkit::keybindings::{BindingMachine, EdgePathPart};
-use modalkit::prelude::{Count, RepeatType};
+use modalkit::keybindings::{BindingMachine, EdgeEvent, EdgePathPart};
+use modalkit::prelude::Count;
 use rooibos_dom::events::{IntoKeyHandler, KeyEventProps, KeyHandler};
-use rooibos_reactive::IntoSignal;
 use rooibos_reactive::graph::IntoReactiveValue;
 use rooibos_reactive::graph::computed::Memo;
 use rooibos_reactive::graph::effect::Effect;
-use rooibos_reactive::graph::signal::{WriteSignal, signal};
-use rooibos_reactive::graph::traits::{Get, Update, With, WriteValue};
+use rooibos_reactive::graph::owner::{StoredValue, on_cleanup};
+use rooibos_reactive::graph::signal::{ReadSignal, WriteSignal, signal};
+use rooibos_reactive::graph::traits::{Get, GetUntracked, Set, Update, With, WriteValue};
 use rooibos_reactive::graph::wrappers::read::Signal;
+use wasm_compat::cell::UsizeCell;
 use wasm_compat::sync::Mutex;
 
-use crate::{AppInfo, CommandBarContext, parse, provide_command_context, use_command_context};
+use crate::{
+    AppInfo, AppInfoManager, CommandBarContext, KeyMode, KeyPath, Keymap, KeymapError, keys, parse,
+    provide_command_context, use_command_context,
+};
 
-type AppInfoManager<T> = KeyManager<TerminalKey, Action<AppInfo<T>>, RepeatType>;
+static ACTION_ID: UsizeCell = UsizeCell::new(0);
 
 #[derive(Clone)]
 struct KeyMapHolder<T>
@@ -32,6 +33,91 @@ where
 {
     bindings: Arc<Mutex<AppInfoManager<T>>>,
     mappings: HashMap<String, Arc<Mutex<Box<dyn KeybindHandler + Send + Sync>>>>,
+    sequences: Vec<KeySequence<T>>,
+}
+
+#[derive(Clone)]
+struct KeySequence<T> {
+    path: Vec<EdgePathPart<TerminalKey, CommonKeyClass>>,
+    // Display text for each part of the path
+    keys: Vec<String>,
+    // Handlers don't have an action to describe them
+    action: Option<T>,
+}
+
+impl<T> KeySequence<T> {
+    fn starts_with(&self, pending: &[TerminalKey]) -> bool {
+        self.path.len() > pending.len()
+            && self
+                .path
+                .iter()
+                .zip(pending)
+                .all(|((_, event), key)| match event {
+                    EdgeEvent::Key(k) => k == key,
+                    // Key classes can't be checked without the keybinding environment, so they're
+                    // treated as matching anything
+                    _ => true,
+                })
+    }
+}
+
+impl<T> KeyMapHolder<T>
+where
+    T: ApplicationAction + Send + Sync,
+{
+    fn pending_keys(&self, pending: &[TerminalKey]) -> Option<PendingKeys<T>> {
+        if pending.is_empty() {
+            return None;
+        }
+        let mut prefix = None;
+        let mut hints: Vec<KeyHint<T>> = Vec::new();
+        for sequence in self.sequences.iter().filter(|s| s.starts_with(pending)) {
+            // The display text can't be matched up with the path if the key string had any
+            // unusual syntax
+            if sequence.keys.len() != sequence.path.len() {
+                continue;
+            }
+            prefix.get_or_insert_with(|| sequence.keys[..pending.len()].concat());
+            let key = &sequence.keys[pending.len()];
+            let hint = match hints.iter_mut().find(|h| h.key == *key) {
+                Some(hint) => hint,
+                None => {
+                    hints.push(KeyHint {
+                        key: key.clone(),
+                        action: None,
+                        continuations: 0,
+                    });
+                    hints.last_mut().unwrap()
+                }
+            };
+            if sequence.path.len() == pending.len() + 1 {
+                hint.action = sequence.action.clone();
+            } else {
+                hint.continuations += 1;
+            }
+        }
+        Some(PendingKeys {
+            prefix: prefix?,
+            hints,
+        })
+    }
+}
+
+/// Key sequence that has been partially entered, along with the keys that can be pressed to
+/// continue it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PendingKeys<T> {
+    pub prefix: String,
+    pub hints: Vec<KeyHint<T>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyHint<T> {
+    pub key: String,
+    /// Action that runs if this key completes a binding.
+    pub action: Option<T>,
+    /// Number of longer bindings that continue with this key.
+    pub continuations: usize,
 }
 
 impl<T> PartialEq for KeyMapHolder<T>
@@ -52,7 +138,9 @@ where
     T: ApplicationAction + Send + Sync + 'static,
 {
     bindings: Signal<KeyMapHolder<T>>,
+    key_maps: ReadSignal<HashMap<String, InternalKeyMap<T>>>,
     set_key_maps: WriteSignal<HashMap<String, InternalKeyMap<T>>>,
+    set_keymaps: WriteSignal<Vec<(usize, Signal<Keymap<T>>)>>,
 }
 
 impl<T> Default for KeyMapper<T>
@@ -84,53 +172,133 @@ where
     T: ApplicationAction + Send + Sync,
 {
     pub fn new() -> Self {
+        provide_command_context::<T>();
         let (key_maps, set_key_maps) = signal(HashMap::<String, InternalKeyMap<T>>::new());
+        let (keymaps, set_keymaps) = signal(Vec::<(usize, Signal<Keymap<T>>)>::new());
+        let command_context = use_command_context::<T>();
+        let backend = command_context.backend;
+        let current_mode = command_context.mode();
         let bindings = Memo::new(move |_| {
-            let mut ism = VimMachine::empty();
+            let current_mode = current_mode.get();
+            let in_mode = |mode: &Option<KeyMode>| mode.as_ref().is_none_or(|m| *m == current_mode);
+            let mut mapped = Vec::new();
             let mut mappings = HashMap::default();
+            let mut sequences = Vec::new();
             key_maps.with(|k| {
                 for key_map in k.values() {
                     match key_map {
-                        InternalKeyMap::Action(key, action) => {
-                            ism.add_mapping(
-                                VimMode::Normal,
-                                key,
-                                &InputStep::<AppInfo<T>>::new()
-                                    .actions(vec![Action::Application(action.clone())]),
-                            );
+                        InternalKeyMap::Action(mode, key_str, key, action) if in_mode(mode) => {
+                            add_action(&mut mapped, &mut sequences, key_str, key.clone(), action);
                         }
-                        InternalKeyMap::Handler(key, handler) => {
+                        InternalKeyMap::Handler(mode, key_str, key, handler) if in_mode(mode) => {
+                            sequences.push(KeySequence {
+                                path: key.clone(),
+                                keys: keys::split(key_str),
+                                action: None,
+                            });
                             let macro_key = format!("__internal:{key:?}");
-                            ism.add_mapping(
-                                VimMode::Normal,
-                                key,
-                                &InputStep::<AppInfo<T>>::new().actions(vec![Action::Macro(
-                                    MacroAction::Run(macro_key.clone(), Count::Contextual),
-                                )]),
-                            );
+                            mapped.push((
+                                key.clone(),
+                                Action::Macro(MacroAction::Run(
+                                    macro_key.clone(),
+                                    Count::Contextual,
+                                )),
+                            ));
                             mappings.insert(macro_key, handler.clone());
                         }
+                        _ => {}
                     }
                 }
             });
+            // Keymaps are added last so they take precedence over bindings with the same keys
+            for (_, keymap) in keymaps.get() {
+                keymap.with(|keymap| {
+                    for (key_str, action) in keymap.bindings() {
+                        add_action(&mut mapped, &mut sequences, key_str, parse(key_str), action);
+                    }
+                });
+            }
             KeyMapHolder {
-                bindings: Arc::new(Mutex::new(KeyManager::new(ism))),
+                bindings: Arc::new(Mutex::new(backend.get().key_manager(mapped))),
                 mappings,
+                sequences,
             }
         });
         Self {
             bindings: bindings.into(),
+            key_maps,
             set_key_maps,
+            set_keymaps,
         }
     }
 
+    /// Adds every binding from a [`Keymap`], such as one loaded from a config file. The bindings
+    /// are replaced whenever the keymap changes.
+    ///
+    /// Keymap bindings take precedence over bindings defined in code. The returned signal reports
+    /// every keymap binding that conflicts with one defined in code on this mapper.
+    pub fn keymap<M>(
+        &mut self,
+        keymap: impl IntoReactiveValue<Signal<Keymap<T>>, M>,
+    ) -> Signal<Vec<KeymapError>> {
+        let keymap = keymap.into_reactive_value();
+        let keymap_id = ACTION_ID.add(1);
+        let set_keymaps = self.set_keymaps;
+        set_keymaps.update(|k| k.push((keymap_id, keymap)));
+
+        // Keep track of the bindings so they can be shown in the command palette
+        let key_actions = use_command_context::<T>().key_actions;
+        let ids = StoredValue::new(Vec::new());
+        let remove_actions = move || {
+            let mut key_actions = key_actions.write_value();
+            for id in ids.write_value().drain(..) {
+                key_actions.remove(&id);
+            }
+        };
+        on_cleanup(move || {
+            remove_actions();
+            set_keymaps.try_update(|k| k.retain(|(id, _)| *id != keymap_id));
+        });
+        Effect::new(move |_| {
+            remove_actions();
+            keymap.with(|keymap| {
+                let mut key_actions = key_actions.write_value();
+                let mut ids = ids.write_value();
+                for (key_str, action) in keymap.bindings() {
+                    let id = ACTION_ID.add(1);
+                    key_actions.insert(id, (key_str.clone(), action.clone()));
+                    ids.push(id);
+                }
+            });
+        });
+
+        let key_maps = self.key_maps;
+        Memo::new(move |_| {
+            key_maps.with(|key_maps| {
+                let bound = key_maps.values().map(|key_map| match key_map {
+                    InternalKeyMap::Action(_, key_str, ..)
+                    | InternalKeyMap::Handler(_, key_str, ..) => key_str.as_str(),
+                });
+                keymap.with(|keymap| keymap.conflicts(bound))
+            })
+        })
+        .into()
+    }
+
     fn map(&mut self, map: KeyActionMap<T>) {
+        self.map_in_mode(map, None);
+    }
+
+    fn map_in_mode(&mut self, map: KeyActionMap<T>, mode: Option<KeyMode>) {
         match map {
             KeyActionMap::Action(key, action) => {
-                self.action_inner(key, action);
+                self.action_inner(key, action, mode);
             }
             KeyActionMap::Handler(key, handler) => {
-                self.handler_inner(key, handler);
+                self.handler_inner(key, handler, mode);
+            }
+            KeyActionMap::Mode(mode, map) => {
+                self.map_in_mode(*map, Some(mode));
             }
         }
     }
@@ -139,34 +307,50 @@ where
     where
         S: IntoReactiveValue<Signal<String>, M>,
     {
-        let key = key.into_reactive_value();
-        let key = (move || parse(key.get())).signal();
-        self.action_inner(key, action)
+        self.action_inner(key.into_reactive_value(), action, None)
     }
 
-    fn action_inner(
-        &mut self,
-        key: Signal<Vec<EdgePathPart<TerminalKey, CommonKeyClass>>>,
-        action: T,
-    ) {
-        let mut action = Some(action);
+    fn action_inner(&mut self, key: Signal<String>, action: T, mode: Option<KeyMode>) {
         let set_key_maps = self.set_key_maps;
+        // Keep track of the binding so it can be shown in the command palette
+        let key_actions = use_command_context::<T>().key_actions;
+        let id = ACTION_ID.add(1);
+        key_actions
+            .write_value()
+            .insert(id, (key.get_untracked(), action.clone()));
+        on_cleanup(move || {
+            key_actions.write_value().remove(&id);
+        });
+
+        let mut action = Some(action);
         Effect::new(move |prev| {
-            let new_key = key.get();
-            let new_key_str = format!("{new_key:?}");
+            let key_str = key.get();
+            if let Some((current, _)) = key_actions.write_value().get_mut(&id) {
+                current.clone_from(&key_str);
+            }
+            let new_key = parse(&key_str);
+            let new_key_str = map_key(&mode, &new_key);
             if let Some(prev) = prev {
                 set_key_maps.update(|m| {
                     let map = m.remove(&prev).unwrap();
-                    let InternalKeyMap::Action(_, action) = map else {
+                    let InternalKeyMap::Action(_, _, _, action) = map else {
                         unreachable!();
                     };
-                    m.insert(new_key_str.clone(), InternalKeyMap::Action(new_key, action));
+                    m.insert(
+                        new_key_str.clone(),
+                        InternalKeyMap::Action(mode.clone(), key_str, new_key, action),
+                    );
                 });
             } else {
                 set_key_maps.update(|m| {
                     m.insert(
                         new_key_str.clone(),
-                        InternalKeyMap::Action(new_key, action.take().unwrap()),
+                        InternalKeyMap::Action(
+                            mode.clone(),
+                            key_str,
+                            new_key,
+                            action.take().unwrap(),
+                        ),
                     );
                 });
             }
@@ -182,39 +366,43 @@ where
         let handler = Arc::new(Mutex::new(
             Box::new(handler) as Box<dyn KeybindHandler + Send + Sync>
         ));
-        let key = key.into_reactive_value();
-        let key = (move || parse(key.get())).signal();
-
-        self.handler_inner(key, handler);
+        self.handler_inner(key.into_reactive_value(), handler, None);
     }
 
     fn handler_inner(
         &mut self,
-        key: Signal<Vec<EdgePathPart<TerminalKey, CommonKeyClass>>>,
+        key: Signal<String>,
         handler: Arc<Mutex<Box<dyn KeybindHandler + Send + Sync>>>,
+        mode: Option<KeyMode>,
     ) {
         let set_key_maps = self.set_key_maps;
         let mut handler = Some(handler);
         Effect::new(move |prev| {
-            let new_key = key.get();
-            let new_key_str = format!("{new_key:?}");
+            let key_str = key.get();
+            let new_key = parse(&key_str);
+            let new_key_str = map_key(&mode, &new_key);
 
             if let Some(prev) = prev {
                 set_key_maps.update(|m| {
                     let map = m.remove(&prev).unwrap();
-                    let InternalKeyMap::Handler(_, handler) = map else {
+                    let InternalKeyMap::Handler(_, _, _, handler) = map else {
                         unreachable!();
                     };
                     m.insert(
                         new_key_str.clone(),
-                        InternalKeyMap::Handler(new_key, handler),
+                        InternalKeyMap::Handler(mode.clone(), key_str, new_key, handler),
                     );
                 });
             } else {
                 set_key_maps.update(|m| {
                     m.insert(
                         new_key_str.clone(),
-                        InternalKeyMap::Handler(new_key, handler.take().unwrap()),
+                        InternalKeyMap::Handler(
+                            mode.clone(),
+                            key_str,
+                            new_key,
+                            handler.take().unwrap(),
+                        ),
                     );
                 });
             }
@@ -223,6 +411,28 @@ where
     }
 }
 
+fn add_action<T>(
+    mapped: &mut Vec<(KeyPath, Action<AppInfo<T>>)>,
+    sequences: &mut Vec<KeySequence<T>>,
+    key_str: &str,
+    key: KeyPath,
+    action: &T,
+) where
+    T: ApplicationAction,
+{
+    sequences.push(KeySequence {
+        path: key.clone(),
+        keys: keys::split(key_str),
+        action: Some(action.clone()),
+    });
+    mapped.push((key, Action::Application(action.clone())));
+}
+
+// Bindings for different modes can use the same keys
+fn map_key(mode: &Option<KeyMode>, key: &KeyPath) -> String {
+    format!("{mode:?}:{key:?}")
+}
+
 #[derive(Clone, Debug)]
 pub struct KeybindContext {
     pub keys: Vec<MatchedKey>,
@@ -248,11 +458,19 @@ impl KeybindHandler for Box<dyn KeybindHandler> {
 }
 
 pub enum KeyActionMap<T> {
-    Action(Signal<Vec<EdgePathPart<TerminalKey, CommonKeyClass>>>, T),
+    /// Binds a key to an action.
+    ///
+    /// The key is stored as the unparsed key string (such as `"<c-p>"`) instead of the parsed key
+    /// sequence so it can be shown in the [`CommandPalette`](crate::CommandPalette). It's parsed
+    /// when the binding is registered. Prefer [`KeyActionMap::action`] over constructing this
+    /// variant directly.
+    Action(Signal<String>, T),
     Handler(
-        Signal<Vec<EdgePathPart<TerminalKey, CommonKeyClass>>>,
+        Signal<String>,
         Arc<Mutex<Box<dyn KeybindHandler + Send + Sync>>>,
     ),
+    /// Binding that's only active in the given mode.
+    Mode(KeyMode, Box<KeyActionMap<T>>),
 }
 
 pub type KeyMap = KeyActionMap<()>;
@@ -269,9 +487,7 @@ where
     where
         S: IntoReactiveValue<Signal<String>, M>,
     {
-        let key = key.into_reactive_value();
-        let key = (move || parse(key.get())).signal();
-        Self::Action(key, action)
+        Self::Action(key.into_reactive_value(), action)
     }
 
     pub fn handler<S, H, M>(key: S, handler: H) -> KeyActionMap<T>
@@ -279,9 +495,28 @@ where
         S: IntoReactiveValue<Signal<String>, M>,
         H: KeybindHandler + Send + Sync + 'static,
     {
-        let key = key.into_reactive_value();
-        let key = (move || parse(key.get())).signal();
-        KeyActionMap::Handler(key, Arc::new(Mutex::new(Box::new(handler))))
+        KeyActionMap::Handler(
+            key.into_reactive_value(),
+            Arc::new(Mutex::new(Box::new(handler))),
+        )
+    }
+
+    /// Binds a key that switches to a different mode.
+    pub fn goto<S, K, M>(key: S, mode: K) -> KeyActionMap<T>
+    where
+        S: IntoReactiveValue<Signal<String>, M>,
+        K: Into<KeyMode>,
+        T: 'static,
+    {
+        provide_command_context::<T>();
+        let command_context = use_command_context::<T>();
+        let mode = mode.into();
+        Self::handler(key, move |_, _| command_context.set_mode(mode.clone()))
+    }
+
+    /// Restricts the binding so it's only active in the given mode.
+    pub fn in_mode(self, mode: impl Into<KeyMode>) -> Self {
+        Self::Mode(mode.into(), Box::new(self))
     }
 }
 
@@ -294,10 +529,13 @@ where
     }
 }
 
+// The original key string is kept around for displaying key hints
 enum InternalKeyMap<T> {
-    Action(Vec<EdgePathPart<TerminalKey, CommonKeyClass>>, T),
+    Action(Option<KeyMode>, String, KeyPath, T),
     Handler(
-        Vec<EdgePathPart<TerminalKey, CommonKeyClass>>,
+        Option<KeyMode>,
+        String,
+        KeyPath,
         Arc<Mutex<Box<dyn KeybindHandler + Send + Sync>>>,
     ),
 }
@@ -316,6 +554,8 @@ where
 {
     manager: Signal<KeyMapHolder<T>>,
     command_context: CommandBarContext<T>,
+    pending: Vec<TerminalKey>,
+    showing_hints: bool,
 }
 
 impl<K, T> From<K> for KeyInputHandler<T>
@@ -359,16 +599,21 @@ where
         Self {
             manager: mapper.bindings,
             command_context: use_command_context(),
+            pending: Vec::new(),
+            showing_hints: false,
         }
     }
 
     fn read(&mut self, props: KeyEventProps) {
-        let mut manager = self.manager.get();
-        let mappings = &mut manager.mappings;
-        let mut manager = manager.bindings.lock_mut();
-        manager.input_key(props.event.into());
+        let mut holder = self.manager.get();
+        let mappings = &mut holder.mappings;
+        let mut manager = holder.bindings.lock_mut();
+        let key: TerminalKey = props.event.into();
+        manager.input_key(key);
+        let mut completed = false;
 
         while let Some((action, context)) = manager.pop() {
+            completed = true;
             match action {
                 Action::Application(app_action) => {
                     let mut handlers = self.command_context.command_handlers.write_value();
@@ -397,6 +642,29 @@ where
                 _ => {}
             }
         }
+
+        self.pending.push(key);
+        if completed {
+            self.pending.clear();
+        }
+        let mut pending_keys = holder.pending_keys(&self.pending);
+        if pending_keys.is_none() && self.pending.len() > 1 {
+            // The previous sequence was abandoned, but this key could be the start of a new one
+            self.pending = vec![key];
+            pending_keys = holder.pending_keys(&self.pending);
+        }
+        if pending_keys.is_none() {
+            self.pending.clear();
+        }
+
+        // Other handlers may be showing hints, so only clear the ones we set
+        if pending_keys.is_some() {
+            self.command_context.pending_keys.set(pending_keys);
+            self.showing_hints = true;
+        } else if self.showing_hints {
+            self.command_context.pending_keys.set(None);
+            self.showing_hints = false;
+        }
     }
 }
 
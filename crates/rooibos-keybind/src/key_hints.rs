@@ -0,0 +1,294 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use modalkit::editing::application::ApplicationAction;
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Widget;
+use rooibos_dom::widgets::{Role, WidgetRole};
+use rooibos_dom::{MeasureNode, RenderNode, clock};
+use rooibos_reactive::col;
+use rooibos_reactive::dom::div::taffy;
+use rooibos_reactive::dom::layout::{
+    end, focusable, full, height, justify_content, show, width, z_index,
+};
+use rooibos_reactive::dom::{DomWidget, LayoutProps, Render, UpdateLayoutProps};
+use rooibos_reactive::graph::IntoReactiveValue;
+use rooibos_reactive::graph::effect::Effect;
+use rooibos_reactive::graph::owner::StoredValue;
+use rooibos_reactive::graph::signal::signal;
+use rooibos_reactive::graph::traits::{Get, GetUntracked, GetValue, Set, UpdateValue, With};
+use rooibos_reactive::graph::wrappers::read::Signal;
+use wasm_compat::futures::spawn_local;
+
+use crate::command_palette::LabelFn;
+use crate::use_command_context;
+
+/// Key hints are mounted as their own root at this z-index so they're drawn on top of the rest of
+/// the app, but below the [`CommandPalette`](crate::CommandPalette).
+pub const KEY_HINTS_Z_INDEX: i32 = 80;
+
+/// Overlay that lists the keys that can be pressed to continue a partially entered key sequence.
+///
+/// The hints are taken from the bindings of every [`KeyMapper`](crate::KeyMapper). They're shown
+/// at the bottom of the screen once a sequence has been pending for the configured delay.
+pub struct KeyHints<T> {
+    delay: Signal<Duration>,
+    action_label: Arc<LabelFn<T>>,
+    style: Signal<Style>,
+    prefix_style: Signal<Style>,
+    key_style: Signal<Style>,
+    group_style: Signal<Style>,
+    layout_props: LayoutProps,
+}
+
+impl<T> UpdateLayoutProps for KeyHints<T> {
+    fn layout_props(&self) -> LayoutProps {
+        self.layout_props.clone()
+    }
+
+    fn update_props(mut self, props: LayoutProps) -> Self {
+        self.layout_props = props;
+        self
+    }
+}
+
+impl<T> Default for KeyHints<T>
+where
+    T: ApplicationAction + Send + Sync + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> KeyHints<T>
+where
+    T: ApplicationAction + Send + Sync + 'static,
+{
+    pub fn new() -> Self {
+        Self {
+            delay: Duration::from_millis(500).into(),
+            action_label: Arc::new(|action| format!("{action:?}")),
+            style: Style::default().into(),
+            prefix_style: Style::default().bold().into(),
+            key_style: Style::default().yellow().into(),
+            group_style: Style::default().cyan().into(),
+            layout_props: LayoutProps::default(),
+        }
+    }
+
+    /// How long a key sequence needs to be pending before the hints are shown.
+    pub fn delay<M>(mut self, delay: impl IntoReactiveValue<Signal<Duration>, M>) -> Self {
+        self.delay = delay.into_reactive_value();
+        self
+    }
+
+    /// Text shown for each action. Defaults to the [`Debug`] representation of the action.
+    pub fn action_label<F>(mut self, action_label: F) -> Self
+    where
+        F: Fn(&T) -> String + Send + Sync + 'static,
+    {
+        self.action_label = Arc::new(action_label);
+        self
+    }
+
+    pub fn style<M>(mut self, style: impl IntoReactiveValue<Signal<Style>, M>) -> Self {
+        self.style = style.into_reactive_value();
+        self
+    }
+
+    /// Style applied to the keys that have already been entered.
+    pub fn prefix_style<M>(
+        mut self,
+        prefix_style: impl IntoReactiveValue<Signal<Style>, M>,
+    ) -> Self {
+        self.prefix_style = prefix_style.into_reactive_value();
+        self
+    }
+
+    pub fn key_style<M>(mut self, key_style: impl IntoReactiveValue<Signal<Style>, M>) -> Self {
+        self.key_style = key_style.into_reactive_value();
+        self
+    }
+
+    /// Style applied to keys that lead to longer sequences rather than an action.
+    pub fn group_style<M>(mut self, group_style: impl IntoReactiveValue<Signal<Style>, M>) -> Self {
+        self.group_style = group_style.into_reactive_value();
+        self
+    }
+
+    pub fn render(self) -> impl Render {
+        let Self {
+            delay,
+            action_label,
+            style,
+            prefix_style,
+            key_style,
+            group_style,
+            layout_props,
+        } = self;
+
+        let pending_keys = use_command_context::<T>().pending_keys();
+        let (visible, set_visible) = signal(false);
+        // Incremented whenever the pending keys change so stale timers can be ignored
+        let generation = StoredValue::new(0usize);
+
+        Effect::new(move |_| {
+            let is_pending = pending_keys.with(|p| p.is_some());
+            generation.update_value(|g| *g += 1);
+            if !is_pending {
+                set_visible.set(false);
+                return;
+            }
+            if visible.get_untracked() {
+                return;
+            }
+            let current = generation.get_value();
+            let delay = delay.get_untracked();
+            spawn_local(async move {
+                clock::sleep(delay).await;
+                if generation.try_get_value() == Some(current) {
+                    set_visible.set(true);
+                }
+            });
+        });
+
+        col![
+            style(
+                z_index(KEY_HINTS_Z_INDEX),
+                width(full()),
+                height(full()),
+                justify_content(end()),
+                show(visible),
+                focusable(false),
+            ),
+            DomWidget::new(move || {
+                let (prefix, hints) = pending_keys.with(|pending| {
+                    let Some(pending) = pending else {
+                        return (String::new(), Vec::new());
+                    };
+                    let hints = pending
+                        .hints
+                        .iter()
+                        .map(|hint| {
+                            let description = match &hint.action {
+                                Some(action) => Span::raw(action_label(action)),
+                                None if hint.continuations > 0 => Span::styled(
+                                    format!("+{} more", hint.continuations),
+                                    group_style.get(),
+                                ),
+                                None => Span::raw(""),
+                            };
+                            let key = if hint.action.is_none() && hint.continuations > 0 {
+                                Span::styled(hint.key.clone(), group_style.get())
+                            } else {
+                                Span::styled(hint.key.clone(), key_style.get())
+                            };
+                            Line::from(vec![key, Span::raw(" → "), description])
+                        })
+                        .collect();
+                    (pending.prefix.clone(), hints)
+                });
+                RenderKeyHints {
+                    prefix: Span::styled(prefix, prefix_style.get()),
+                    hints,
+                    style: style.get(),
+                }
+            })
+            .layout_props(layout_props)
+            .clear(true)
+            .focusable(false)
+        ]
+    }
+}
+
+struct RenderKeyHints {
+    prefix: Span<'static>,
+    hints: Vec<Line<'static>>,
+    style: Style,
+}
+
+impl RenderKeyHints {
+    fn column_width(&self) -> usize {
+        // Leave a gap between columns
+        self.hints.iter().map(|h| h.width()).max().unwrap_or(0) + 2
+    }
+
+    fn columns(&self, width: usize) -> usize {
+        (width / self.column_width()).max(1)
+    }
+
+    fn rows(&self, width: usize) -> usize {
+        self.hints.len().div_ceil(self.columns(width))
+    }
+}
+
+impl WidgetRole for RenderKeyHints {
+    fn widget_role() -> Option<Role> {
+        None
+    }
+}
+
+impl RenderNode for RenderKeyHints {
+    fn render(&mut self, area: Rect, frame: &mut Frame) {
+        let buf = frame.buffer_mut();
+        buf.set_style(area, self.style);
+        if area.is_empty() {
+            return;
+        }
+
+        Line::from(self.prefix.clone()).render(Rect { height: 1, ..area }, buf);
+        let column_width = self.column_width();
+        let rows = self.rows(area.width as usize);
+        for (i, hint) in self.hints.iter().enumerate() {
+            // Fill each column from top to bottom so the hints read like a list
+            let (column, row) = (i / rows, i % rows);
+            let x = (column * column_width) as u16;
+            let y = (row + 1) as u16;
+            if x >= area.width || y >= area.height {
+                continue;
+            }
+            hint.render(
+                Rect {
+                    x: area.x + x,
+                    y: area.y + y,
+                    width: (column_width as u16).min(area.width - x),
+                    height: 1,
+                },
+                buf,
+            );
+        }
+    }
+}
+
+impl MeasureNode for RenderKeyHints {
+    fn measure(
+        &self,
+        known_dimensions: taffy::Size<Option<f32>>,
+        available_space: taffy::Size<taffy::AvailableSpace>,
+        _style: &taffy::Style,
+    ) -> taffy::Size<f32> {
+        let width = known_dimensions
+            .width
+            .unwrap_or(match available_space.width {
+                taffy::AvailableSpace::Definite(width) => width,
+                _ => self.estimate_size().width,
+            });
+        taffy::Size {
+            width,
+            height: known_dimensions
+                .height
+                .unwrap_or((self.rows(width as usize) + 1) as f32),
+        }
+    }
+
+    fn estimate_size(&self) -> taffy::Size<f32> {
+        taffy::Size {
+            width: (self.column_width() * self.hints.len()).max(self.prefix.width()) as f32,
+            height: (self.hints.len().min(1) + 1) as f32,
+        }
+    }
+}
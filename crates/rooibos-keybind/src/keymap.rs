@@ -0,0 +1,241 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::try_parse;
+
+/// Serializable set of key bindings that maps key strings to named actions.
+///
+/// Keys use the same syntax as [`keys::combine`](crate::keys::combine), e.g. `<C-Up>` or `gg`.
+/// Since it's a plain map, it can be embedded in any config format supported by serde:
+///
+/// ```toml
+/// [keys]
+/// "<C-Up>" = "count 1"
+/// "<C-r>" = "reset"
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct KeymapConfig {
+    pub bindings: BTreeMap<String, String>,
+}
+
+impl KeymapConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn bind(mut self, key: impl Into<String>, action: impl Into<String>) -> Self {
+        self.bindings.insert(key.into(), action.into());
+        self
+    }
+}
+
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
+pub enum KeymapError {
+    #[error("invalid key sequence {key:?}")]
+    InvalidKey { key: String },
+    #[error("unknown action {action:?} bound to {key:?}")]
+    UnknownAction { key: String, action: String },
+    #[error("{key:?} and {other:?} are the same key sequence")]
+    Conflict { key: String, other: String },
+    #[error("{prefix:?} is the start of {key:?}, so they can't both be bound")]
+    PrefixConflict { key: String, prefix: String },
+    #[error("{key:?} conflicts with {other:?}, which is bound in code")]
+    BindingConflict { key: String, other: String },
+}
+
+/// Key bindings from a [`KeymapConfig`] with every action name resolved.
+///
+/// Use [`KeyMapper::keymap`](crate::KeyMapper::keymap) to apply the bindings.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Keymap<T> {
+    bindings: Vec<(String, T)>,
+}
+
+impl<T> Default for Keymap<T> {
+    fn default() -> Self {
+        Self {
+            bindings: Vec::new(),
+        }
+    }
+}
+
+impl<T> Keymap<T> {
+    /// Validates every binding in the config and resolves the action names with `resolve`.
+    ///
+    /// All invalid keys, unknown actions, keys that parse to the same sequence, and sequences that
+    /// start with another bound sequence (like `g` and `gg`) are reported rather than stopping at
+    /// the first error.
+    ///
+    /// Use [`Keymap::conflicts`] to check the keymap against bindings defined in code.
+    pub fn parse<F>(config: &KeymapConfig, resolve: F) -> Result<Self, Vec<KeymapError>>
+    where
+        F: Fn(&str) -> Option<T>,
+    {
+        let mut bindings = Vec::new();
+        let mut errors = Vec::new();
+        let mut seen: Vec<(&String, Vec<String>)> = Vec::new();
+
+        for (key, action_name) in &config.bindings {
+            let Some(parts) = key_parts(key) else {
+                errors.push(KeymapError::InvalidKey { key: key.clone() });
+                continue;
+            };
+            // Different strings can refer to the same keys, e.g. "<C-a>" and "<c-a>"
+            if let Some((other, _)) = seen.iter().find(|(_, other)| *other == parts) {
+                errors.push(KeymapError::Conflict {
+                    key: key.clone(),
+                    other: (*other).clone(),
+                });
+                continue;
+            }
+            if let Some((other, other_parts)) =
+                seen.iter().find(|(_, other)| overlaps(&parts, other))
+            {
+                let (key, prefix) = if other_parts.len() < parts.len() {
+                    (key, *other)
+                } else {
+                    (*other, key)
+                };
+                errors.push(KeymapError::PrefixConflict {
+                    key: key.clone(),
+                    prefix: prefix.clone(),
+                });
+                continue;
+            }
+            seen.push((key, parts));
+            match resolve(action_name) {
+                Some(action) => bindings.push((key.clone(), action)),
+                None => errors.push(KeymapError::UnknownAction {
+                    key: key.clone(),
+                    action: action_name.clone(),
+                }),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(Self { bindings })
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Checks the bindings against keys that are bound somewhere else, such as in code with
+    /// [`KeyMapper::action`](crate::KeyMapper::action). Bindings for the same sequence, or where
+    /// one sequence is the start of the other, are reported as conflicts.
+    pub fn conflicts<'a>(&self, keys: impl IntoIterator<Item = &'a str>) -> Vec<KeymapError> {
+        let bound: Vec<_> = keys
+            .into_iter()
+            .filter_map(|key| Some((key, key_parts(key)?)))
+            .collect();
+        let mut errors = Vec::new();
+        for (key, _) in &self.bindings {
+            let Some(parts) = key_parts(key) else {
+                continue;
+            };
+            for (other, other_parts) in &bound {
+                if parts == *other_parts || overlaps(&parts, other_parts) {
+                    errors.push(KeymapError::BindingConflict {
+                        key: key.clone(),
+                        other: other.to_string(),
+                    });
+                }
+            }
+        }
+        errors
+    }
+
+    pub fn bindings(&self) -> &[(String, T)] {
+        &self.bindings
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bindings.is_empty()
+    }
+}
+
+/// Parses a key string into a form that can be compared with other key sequences.
+fn key_parts(key: &str) -> Option<Vec<String>> {
+    let path = try_parse(key)?;
+    Some(path.iter().map(|part| format!("{part:?}")).collect())
+}
+
+/// Returns `true` if one sequence is the start of the other.
+fn overlaps(a: &[String], b: &[String]) -> bool {
+    a.len() != b.len() && (a.starts_with(b) || b.starts_with(a))
+}
+
+/// Resolves an action name by parsing it as a command, so a keymap can bind keys to commands
+/// with arguments like `"count 1"`.
+#[cfg(feature = "derive-commands")]
+pub fn parse_action<C>(name: &str) -> Option<C>
+where
+    C: clap::Parser,
+{
+    let args = shlex::split(&format!("- {name}"))?;
+    C::try_parse_from(args).ok()
+}
+
+#[cfg(feature = "config")]
+mod config {
+    use rooibos_config::use_config;
+    use rooibos_config::watch_config::LoadConfig;
+    use rooibos_reactive::graph::computed::Memo;
+    use rooibos_reactive::graph::traits::With;
+    use rooibos_reactive::graph::wrappers::read::Signal;
+
+    use super::{Keymap, KeymapConfig, KeymapError};
+
+    /// Keymap loaded from the app config, along with any errors from the latest version of the
+    /// config.
+    #[derive(Clone, Copy)]
+    pub struct KeymapSignal<T>
+    where
+        T: Send + Sync + 'static,
+    {
+        pub keymap: Signal<Keymap<T>>,
+        pub errors: Signal<Vec<KeymapError>>,
+    }
+
+    /// Reads the keymap out of the config provided with
+    /// [`provide_config`](rooibos_config::provide_config) so changes to the config file are
+    /// applied as soon as they're saved.
+    ///
+    /// If the updated keymap is invalid, the last valid keymap is kept and the errors are
+    /// reported in [`KeymapSignal::errors`].
+    pub fn use_keymap<C, T, S, R>(select: S, resolve: R) -> KeymapSignal<T>
+    where
+        C: LoadConfig + Clone + 'static,
+        C::Config: Send + Sync + 'static,
+        C::Error: Send + Sync + 'static,
+        T: PartialEq + Clone + Send + Sync + 'static,
+        S: Fn(&C::Config) -> &KeymapConfig + Send + Sync + 'static,
+        R: Fn(&str) -> Option<T> + Send + Sync + 'static,
+    {
+        let config = use_config::<C>();
+        let parsed = Memo::new(move |prev: Option<&(Keymap<T>, Vec<KeymapError>)>| {
+            config.with(|config| match config {
+                Ok(update) => match Keymap::parse(select(&update.new), &resolve) {
+                    Ok(keymap) => (keymap, Vec::new()),
+                    // Keep the last valid bindings so a bad edit doesn't unbind everything
+                    Err(errors) => (prev.map(|(k, _)| k.clone()).unwrap_or_default(), errors),
+                },
+                // Config errors are reported by the config itself
+                Err(_) => prev.cloned().unwrap_or_default(),
+            })
+        });
+
+        KeymapSignal {
+            keymap: Signal::derive(move || parsed.with(|(keymap, _)| keymap.clone())),
+            errors: Signal::derive(move || parsed.with(|(_, errors)| errors.clone())),
+        }
+    }
+}
+
+#[cfg(feature = "config")]
+pub use config::*;
+
+#[cfg(test)]
+#[path = "./keymap_test.rs"]
+mod keymap_test;
@@ -0,0 +1,93 @@
+use super::{Keymap, KeymapConfig, KeymapError};
+
+fn resolve(name: &str) -> Option<String> {
+    (name != "unknown").then(|| name.to_string())
+}
+
+fn binding(key: &str, action: &str) -> (String, String) {
+    (key.to_string(), action.to_string())
+}
+
+#[test]
+fn parse_bindings() {
+    let config = KeymapConfig::new().bind("<C-Up>", "up").bind("gg", "top");
+    let keymap = Keymap::parse(&config, resolve).unwrap();
+    assert_eq!(
+        keymap.bindings(),
+        [binding("<C-Up>", "up"), binding("gg", "top")]
+    );
+}
+
+#[test]
+fn parse_reports_every_error() {
+    let config = KeymapConfig::new()
+        .bind("", "empty")
+        .bind("<C-a>", "first")
+        .bind("<c-a>", "second")
+        .bind("x", "unknown");
+    let errors = Keymap::parse(&config, resolve).unwrap_err();
+    assert_eq!(
+        errors,
+        vec![
+            KeymapError::InvalidKey { key: String::new() },
+            KeymapError::Conflict {
+                key: "<c-a>".to_string(),
+                other: "<C-a>".to_string(),
+            },
+            KeymapError::UnknownAction {
+                key: "x".to_string(),
+                action: "unknown".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn parse_reports_prefix_conflicts() {
+    let config = KeymapConfig::new()
+        .bind("g", "go")
+        .bind("gg", "top")
+        .bind("gx", "open")
+        .bind("x", "close");
+    let errors = Keymap::parse(&config, resolve).unwrap_err();
+    assert_eq!(
+        errors,
+        vec![
+            KeymapError::PrefixConflict {
+                key: "gg".to_string(),
+                prefix: "g".to_string(),
+            },
+            KeymapError::PrefixConflict {
+                key: "gx".to_string(),
+                prefix: "g".to_string(),
+            },
+        ]
+    );
+    assert_eq!(
+        errors[0].to_string(),
+        r#""g" is the start of "gg", so they can't both be bound"#
+    );
+}
+
+#[test]
+fn conflicts_with_code_bindings() {
+    let config = KeymapConfig::new()
+        .bind("<C-r>", "reset")
+        .bind("gg", "top")
+        .bind("q", "quit");
+    let keymap = Keymap::parse(&config, resolve).unwrap();
+    assert_eq!(
+        keymap.conflicts(["<c-r>", "g", "x"]),
+        vec![
+            KeymapError::BindingConflict {
+                key: "<C-r>".to_string(),
+                other: "<c-r>".to_string(),
+            },
+            KeymapError::BindingConflict {
+                key: "gg".to_string(),
+                other: "g".to_string(),
+            },
+        ]
+    );
+    assert!(keymap.conflicts(["x", "<C-q>"]).is_empty());
+}
@@ -62,6 +62,29 @@ where
     }
 }
 
+/// Splits a key string into the individual keys in the sequence.
+///
+/// For example, `"<C-w>j"` is split into `["<C-w>", "j"]`.
+pub fn split(keys: &str) -> Vec<String> {
+    let mut split = Vec::new();
+    let mut rest = keys;
+    while let Some(c) = rest.chars().next() {
+        let close = match c {
+            '<' => Some('>'),
+            '{' => Some('}'),
+            _ => None,
+        };
+        // Brackets without a matching closing bracket are treated as literals
+        let len = close
+            .and_then(|close| rest[1..].find(close))
+            .filter(|&end| end > 0)
+            .map_or(c.len_utf8(), |end| end + 2);
+        split.push(rest[..len].to_string());
+        rest = &rest[len..];
+    }
+    split
+}
+
 impl From<Key<'_>> for Signal<String> {
     fn from(val: Key<'_>) -> Self {
         val.to_string().into()
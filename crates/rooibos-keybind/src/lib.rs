@@ -1,13 +1,21 @@
 mod command_bar;
 mod command_handler;
+mod command_palette;
 mod key_handler;
+mod key_hints;
+mod keymap;
 pub mod keys;
+mod modal;
 
 use std::fmt;
 
 pub use command_bar::*;
 pub use command_handler::*;
+pub use command_palette::*;
 pub use key_handler::*;
+pub use key_hints::*;
+pub use keymap::*;
+pub use modal::*;
 pub use modalkit::actions::Action;
 pub use modalkit::editing::application::ApplicationAction;
 pub use modalkit::editing::context::EditContext;
@@ -26,3 +34,10 @@ where
         .unwrap()
         .1
 }
+
+fn try_parse(input: &str) -> Option<Vec<(EdgeRepeat, EdgeEvent<TerminalKey, CommonKeyClass>)>> {
+    match modalkit::env::keyparse::parse(input) {
+        Ok(("", keys)) if !keys.is_empty() => Some(keys),
+        _ => None,
+    }
+}
@@ -0,0 +1,347 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use modalkit::actions::{
+    Action, CommandAction, CommandBarAction, EditAction, EditorAction, PromptAction,
+};
+use modalkit::editing::application::ApplicationAction;
+use modalkit::editing::key::KeyManager;
+use modalkit::env::CommonKeyClass;
+use modalkit::env::emacs::EmacsMode;
+use modalkit::env::emacs::keybindings::{EmacsMachine, InputStep as EmacsStep};
+use modalkit::env::vim::VimMode;
+use modalkit::env::vim::keybindings::{InputStep as VimStep, VimMachine};
+use modalkit::key::TerminalKey;
+use modalkit::keybindings::EdgePathPart;
+use modalkit::prelude::{
+    CommandType, CompletionDisplay, CompletionStyle, CompletionType, Count, EditTarget, MoveDir1D,
+    MoveType, RecallFilter, RepeatType, Specifier,
+};
+
+use crate::{AppInfo, keys, parse};
+
+pub type AppInfoManager<T> = KeyManager<TerminalKey, Action<AppInfo<T>>, RepeatType>;
+
+pub type KeyPath = Vec<EdgePathPart<TerminalKey, CommonKeyClass>>;
+
+/// Name of a set of key bindings that can be switched between at runtime.
+///
+/// Modes are tracked by the [`CommandBarContext`](crate::CommandBarContext) rather than by the
+/// modal backend, so apps can define as many as they need. Bindings are restricted to a mode with
+/// [`KeyActionMap::in_mode`](crate::KeyActionMap::in_mode).
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct KeyMode(Cow<'static, str>);
+
+impl KeyMode {
+    pub const NORMAL: Self = Self::new_static("normal");
+    pub const INSERT: Self = Self::new_static("insert");
+
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self(name.into())
+    }
+
+    pub const fn new_static(name: &'static str) -> Self {
+        Self(Cow::Borrowed(name))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for KeyMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<&'static str> for KeyMode {
+    fn from(value: &'static str) -> Self {
+        Self::new_static(value)
+    }
+}
+
+impl From<String> for KeyMode {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Key binding engine used by every [`KeyMapper`](crate::KeyMapper) and the
+/// [`CommandHandler`](crate::CommandHandler).
+///
+/// Set with [`CommandHandler::with_backend`](crate::CommandHandler::with_backend) or
+/// [`set_modal_backend`](crate::set_modal_backend). Defaults to [`Vim`].
+pub trait ModalBackend<T>: Send + Sync + 'static
+where
+    T: ApplicationAction,
+{
+    /// Mode that's active when the app starts.
+    fn initial_mode(&self) -> KeyMode;
+
+    /// Text to show in a status bar while the mode is active.
+    fn mode_indicator(&self, mode: &KeyMode) -> Option<String> {
+        if *mode == self.initial_mode() {
+            None
+        } else {
+            Some(mode.as_str().to_uppercase())
+        }
+    }
+
+    /// Builds the bindings for a [`KeyMapper`](crate::KeyMapper). Only the bindings for the
+    /// current mode are included.
+    fn key_manager(&self, bindings: Vec<(KeyPath, Action<AppInfo<T>>)>) -> AppInfoManager<T>;
+
+    /// Builds the bindings used to open and edit the [`CommandBar`](crate::CommandBar).
+    fn command_manager(&self) -> AppInfoManager<T>;
+}
+
+/// Vim-style bindings. The command bar is opened with `:`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Vim;
+
+impl<T> ModalBackend<T> for Vim
+where
+    T: ApplicationAction + Send + Sync + 'static,
+{
+    fn initial_mode(&self) -> KeyMode {
+        KeyMode::NORMAL
+    }
+
+    fn mode_indicator(&self, mode: &KeyMode) -> Option<String> {
+        if *mode == KeyMode::NORMAL {
+            None
+        } else {
+            Some(format!("-- {} --", mode.as_str().to_uppercase()))
+        }
+    }
+
+    fn key_manager(&self, bindings: Vec<(KeyPath, Action<AppInfo<T>>)>) -> AppInfoManager<T> {
+        vim_key_manager(bindings)
+    }
+
+    fn command_manager(&self) -> AppInfoManager<T> {
+        vim_command_manager(Some(":"))
+    }
+}
+
+/// Emacs-style bindings. The command bar is opened with `M-x` and closed with `C-g`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Emacs;
+
+impl<T> ModalBackend<T> for Emacs
+where
+    T: ApplicationAction + Send + Sync + 'static,
+{
+    fn initial_mode(&self) -> KeyMode {
+        KeyMode::INSERT
+    }
+
+    fn key_manager(&self, bindings: Vec<(KeyPath, Action<AppInfo<T>>)>) -> AppInfoManager<T> {
+        let mut ism = EmacsMachine::<TerminalKey, AppInfo<T>>::empty();
+        for (key, action) in bindings {
+            ism.add_mapping(
+                EmacsMode::Insert,
+                &key,
+                &EmacsStep::<AppInfo<T>>::new().actions(vec![action]),
+            );
+        }
+        KeyManager::new(ism)
+    }
+
+    fn command_manager(&self) -> AppInfoManager<T> {
+        let mut ism = EmacsMachine::<TerminalKey, AppInfo<T>>::empty();
+        ism.add_mapping(
+            EmacsMode::Insert,
+            &parse(keys::combine([keys::META, 'x'.into()])),
+            &EmacsStep::<AppInfo<T>>::new()
+                .actions(vec![focus_command_bar("M-x ")])
+                .goto(EmacsMode::Search),
+        );
+        ism.add_mapping(
+            EmacsMode::Search,
+            &parse(keys::combine([keys::CTRL, 'g'.into()])),
+            &EmacsStep::<AppInfo<T>>::new()
+                .actions(vec![Action::CommandBar(CommandBarAction::Unfocus)])
+                .goto(EmacsMode::Insert),
+        );
+        for (key, actions, exit) in command_bar_mappings() {
+            let mut step = EmacsStep::<AppInfo<T>>::new().actions(actions);
+            if exit {
+                step = step.goto(EmacsMode::Insert);
+            }
+            ism.add_mapping(EmacsMode::Search, &key, &step);
+        }
+        KeyManager::new(ism)
+    }
+}
+
+/// Non-modal bindings where every key runs its binding immediately. The command bar is only
+/// bound to a key if one is set with [`Plain::command_key`].
+#[derive(Clone, Debug, Default)]
+pub struct Plain {
+    command_key: Option<String>,
+}
+
+impl Plain {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn command_key(mut self, command_key: impl Into<String>) -> Self {
+        self.command_key = Some(command_key.into());
+        self
+    }
+}
+
+impl<T> ModalBackend<T> for Plain
+where
+    T: ApplicationAction + Send + Sync + 'static,
+{
+    fn initial_mode(&self) -> KeyMode {
+        KeyMode::NORMAL
+    }
+
+    fn key_manager(&self, bindings: Vec<(KeyPath, Action<AppInfo<T>>)>) -> AppInfoManager<T> {
+        // The vim machine doesn't add any bindings of its own when it's created empty, so it
+        // works fine as a non-modal keymap
+        vim_key_manager(bindings)
+    }
+
+    fn command_manager(&self) -> AppInfoManager<T> {
+        vim_command_manager(self.command_key.as_deref())
+    }
+}
+
+fn vim_key_manager<T>(bindings: Vec<(KeyPath, Action<AppInfo<T>>)>) -> AppInfoManager<T>
+where
+    T: ApplicationAction + Send + Sync + 'static,
+{
+    let mut ism = VimMachine::<TerminalKey, AppInfo<T>>::empty();
+    for (key, action) in bindings {
+        ism.add_mapping(
+            VimMode::Normal,
+            &key,
+            &VimStep::<AppInfo<T>>::new().actions(vec![action]),
+        );
+    }
+    KeyManager::new(ism)
+}
+
+fn vim_command_manager<T>(command_key: Option<&str>) -> AppInfoManager<T>
+where
+    T: ApplicationAction + Send + Sync + 'static,
+{
+    let mut ism = VimMachine::<TerminalKey, AppInfo<T>>::empty();
+    if let Some(command_key) = command_key {
+        ism.add_mapping(
+            VimMode::Normal,
+            &parse(command_key),
+            &VimStep::<AppInfo<T>>::new()
+                .actions(vec![focus_command_bar(":")])
+                .goto(VimMode::Command),
+        );
+    }
+    ism.add_mapping(
+        VimMode::Command,
+        &parse(keys::ESC),
+        &VimStep::<AppInfo<T>>::new()
+            .actions(vec![Action::CommandBar(CommandBarAction::Unfocus)])
+            .goto(VimMode::Normal),
+    );
+    for (key, actions, exit) in command_bar_mappings() {
+        let mut step = VimStep::<AppInfo<T>>::new().actions(actions);
+        if exit {
+            step = step.goto(VimMode::Normal);
+        }
+        ism.add_mapping(VimMode::Command, &key, &step);
+    }
+    KeyManager::new(ism)
+}
+
+fn focus_command_bar<T>(prompt: &str) -> Action<AppInfo<T>>
+where
+    T: ApplicationAction,
+{
+    Action::CommandBar(CommandBarAction::Focus(
+        prompt.into(),
+        CommandType::Command,
+        Box::new(Action::Command(CommandAction::Execute(Count::Exact(1)))),
+    ))
+}
+
+// Bindings for editing the command bar that are shared between all backends. The flag indicates
+// whether the binding leaves the command bar.
+fn command_bar_mappings<T>() -> Vec<(KeyPath, Vec<Action<AppInfo<T>>>, bool)>
+where
+    T: ApplicationAction,
+{
+    let move_column = |dir| EditTarget::Motion(MoveType::Column(dir, true), Count::Contextual);
+    vec![
+        (
+            parse(keys::ENTER),
+            vec![Action::Prompt(PromptAction::Submit)],
+            true,
+        ),
+        (
+            parse(keys::UP),
+            vec![Action::Prompt(PromptAction::Recall(
+                RecallFilter::All,
+                MoveDir1D::Previous,
+                Count::Contextual,
+            ))],
+            false,
+        ),
+        (
+            parse(keys::DOWN),
+            vec![Action::Prompt(PromptAction::Recall(
+                RecallFilter::All,
+                MoveDir1D::Next,
+                Count::Contextual,
+            ))],
+            false,
+        ),
+        (
+            parse(keys::LEFT),
+            vec![Action::Editor(EditorAction::Edit(
+                Specifier::Contextual,
+                move_column(MoveDir1D::Previous),
+            ))],
+            false,
+        ),
+        (
+            parse(keys::RIGHT),
+            vec![Action::Editor(EditorAction::Edit(
+                Specifier::Contextual,
+                move_column(MoveDir1D::Next),
+            ))],
+            false,
+        ),
+        (
+            parse(keys::BACKSPACE),
+            vec![Action::Editor(EditorAction::Edit(
+                Specifier::Exact(EditAction::Delete),
+                move_column(MoveDir1D::Previous),
+            ))],
+            false,
+        ),
+        (
+            parse(keys::TAB),
+            vec![Action::Editor(EditorAction::Complete(
+                CompletionStyle::List(MoveDir1D::Next),
+                CompletionType::Auto,
+                CompletionDisplay::None,
+            ))],
+            false,
+        ),
+        (
+            parse(keys::combine([keys::SHIFT, keys::TAB])),
+            vec![Action::Editor(EditorAction::Complete(
+                CompletionStyle::List(MoveDir1D::Previous),
+                CompletionType::Auto,
+                CompletionDisplay::None,
+            ))],
+            false,
+        ),
+    ]
+}
@@ -10,22 +10,23 @@ use rooibos_dom::events::{
     BlurEvent, ClickHandler, DragHandler, EventData, EventHandle, FocusEvent, IntoClickHandler,
     IntoDragHandler, IntoKeyHandler, KeyHandler, StateChangeEvent,
 };
-use rooibos_dom::{AsDomNode, BuildNodeRenderer, NodeId};
+use rooibos_dom::{AsDomNode, BuildNodeRenderer, NodeId, ScrollDirection};
 use tachys::prelude::*;
 use wasm_compat::sync::RwLock;
 
 use super::dom_node::DomNode;
 use super::layout::{
-    AlignSelf, AspectRatio, Background, BorderProp, Borders, Class, Clear, Enabled, FlexBasis,
-    FlexGrow, FlexShrink, Focusable, Height, Id, IntoAlignSelfSignal, IntoJustifySelfSignal,
-    JustifySelf, Margin, MarginBottom, MarginLeft, MarginRight, MarginTop, MarginX, MarginY,
-    MaxHeight, MaxWidth, MinHeight, MinWidth, Overflow, OverflowX, OverflowY, Padding,
-    PaddingBottom, PaddingLeft, PaddingRight, PaddingTop, PaddingX, PaddingY, Position, Property,
-    UpdateLayout, Width, ZIndex, align_self, aspect_ratio, background, borders, class, clear,
-    enabled, flex_basis, flex_grow, flex_shrink, focusable, height, id, justify_self, margin,
-    margin_bottom, margin_left, margin_right, margin_top, margin_x, margin_y, max_height,
-    max_width, min_height, min_width, overflow, overflow_x, overflow_y, padding, padding_bottom,
-    padding_left, padding_right, padding_top, padding_x, padding_y, position, width, z_index,
+    AlignSelf, AspectRatio, Background, BorderProp, Borders, Checked, Class, Clear, Enabled,
+    FlexBasis, FlexGrow, FlexShrink, Focusable, Height, Id, IntoAlignSelfSignal,
+    IntoJustifySelfSignal, JustifySelf, LabelFor, Margin, MarginBottom, MarginLeft, MarginRight,
+    MarginTop, MarginX, MarginY, MaxHeight, MaxWidth, MinHeight, MinWidth, Overflow, OverflowX,
+    OverflowY, Padding, PaddingBottom, PaddingLeft, PaddingRight, PaddingTop, PaddingX, PaddingY,
+    Position, Property, UpdateLayout, Width, ZIndex, align_self, aspect_ratio, background, borders,
+    checked, class, clear, enabled, flex_basis, flex_grow, flex_shrink, focusable, height, id,
+    justify_self, label_for, margin, margin_bottom, margin_left, margin_right, margin_top,
+    margin_x, margin_y, max_height, max_width, min_height, min_width, overflow, overflow_x,
+    overflow_y, padding, padding_bottom, padding_left, padding_right, padding_top, padding_x,
+    padding_y, position, width, z_index,
 };
 #[cfg(feature = "effects")]
 use super::layout::{Effect, effect};
@@ -341,6 +342,18 @@ where
         self
     }
 
+    pub fn on_scroll<F>(mut self, mut handler: F) -> Self
+    where
+        F: FnMut(ScrollDirection, EventData, EventHandle) + 'static,
+    {
+        self.inner.0 = self.inner.0.on_scroll(move |direction, data, handle| {
+            #[cfg(debug_assertions)]
+            let _guard = reactive_graph::diagnostics::SpecialNonReactiveZone::enter();
+            handler(direction, data, handle);
+        });
+        self
+    }
+
     pub fn layout_props(
         self,
         layout_props: LayoutProps,
@@ -455,8 +468,10 @@ pub struct LayoutProps {
     pub clear: Clear,
     pub enabled: Enabled,
     pub id: Id,
+    pub label_for: LabelFor,
     pub class: Class,
     pub z_index: ZIndex,
+    pub checked: Checked,
     #[cfg(feature = "effects")]
     pub effect: Effect,
 }
@@ -469,8 +484,10 @@ pub struct LayoutPropsState {
     clear: <Clear as Property>::State,
     enabled: <Enabled as Property>::State,
     id: <Id as Property>::State,
+    label_for: <LabelFor as Property>::State,
     class: <Class as Property>::State,
     z_index: <ZIndex as Property>::State,
+    checked: <Checked as Property>::State,
     #[cfg(feature = "effects")]
     effect: <Effect as Property>::State,
 }
@@ -492,7 +509,8 @@ impl Property for LayoutProps {
 
     fn build(self, node: &DomNode) -> Self::State {
         build_props!(
-            self, node, borders, background, focusable, simple, clear, enabled, id, class, z_index
+            self, node, borders, background, focusable, simple, clear, enabled, id, label_for,
+            class, z_index, checked
         );
         #[cfg(feature = "effects")]
         build_props!(self, node, effect);
@@ -505,8 +523,10 @@ impl Property for LayoutProps {
             clear,
             enabled,
             id,
+            label_for,
             class,
             z_index,
+            checked,
             #[cfg(feature = "effects")]
             effect,
         }
@@ -514,8 +534,8 @@ impl Property for LayoutProps {
 
     fn rebuild(self, node: &DomNode, state: &mut Self::State) {
         rebuild_props!(
-            self, node, state, borders, background, focusable, simple, clear, enabled, id, class,
-            z_index
+            self, node, state, borders, background, focusable, simple, clear, enabled, id,
+            label_for, class, z_index, checked
         );
         #[cfg(feature = "effects")]
         rebuild_props!(self, node, state, effect);
@@ -724,6 +744,14 @@ where
         let props = self.layout_props().id(val);
         self.update_props(props)
     }
+
+    fn label_for<S>(self, val: S) -> Self
+    where
+        S: Into<NodeId>,
+    {
+        let props = self.layout_props().label_for(val);
+        self.update_props(props)
+    }
 }
 
 impl WidgetProperty for Id {}
@@ -757,6 +785,37 @@ impl LayoutProps {
         self.id = id(val);
         self
     }
+
+    pub fn label_for<S>(mut self, val: S) -> Self
+    where
+        S: Into<NodeId>,
+    {
+        self.label_for = label_for(val);
+        self
+    }
+}
+
+impl WidgetProperty for LabelFor {}
+
+impl<P> DomWidget<P>
+where
+    P: NextTuple,
+{
+    pub fn label_for<S>(self, val: S) -> DomWidget<P::Output<LabelFor>>
+    where
+        S: Into<NodeId>,
+    {
+        DomWidget {
+            inner: self.inner,
+            properties: self.properties.next_tuple(label_for(val)),
+        }
+    }
+}
+
+impl UpdateLayoutPropsBorrowed for LabelFor {
+    fn update_props(self, props: &mut LayoutProps) {
+        props.label_for = self;
+    }
 }
 
 #[macro_export]
@@ -901,6 +960,7 @@ widget_prop!(Clear, clear, bool, clear);
 widget_prop!(Enabled, enabled, bool, enabled);
 widget_prop!(Class, class, Vec<String>, class);
 widget_prop!(ZIndex, z_index, i32, z_index);
+widget_prop!(Checked, checked, bool, checked);
 #[cfg(feature = "effects")]
 widget_prop!(Effect, effect, super::layout::SyncEffect, effect);
 
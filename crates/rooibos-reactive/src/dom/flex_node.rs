@@ -19,16 +19,17 @@ use super::layout::{
     JustifyContent, JustifyItems, Margin, MarginBottom, MarginLeft, MarginRight, MarginTop,
     MarginX, MarginY, MaxHeight, MaxWidth, MinHeight, MinWidth, Overflow, OverflowX, OverflowY,
     Padding, PaddingBottom, PaddingLeft, PaddingRight, PaddingTop, PaddingX, PaddingY, Position,
-    Property, RowGap, Show, Width, ZIndex, align_content, align_items, align_self, aspect_ratio,
-    background, borders, class, clear, column_gap, flex_basis, flex_grow, flex_shrink, flex_wrap,
-    focusable, gap, height, id, justify_content, justify_items, margin, margin_bottom, margin_left,
-    margin_right, margin_top, margin_x, margin_y, max_height, max_width, min_height, min_width,
-    overflow, overflow_x, overflow_y, padding, padding_bottom, padding_left, padding_right,
-    padding_top, padding_x, padding_y, position, row_gap, show, width, z_index,
+    Property, RowGap, Show, StickToBottom, Width, ZIndex, align_content, align_items, align_self,
+    aspect_ratio, background, borders, class, clear, column_gap, flex_basis, flex_grow,
+    flex_shrink, flex_wrap, focusable, gap, height, id, justify_content, justify_items, margin,
+    margin_bottom, margin_left, margin_right, margin_top, margin_x, margin_y, max_height,
+    max_width, min_height, min_width, overflow, overflow_x, overflow_y, padding, padding_bottom,
+    padding_left, padding_right, padding_top, padding_x, padding_y, position, row_gap, show,
+    stick_to_bottom, width, z_index,
 };
 #[cfg(feature = "effects")]
 use super::layout::{Effect, effect};
-use super::{DomNode, RenderAny, RooibosDom};
+use super::{DomNode, LayoutProps, RenderAny, RooibosDom};
 use crate::dom::layout::Focusable;
 
 #[derive(Debug)]
@@ -253,6 +254,8 @@ impl FlexProperty for () {}
 
 impl FlexProperty for Id {}
 
+impl FlexProperty for LayoutProps {}
+
 impl<C, P> FlexNode<C, P>
 where
     P: NextTuple,
@@ -371,6 +374,7 @@ dimension_flex_prop!(FlexBasis, flex_basis);
 flex_prop!(OverflowX, overflow_x, taffy::Overflow);
 flex_prop!(OverflowY, overflow_y, taffy::Overflow);
 flex_prop!(Overflow, overflow, taffy::Overflow);
+flex_prop!(StickToBottom, stick_to_bottom, bool);
 
 pub struct FlexNodeState<C, P>
 where
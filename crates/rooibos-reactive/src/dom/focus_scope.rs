@@ -26,7 +26,7 @@
 // modals
 //
 // focus_scope![
-//   style(contain()),
+//   style(contain(true)),
 //   col![..]
 // ]
 //
@@ -58,7 +58,7 @@ use rooibos_dom::AsDomNode;
 use tachys::prelude::Renderer;
 use tachys::view::{Mountable, Render};
 
-use super::layout::{FocusModeProp, Property};
+use super::layout::{Contain, FocusModeProp, Property};
 use super::{DomNode, RenderAny, RooibosDom};
 
 pub struct FocusScope<C, P> {
@@ -113,6 +113,19 @@ impl FocusScopeProperty for () {}
 
 impl FocusScopeProperty for FocusModeProp {}
 
+impl FocusScopeProperty for Contain {}
+
+macro_rules! impl_focus_scope_property_for_tuples {
+    ($($ty:ident),* $(,)?) => {
+        impl<$($ty,)*> FocusScopeProperty for ($($ty,)*)
+            where $($ty: FocusScopeProperty,)*
+        { }
+    }
+}
+
+impl_focus_scope_property_for_tuples!(A);
+impl_focus_scope_property_for_tuples!(A, B);
+
 pub struct FocusScopeState<C, P>
 where
     C: Render<RooibosDom>,
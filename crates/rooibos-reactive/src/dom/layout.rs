@@ -201,6 +201,27 @@ impl Property for FocusModeProp {
     }
 }
 
+signal_wrapper!(Contain, contain, bool, false);
+
+impl Property for Contain {
+    type State = RenderEffect<()>;
+
+    fn build(self, node: &DomNode) -> Self::State {
+        let key = node.get_key();
+        RenderEffect::new(move |_| {
+            if let Some(contain) = self.0 {
+                let contain = contain.get();
+                with_nodes_mut(|nodes| nodes.set_contain_focus(key, contain));
+            }
+        })
+    }
+
+    fn rebuild(self, node: &DomNode, state: &mut Self::State) {
+        let new = self.build(node);
+        *state = new;
+    }
+}
+
 signal_wrapper!(ZIndex, z_index, i32, 0);
 
 impl Property for ZIndex {
@@ -293,6 +314,50 @@ impl Property for Clear {
     }
 }
 
+signal_wrapper!(Checked, checked, bool, false);
+
+impl Property for Checked {
+    type State = RenderEffect<()>;
+
+    fn build(self, node: &DomNode) -> Self::State {
+        let key = node.get_key();
+        RenderEffect::new(move |_| {
+            if let Some(checked) = self.0 {
+                with_nodes_mut(|nodes| {
+                    nodes.set_checked(key, checked.get());
+                });
+            }
+        })
+    }
+
+    fn rebuild(self, node: &DomNode, state: &mut Self::State) {
+        let new = self.build(node);
+        *state = new;
+    }
+}
+
+signal_wrapper!(StickToBottom, stick_to_bottom, bool, false);
+
+impl Property for StickToBottom {
+    type State = RenderEffect<()>;
+
+    fn build(self, node: &DomNode) -> Self::State {
+        let key = node.get_key();
+        RenderEffect::new(move |_| {
+            if let Some(stick_to_bottom) = self.0 {
+                with_nodes_mut(|nodes| {
+                    nodes.set_stick_to_bottom(key, stick_to_bottom.get());
+                });
+            }
+        })
+    }
+
+    fn rebuild(self, node: &DomNode, state: &mut Self::State) {
+        let new = self.build(node);
+        *state = new;
+    }
+}
+
 signal_wrapper!(Class, class, Vec<String>, Vec::default());
 
 impl Property for Class {
@@ -345,6 +410,44 @@ impl Property for Id {
     fn rebuild(self, _node: &DomNode, _state: &mut Self::State) {}
 }
 
+/// Marks a node as the label for the node with the given id, like the `for` attribute on an
+/// HTML `<label>`.
+#[derive(Default, Clone)]
+pub struct LabelFor(pub(crate) Option<NodeId>);
+
+impl_next_tuple!(LabelFor);
+
+impl LabelFor {
+    pub fn value(&self) -> Option<NodeId> {
+        self.0.clone()
+    }
+}
+
+pub fn label_for(id: impl Into<NodeId>) -> LabelFor {
+    LabelFor(Some(id.into()))
+}
+
+impl Property for LabelFor {
+    type State = ();
+
+    fn build(self, node: &DomNode) -> Self::State {
+        let key = node.get_key();
+        if let Some(label_for) = self.0 {
+            with_nodes_mut(|nodes| {
+                nodes.set_label_for(key, Some(label_for));
+            });
+        }
+    }
+
+    fn rebuild(self, node: &DomNode, _state: &mut Self::State) {
+        // The label may have been removed, so the target is replaced even if there isn't one
+        let key = node.get_key();
+        with_nodes_mut(|nodes| {
+            nodes.set_label_for(key, self.0);
+        });
+    }
+}
+
 impl<T> Property for T
 where
     T: UpdateLayout + 'static,
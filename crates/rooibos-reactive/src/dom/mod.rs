@@ -9,11 +9,13 @@ pub use into_view::*;
 use ratatui::backend::WindowSize;
 pub use renderer::*;
 pub use rooibos_dom::{
-    DomNodeRepr, MeasureNode, RenderNode, clear_focus, dom_update_receiver, events, focus_id,
-    focus_next, focus_prev, line, render_terminal, root, set_pixel_size,
-    set_supports_keyboard_enhancement, span, text, try_focus_id, widgets,
+    DomNodeRepr, MeasureNode, RenderNode, clear_focus, dom_update_receiver, events,
+    focus_first_child, focus_id, focus_next, focus_prev, line, render_terminal, root, scroll_by,
+    scroll_to, set_pixel_size, set_supports_keyboard_enhancement, span, text,
+    try_focus_first_child, try_focus_id, try_scroll_by, try_scroll_to, widgets,
 };
 use rooibos_dom::{render_dom, with_nodes, with_nodes_mut};
+pub use scroll::*;
 
 mod children;
 pub mod div;
@@ -25,6 +27,7 @@ pub mod focus_scope;
 mod into_view;
 pub mod layout;
 mod renderer;
+mod scroll;
 
 pub fn mount<F, M>(f: F, window_size: Option<WindowSize>)
 where
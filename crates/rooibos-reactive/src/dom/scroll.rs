@@ -0,0 +1,69 @@
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+use reactive_graph::owner::on_cleanup;
+use reactive_graph::signal::ArcRwSignal;
+use reactive_graph::traits::Set;
+use reactive_graph::wrappers::read::Signal;
+pub use rooibos_dom::ScrollPosition;
+use rooibos_dom::with_nodes_mut;
+
+use crate::dom::NodeId;
+
+struct ScrollSubscription {
+    position: ArcRwSignal<ScrollPosition>,
+    subscribers: usize,
+}
+
+thread_local! {
+    // Each node gets its own signal so a scroll only notifies the subscribers of that node.
+    static SCROLL_POSITIONS: RefCell<HashMap<rooibos_dom::NodeId, ScrollSubscription>> =
+        RefCell::new(HashMap::new());
+    static SCROLL_LISTENER_REGISTERED: Cell<bool> = const { Cell::new(false) };
+}
+
+fn register_scroll_listener() {
+    if SCROLL_LISTENER_REGISTERED.replace(true) {
+        return;
+    }
+    with_nodes_mut(|nodes| {
+        nodes.on_scroll_change(|id, position| {
+            let signal = SCROLL_POSITIONS
+                .with_borrow(|positions| positions.get(&id).map(|s| s.position.clone()));
+            if let Some(signal) = signal {
+                signal.set(position);
+            }
+        })
+    });
+}
+
+/// Returns the scroll position of the node with the given id. The node should have
+/// `overflow: scroll` set, otherwise the position will always be zero.
+pub fn use_scroll(id: impl Into<NodeId>) -> Signal<ScrollPosition> {
+    register_scroll_listener();
+    let id: rooibos_dom::NodeId = id.into().into();
+    let initial = rooibos_dom::scroll_position(id.clone()).unwrap_or_default();
+    let position = SCROLL_POSITIONS.with_borrow_mut(|positions| {
+        let subscription = positions
+            .entry(id.clone())
+            .or_insert_with(|| ScrollSubscription {
+                position: ArcRwSignal::new(initial),
+                subscribers: 0,
+            });
+        subscription.subscribers += 1;
+        subscription.position.clone()
+    });
+
+    on_cleanup(move || {
+        SCROLL_POSITIONS.with_borrow_mut(|positions| {
+            if let Some(subscription) = positions.get_mut(&id) {
+                subscription.subscribers -= 1;
+                if subscription.subscribers == 0 {
+                    positions.remove(&id);
+                }
+            }
+        });
+    });
+
+    position.into()
+}
@@ -279,7 +279,7 @@ where
     F: Future<Output = ()> + 'static,
 {
     spawn_local(async move {
-        wasm_compat::futures::sleep(duration).await;
+        rooibos_dom::clock::sleep(duration).await;
         f.await;
     });
 }
@@ -8,9 +8,17 @@ use syn::{
 
 use crate::get_components_import;
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FieldKind {
+    Param,
+    Optional,
+    // Each item is added to the query using the same key
+    Repeated,
+}
+
 struct StructField {
     ident: Ident,
-    optional: bool,
+    kind: FieldKind,
     components: TokenStream,
 }
 
@@ -19,7 +27,7 @@ impl StructField {
         let name_str = self.ident.to_string().to_ascii_lowercase();
         let name_upper = Ident::new(&name_str.to_ascii_uppercase(), Span::call_site());
         let components = &self.components;
-        if self.optional {
+        if self.kind != FieldKind::Param {
             quote!(const #name_upper: #components::Query = #components::Query(::std::borrow::Cow::Borrowed(#name_str));)
         } else {
             quote!(const #name_upper: #components::Param = #components::Param(::std::borrow::Cow::Borrowed(#name_str));)
@@ -29,31 +37,38 @@ impl StructField {
     fn add_to_route(&self) -> TokenStream {
         let ident = &self.ident;
         let ident_assign = format!("{ident}=");
-        if self.optional {
-            quote! {
-                if let Some(#ident) = &self.#ident {
-                    if base_added {
-                        url += "&";
-                    } else {
-                        url += "?";
-                        base_added = true;
-                    }
-                    url += #ident_assign;
-                    url += &#ident.to_string();
-                }
-
+        let components = &self.components;
+        let add_query = quote! {
+            if base_added {
+                url += "&";
+            } else {
+                url += "?";
+                base_added = true;
             }
-        } else {
-            quote! {
+            url += #ident_assign;
+            url += &#components::__private::encode_query_value(&#ident.to_string());
+        };
+        match self.kind {
+            FieldKind::Param => quote! {
                 url += "/";
-                url += &self.#ident.to_string();
-            }
+                url += &#components::__private::encode_path_segment(&self.#ident.to_string());
+            },
+            FieldKind::Optional => quote! {
+                if let Some(#ident) = &self.#ident {
+                    #add_query
+                }
+            },
+            FieldKind::Repeated => quote! {
+                for #ident in &self.#ident {
+                    #add_query
+                }
+            },
         }
     }
 
     fn add_to_route_template(&self) -> String {
         let ident = &self.ident;
-        if self.optional {
+        if self.kind != FieldKind::Param {
             "".to_string()
         } else {
             format!("{{{ident}}}")
@@ -150,18 +165,23 @@ fn get_struct_fields(
                     acc
                 });
 
-                let optional = vec!["Option:", "std:option:Option:", "core:option:Option:"]
-                    .into_iter()
-                    .any(|s| idents_of_path == s);
+                let is_type = |names: [&str; 3]| names.into_iter().any(|s| idents_of_path == s);
+                let kind = if is_type(["Option:", "std:option:Option:", "core:option:Option:"]) {
+                    FieldKind::Optional
+                } else if is_type(["Vec:", "std:vec:Vec:", "alloc:vec:Vec:"]) {
+                    FieldKind::Repeated
+                } else {
+                    FieldKind::Param
+                };
                 Some(StructField {
                     ident: ident.clone(),
-                    optional,
+                    kind,
                     components: components.clone(),
                 })
             } else {
                 Some(StructField {
                     ident: ident.clone(),
-                    optional: false,
+                    kind: FieldKind::Param,
                     components: components.clone(),
                 })
             }
@@ -177,7 +197,7 @@ fn generate_enum(
     emitter: &mut Emitter,
 ) -> manyhow::Result {
     let fields = get_enum_fields(enum_input, emitter);
-    let routes = fields.iter().map(|f| f.field_to_route());
+    let routes = fields.iter().map(|f| f.field_to_route(&components));
     let route_templates = fields.iter().map(|f| f.field_to_route_template());
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     Ok(quote! {
@@ -201,19 +221,44 @@ fn generate_enum(
 
 struct EnumField {
     ident: Ident,
+    nested: bool,
 }
 
 impl EnumField {
-    fn field_to_route(&self) -> TokenStream {
+    fn base_route(&self) -> String {
+        "/".to_string() + &(self.ident.to_string().to_case(Case::Kebab))
+    }
+
+    fn field_to_route(&self, components: &TokenStream) -> TokenStream {
         let variant: Expr = parse_str(&format!("Self::{}", self.ident)).unwrap();
-        let route = "/".to_string() + &(self.ident.to_string().to_case(Case::Kebab));
-        quote!(#variant => #route.to_string())
+        let route = self.base_route();
+        if self.nested {
+            // The nested route's index is at "/", which shouldn't add a trailing slash
+            quote! {
+                #variant(inner) => {
+                    let inner = #components::ToRoute::to_route(inner);
+                    if inner == "/" {
+                        #route.to_string()
+                    } else {
+                        format!("{}{}", #route, inner)
+                    }
+                }
+            }
+        } else {
+            quote!(#variant => #route.to_string())
+        }
     }
 
     fn field_to_route_template(&self) -> TokenStream {
         let variant: Expr = parse_str(&format!("Self::{}", self.ident)).unwrap();
-        let route = "/".to_string() + &(self.ident.to_string().to_case(Case::Kebab));
-        quote!(#variant => #route)
+        let route = self.base_route();
+        if self.nested {
+            // Nested routes are defined as children of this one, so only the parent's portion of
+            // the path is included
+            quote!(#variant(_) => #route)
+        } else {
+            quote!(#variant => #route)
+        }
     }
 }
 
@@ -221,17 +266,23 @@ fn get_enum_fields(enum_input: DataEnum, emitter: &mut Emitter) -> Vec<EnumField
     enum_input
         .variants
         .iter()
-        .filter_map(|v| {
-            if v.fields != Fields::Unit {
+        .filter_map(|v| match &v.fields {
+            Fields::Unit => Some(EnumField {
+                ident: v.ident.clone(),
+                nested: false,
+            }),
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => Some(EnumField {
+                ident: v.ident.clone(),
+                nested: true,
+            }),
+            _ => {
                 emitter.emit(ErrorMessage::spanned(
                     &v.ident,
-                    "Route cannot be derived on enum variants with fields",
+                    "Route can only be derived on unit variants or variants containing a single \
+                     nested route",
                 ));
-                return None;
+                None
             }
-            Some(EnumField {
-                ident: v.ident.clone(),
-            })
         })
         .collect()
 }
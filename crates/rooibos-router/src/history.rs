@@ -0,0 +1,102 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
+
+use fs_err as fs;
+use url::Url;
+
+const DEFAULT_DEPTH: usize = 50;
+const FILE_NAME: &str = "router_history";
+
+/// Saves the router's history to a file so it can be restored the next time the app starts.
+///
+/// Set with [`Router::persist_history`](crate::Router::persist_history).
+#[derive(Clone, Debug)]
+pub struct HistoryPersistence {
+    path: Option<PathBuf>,
+    depth: usize,
+}
+
+impl HistoryPersistence {
+    /// Stores the history in the platform's state directory (or data directory if the platform
+    /// doesn't have one) under a folder named after the app.
+    pub fn new(app_name: impl AsRef<str>) -> Self {
+        let path = dirs::state_dir()
+            .or_else(dirs::data_local_dir)
+            .map(|dir| dir.join(app_name.as_ref()).join(FILE_NAME));
+        Self {
+            path,
+            depth: DEFAULT_DEPTH,
+        }
+    }
+
+    /// Stores the history in a specific file.
+    pub fn file(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: Some(path.into()),
+            depth: DEFAULT_DEPTH,
+        }
+    }
+
+    /// Maximum number of entries that are saved for both the back and forward history. Older
+    /// entries are dropped first. Defaults to 50.
+    pub fn depth(mut self, depth: usize) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    pub(crate) fn load(&self) -> Option<(Vec<Url>, Vec<Url>)> {
+        let contents = fs::read_to_string(self.path.as_ref()?).ok()?;
+        // The back history and forward buffer are separated by a blank line
+        let (history, buffer) = contents.split_once("\n\n").unwrap_or((&contents, ""));
+        let parse = |lines: &str| {
+            lines
+                .lines()
+                .filter_map(|line| Url::parse(line).ok())
+                .collect::<Vec<_>>()
+        };
+        Some((parse(history), parse(buffer)))
+    }
+
+    pub(crate) fn save(&self, history: &[Url], buffer: &[Url]) -> io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let join = |urls: &[Url]| {
+            urls[urls.len().saturating_sub(self.depth)..]
+                .iter()
+                .map(|url| url.as_str())
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        fs::write(path, format!("{}\n\n{}\n", join(history), join(buffer)))
+    }
+
+    // Saves the history on a background thread so navigation isn't blocked on file IO. If the
+    // history changes again while a save is in progress, only the latest version is written.
+    pub(crate) fn writer(self) -> mpsc::Sender<(Vec<Url>, Vec<Url>)> {
+        let (tx, rx) = mpsc::channel::<(Vec<Url>, Vec<Url>)>();
+        thread::spawn(move || {
+            while let Ok(mut entry) = rx.recv() {
+                while let Ok(next) = rx.try_recv() {
+                    entry = next;
+                }
+                // Persisting history is best-effort, failing to save shouldn't affect the app
+                let _ = self.save(&entry.0, &entry.1);
+            }
+        });
+        tx
+    }
+}
+
+#[cfg(test)]
+#[path = "./history_test.rs"]
+mod history_test;
@@ -0,0 +1,57 @@
+use std::path::PathBuf;
+
+use url::Url;
+
+use super::HistoryPersistence;
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir()
+        .join(format!("rooibos-router-{}", std::process::id()))
+        .join(name)
+}
+
+fn urls(paths: &[&str]) -> Vec<Url> {
+    paths
+        .iter()
+        .map(|path| Url::parse(&format!("app://{path}")).unwrap())
+        .collect()
+}
+
+#[test]
+fn save_and_load() {
+    let persistence = HistoryPersistence::file(temp_path("save_and_load"));
+    let history = urls(&["/home", "/post/1?tab=comments"]);
+    let buffer = urls(&["/post/3", "/post/2"]);
+    persistence.save(&history, &buffer).unwrap();
+
+    assert_eq!(persistence.load(), Some((history, buffer)));
+}
+
+#[test]
+fn save_empty_buffer() {
+    let persistence = HistoryPersistence::file(temp_path("save_empty_buffer"));
+    let history = urls(&["/home"]);
+    persistence.save(&history, &[]).unwrap();
+
+    assert_eq!(persistence.load(), Some((history, Vec::new())));
+}
+
+#[test]
+fn depth_keeps_latest_entries() {
+    let persistence = HistoryPersistence::file(temp_path("depth_keeps_latest_entries")).depth(2);
+    let history = urls(&["/home", "/post/1", "/post/2"]);
+    // The entry that's navigated to next is at the end of the buffer
+    let buffer = urls(&["/post/5", "/post/4", "/post/3"]);
+    persistence.save(&history, &buffer).unwrap();
+
+    assert_eq!(
+        persistence.load(),
+        Some((urls(&["/post/1", "/post/2"]), urls(&["/post/4", "/post/3"])))
+    );
+}
+
+#[test]
+fn load_missing_file() {
+    let persistence = HistoryPersistence::file(temp_path("load_missing_file"));
+    assert_eq!(persistence.load(), None);
+}
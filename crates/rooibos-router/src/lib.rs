@@ -1,14 +1,28 @@
 use std::borrow::Cow;
-
-use reactive_graph::computed::Memo;
-use reactive_graph::owner::{Owner, StoredValue, provide_context, use_context};
-use reactive_graph::signal::{WriteSignal, signal};
-use reactive_graph::traits::{Get, GetValue, Update, UpdateValue, With};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+pub use params::ParamsError;
+use percent_encoding::{AsciiSet, CONTROLS, percent_decode_str};
+use reactive_graph::computed::{AsyncDerived, Memo};
+#[cfg(feature = "persist-history")]
+use reactive_graph::effect::Effect;
+use reactive_graph::owner::{Owner, StoredValue, on_cleanup, provide_context, use_context};
+use reactive_graph::signal::{ReadSignal, WriteSignal, signal};
+use reactive_graph::traits::{
+    Get, GetUntracked, GetValue, SetValue, Update, UpdateValue, With, WithUntracked, WithValue,
+};
 use reactive_graph::wrappers::read::Signal;
 use rooibos_reactive::IntoSignal;
 use rooibos_reactive::dom::{ChildrenFnMut, IntoChildrenFnMut};
 pub use rooibos_router_macros::*;
-use url::Url;
+use serde::de::DeserializeOwned;
+pub use url::Url;
+#[cfg(feature = "persist-history")]
+mod history;
+#[cfg(feature = "persist-history")]
+pub use history::HistoryPersistence;
+mod params;
 
 pub trait ToRoute {
     fn to_route(&self) -> String;
@@ -20,6 +34,18 @@ impl ToRoute for Box<dyn ToRoute> {
     }
 }
 
+impl ToRoute for String {
+    fn to_route(&self) -> String {
+        self.clone()
+    }
+}
+
+impl ToRoute for &str {
+    fn to_route(&self) -> String {
+        self.to_string()
+    }
+}
+
 pub trait ToRouteTemplateStatic {
     fn to_route_template() -> &'static str;
 }
@@ -34,7 +60,70 @@ pub struct Query(pub Cow<'static, str>);
 
 pub struct Route {
     path: String,
-    children: ChildrenFnMut,
+    view: ChildrenFnMut,
+    children: Vec<Route>,
+    loader: Option<LoaderFn>,
+}
+
+impl Route {
+    /// Adds routes that are nested under this one. Their paths are relative to this route's path
+    /// and they're rendered wherever this route's view places an [`Outlet`].
+    ///
+    /// A child with the path `/`, such as [`DefaultRoute`], is rendered when the parent's path
+    /// is matched exactly.
+    pub fn children(mut self, children: impl Into<Vec<Route>>) -> Self {
+        self.children = children.into();
+        self
+    }
+
+    /// Loads data for the route whenever it's navigated to or the URL changes while it's active.
+    ///
+    /// The result can be read from the route's view and any nested routes with [`use_loader`].
+    /// It's an [`AsyncDerived`], so it can be awaited inside `suspense!` or `transition!`.
+    pub fn loader<T, F, Fut>(mut self, loader: F) -> Self
+    where
+        T: Send + Sync + 'static,
+        F: Fn(Url) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = T> + Send + 'static,
+    {
+        let loader = Arc::new(loader);
+        self.loader = Some(Arc::new(move || {
+            let route_ctx = use_route_context().unwrap();
+            let loader = loader.clone();
+            let data = AsyncDerived::new(move || loader(route_ctx.current_route.get()));
+            provide_context(RouteLoader(data));
+        }));
+        self
+    }
+}
+
+type LoaderFn = Arc<dyn Fn() + Send + Sync>;
+
+struct RouteLoader<T>(AsyncDerived<T>)
+where
+    T: 'static;
+
+// Derive would add an unnecessary T: Clone bound
+impl<T> Clone for RouteLoader<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for RouteLoader<T> {}
+
+/// Data returned by the [`loader`](Route::loader) of the current route or one of its parents.
+///
+/// # Panics
+///
+/// Panics if none of the active routes have a loader that returns `T`.
+pub fn use_loader<T>() -> AsyncDerived<T>
+where
+    T: Send + Sync + 'static,
+{
+    use_context::<RouteLoader<T>>()
+        .expect("no route loader returns this type")
+        .0
 }
 
 pub trait RouteFromStatic {
@@ -49,7 +138,9 @@ impl RouteFromStatic for Route {
     fn new<T: ToRouteTemplateStatic>(children: impl IntoChildrenFnMut) -> Self {
         Self {
             path: T::to_route_template().to_string(),
-            children: children.into_children_fn_mut(),
+            view: children.into_children_fn_mut(),
+            children: Vec::new(),
+            loader: None,
         }
     }
 }
@@ -58,7 +149,9 @@ impl RouteFrom for Route {
     fn new(route: impl ToRouteTemplate, children: impl IntoChildrenFnMut) -> Self {
         Self {
             path: route.to_route_template().to_string(),
-            children: children.into_children_fn_mut(),
+            view: children.into_children_fn_mut(),
+            children: Vec::new(),
+            loader: None,
         }
     }
 }
@@ -77,11 +170,43 @@ impl ToRouteTemplateStatic for DefaultRoute {
     }
 }
 
+/// Navigation that's about to happen, passed to guards registered with
+/// [`RouteContext::on_before_leave`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Navigation {
+    pub from: Url,
+    pub to: Url,
+    pub kind: NavigationKind,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NavigationKind {
+    Push,
+    Back,
+    Forward,
+}
+
+type Guard = Arc<dyn Fn(&Navigation) -> bool + Send + Sync>;
+
+// Index of the route whose view is being rendered, used to scope guards to their route
+#[derive(Clone, Copy)]
+struct RouteScope(usize);
+
+static GUARD_ID: AtomicUsize = AtomicUsize::new(0);
+
+// Guards against redirect loops
+const MAX_REDIRECTS: usize = 16;
+
 #[derive(Clone, Copy)]
 pub struct RouteContext {
+    history: ReadSignal<Vec<Url>>,
     set_history: WriteSignal<Vec<Url>>,
     current_route: Memo<Url>,
     router: StoredValue<matchit::Router<usize>>,
+    chains: StoredValue<Vec<Vec<usize>>>,
+    redirects: StoredValue<matchit::Router<String>>,
+    guards: StoredValue<Vec<(usize, Option<usize>, Guard)>>,
+    buffer: ReadSignal<Vec<Url>>,
     set_buffer: WriteSignal<Vec<Url>>,
     can_go_forward: Signal<bool>,
     can_go_back: Signal<bool>,
@@ -93,11 +218,85 @@ impl RouteContext {
     }
 
     fn push_str(&self, route: &str) {
-        let url = Url::options()
-            .base_url(Some(&"app://".parse().unwrap()))
-            .parse(route)
-            .unwrap();
-        self.push_url(url, true);
+        let url = self.redirect(parse_url(route));
+        let Some(from) = self.history.with_untracked(|h| h.last().cloned()) else {
+            // Nothing to leave yet
+            self.push_url(url, true);
+            return;
+        };
+        self.navigate(Navigation {
+            from,
+            to: url,
+            kind: NavigationKind::Push,
+        });
+    }
+
+    fn redirect(&self, mut url: Url) -> Url {
+        for _ in 0..MAX_REDIRECTS {
+            let target = self.redirects.with_value(|r| {
+                let matched = r.at(url.path()).ok()?;
+                let mut target = matched.value.clone();
+                // Params from the original route can be used in the target
+                for (key, value) in matched.params.iter() {
+                    target = target.replace(&format!("{{{key}}}"), value);
+                }
+                Some(target)
+            });
+            match target {
+                Some(target) => url = parse_url(&target),
+                None => break,
+            }
+        }
+        url
+    }
+
+    fn navigate(&self, navigation: Navigation) {
+        let from = self.route_chain(&navigation.from);
+        let to = self.route_chain(&navigation.to);
+        // Navigating between children of a route doesn't leave it
+        let guards: Vec<_> = self.guards.with_value(|g| {
+            g.iter()
+                .filter(|(_, route, _)| {
+                    route.is_none_or(|route| from.contains(&route) && !to.contains(&route))
+                })
+                .map(|(_, _, guard)| guard.clone())
+                .collect()
+        });
+        if guards.iter().all(|guard| guard(&navigation)) {
+            self.proceed(navigation);
+        }
+    }
+
+    /// Completes a navigation without running any guards. This can be used to continue a
+    /// navigation that was vetoed by a guard, e.g. once the user confirms they want to discard
+    /// their changes.
+    pub fn proceed(&self, navigation: Navigation) {
+        match navigation.kind {
+            NavigationKind::Push => self.push_url(navigation.to, true),
+            NavigationKind::Back => self.back_inner(),
+            NavigationKind::Forward => self.forward_inner(),
+        }
+    }
+
+    /// Registers a guard that runs before navigating away from the current route. Returning
+    /// `false` cancels the navigation.
+    ///
+    /// The guard only runs when the navigation leaves the route it was registered in, so moving
+    /// between that route's children doesn't trigger it. Guards registered outside of a route
+    /// run for every navigation. The guard is removed when the route it was registered in is no
+    /// longer rendered.
+    pub fn on_before_leave<F>(&self, guard: F)
+    where
+        F: Fn(&Navigation) -> bool + Send + Sync + 'static,
+    {
+        let id = GUARD_ID.fetch_add(1, Ordering::Relaxed);
+        let route = use_context::<RouteScope>().map(|scope| scope.0);
+        self.guards
+            .update_value(|g| g.push((id, route, Arc::new(guard))));
+        let guards = self.guards;
+        on_cleanup(move || {
+            guards.update_value(|g| g.retain(|(guard_id, _, _)| *guard_id != id));
+        });
     }
 
     fn push_url(&self, url: Url, clear_buffer: bool) {
@@ -115,16 +314,82 @@ impl RouteContext {
         });
     }
 
+    // Starts the history over at a new route. Redirects are applied, but there's nothing to
+    // leave yet, so guards aren't run.
     fn replace_root(&self, new_root: &str) {
+        let url = self.redirect(parse_url(new_root));
         self.set_history.update(|h| {
             h.clear();
+            h.push(url);
+        });
+        self.set_buffer.update(|b| b.clear());
+    }
+
+    fn matches(&self, url: &Url) -> bool {
+        self.router.with_value(|r| r.at(url.path()).is_ok())
+    }
+
+    // Routes that are rendered for the url, from the outermost layout down to the matched route
+    fn route_chain(&self, url: &Url) -> Vec<usize> {
+        let Some(index) = self
+            .router
+            .with_value(|r| r.at(url.path()).ok().map(|m| *m.value))
+        else {
+            return Vec::new();
+        };
+        self.chains.with_value(|c| c[index].clone())
+    }
+
+    #[cfg(feature = "persist-history")]
+    fn restore(&self, persistence: &HistoryPersistence) -> bool {
+        let Some((history, buffer)) = persistence.load() else {
+            return false;
+        };
+        // Routes may have been removed or redirected since the history was saved
+        let restore = |urls: Vec<Url>| -> Vec<_> {
+            urls.into_iter()
+                .map(|u| self.redirect(u))
+                .filter(|u| self.matches(u))
+                .collect()
+        };
+        let history = restore(history);
+        if history.is_empty() {
+            return false;
+        }
+        let buffer = restore(buffer);
+        self.set_history.update(|h| *h = history);
+        self.set_buffer.update(|b| *b = buffer);
+        true
+    }
+
+    #[cfg(feature = "persist-history")]
+    fn persist(&self, persistence: HistoryPersistence) {
+        let history = self.history;
+        let buffer = self.buffer;
+        let writer = persistence.writer();
+        Effect::new(move || {
+            let entry = (history.get(), buffer.get());
+            // The writer only stops if the app is shutting down
+            let _ = writer.send(entry);
         });
-        self.push_str(new_root);
     }
 
     pub fn back(&self) {
-        self.set_history.update(|h| {
+        let navigation = self.history.with_untracked(|h| {
             // ensure we always have the initial history entry
+            (h.len() > 1).then(|| Navigation {
+                from: h[h.len() - 1].clone(),
+                to: h[h.len() - 2].clone(),
+                kind: NavigationKind::Back,
+            })
+        });
+        if let Some(navigation) = navigation {
+            self.navigate(navigation);
+        }
+    }
+
+    fn back_inner(&self) {
+        self.set_history.update(|h| {
             if h.len() > 1 {
                 self.set_buffer.update(|b| b.push(h.pop().unwrap()));
             }
@@ -132,6 +397,17 @@ impl RouteContext {
     }
 
     pub fn forward(&self) {
+        let Some(to) = self.buffer.with_untracked(|b| b.last().cloned()) else {
+            return;
+        };
+        self.navigate(Navigation {
+            from: self.current_route.get_untracked(),
+            to,
+            kind: NavigationKind::Forward,
+        });
+    }
+
+    fn forward_inner(&self) {
         self.set_buffer.update(|b| {
             if let Some(entry) = b.pop() {
                 self.push_url(entry, false);
@@ -143,13 +419,27 @@ impl RouteContext {
         self.current_route.get()
     }
 
+    // Path params are matched against the encoded path, so they're decoded before they're
+    // returned
+    fn params(&self) -> Vec<(String, String)> {
+        let route = self.current_route.get();
+        self.router.with_value(|r| {
+            r.at(route.path())
+                .unwrap()
+                .params
+                .iter()
+                .map(|(key, value)| (key.to_string(), decode_path_segment(value)))
+                .collect()
+        })
+    }
+
     pub fn try_use_param(&self, param: Param) -> Signal<Option<String>> {
-        let router = self.router.get_value();
-        let current_route = self.current_route;
+        let context = *self;
         (move || {
-            let route = current_route.get();
-            let params = router.at(route.path()).unwrap().params;
-            params.get(&param.0).map(|s| s.to_owned())
+            context
+                .params()
+                .into_iter()
+                .find_map(|(key, value)| (key == param.0).then_some(value))
         })
         .signal()
     }
@@ -189,6 +479,38 @@ impl RouteContext {
     }
 }
 
+/// Deserializes the current route's path params into `T`.
+///
+/// # Panics
+///
+/// Panics if called outside of a router.
+pub fn use_params<T>() -> Signal<Result<T, ParamsError>>
+where
+    T: DeserializeOwned + Send + Sync + 'static,
+{
+    let context = use_route_context().expect("use_params must be called inside a router");
+    (move || T::deserialize(params::ParamsDeserializer::new(context.params()))).signal()
+}
+
+/// Deserializes the current route's query string into `T`. Keys that are repeated in the query
+/// can be collected into a `Vec`.
+///
+/// # Panics
+///
+/// Panics if called outside of a router.
+pub fn use_query<T>() -> Signal<Result<T, ParamsError>>
+where
+    T: DeserializeOwned + Send + Sync + 'static,
+{
+    let context = use_route_context().expect("use_query must be called inside a router");
+    (move || {
+        context
+            .current_route
+            .with(|route| T::deserialize(params::ParamsDeserializer::new(route.query_pairs())))
+    })
+    .signal()
+}
+
 pub fn use_route_context() -> Option<RouteContext> {
     use_context::<RouteContext>()
 }
@@ -201,13 +523,98 @@ fn use_route_context_internal(initial: &str) -> RouteContext {
     }
 }
 
+/// Reads a route to start the app at from the command line argument following `flag`, e.g.
+/// `--route /post/1` or `--route=/post/1`.
+///
+/// Pass the result to [`Router::deep_link`].
+pub fn deep_link_from_args(flag: &str) -> Option<String> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == flag {
+            return args.next();
+        }
+        if let Some(link) = arg
+            .strip_prefix(flag)
+            .and_then(|rest| rest.strip_prefix('='))
+        {
+            return Some(link.to_string());
+        }
+    }
+    None
+}
+
+/// Reads a route to start the app at from an environment variable.
+///
+/// Pass the result to [`Router::deep_link`].
+pub fn deep_link_from_env(var: &str) -> Option<String> {
+    std::env::var(var).ok().filter(|link| !link.is_empty())
+}
+
+// Links coming from outside the app may use a custom scheme like `myapp://post/1`. Everything
+// after the scheme is treated as the path. A `://` that appears after the start of the path, such
+// as in `/search?q=https://example.com`, isn't a scheme.
+fn normalize_link(link: &str) -> String {
+    match link.split_once("://") {
+        Some((scheme, path)) if !scheme.is_empty() && !scheme.contains(['/', '?', '#']) => {
+            format!("/{}", path.trim_start_matches('/'))
+        }
+        _ => link.to_string(),
+    }
+}
+
+// Characters that have to be escaped in a path segment
+const PATH_SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'/')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
+fn decode_path_segment(value: &str) -> String {
+    percent_decode_str(value).decode_utf8_lossy().into_owned()
+}
+
+// Used by the Route derive
+#[doc(hidden)]
+pub mod __private {
+    pub fn encode_path_segment(value: &str) -> String {
+        percent_encoding::utf8_percent_encode(value, super::PATH_SEGMENT).to_string()
+    }
+
+    pub fn encode_query_value(value: &str) -> String {
+        url::form_urlencoded::byte_serialize(value.as_bytes()).collect()
+    }
+}
+
+fn parse_url(route: &str) -> Url {
+    try_parse_url(route).unwrap()
+}
+
+fn try_parse_url(route: &str) -> Option<Url> {
+    Url::options()
+        .base_url(Some(&"app://".parse().unwrap()))
+        .parse(route)
+        .ok()
+}
+
 fn init_router(initial: &str) -> RouteContext {
     let (history, set_history) = signal(vec![]);
     let (buffer, set_buffer) = signal(vec![]);
     let context = RouteContext {
+        history,
         set_history,
         router: StoredValue::new(matchit::Router::new()),
+        chains: StoredValue::new(Vec::new()),
+        redirects: StoredValue::new(matchit::Router::new()),
+        guards: StoredValue::new(Vec::new()),
         current_route: Memo::new(move |_| history.with(|h| h.last().cloned().unwrap())),
+        buffer,
         set_buffer,
         can_go_forward: (move || buffer.with(|b| !b.is_empty())).signal(),
         can_go_back: (move || history.with(|h| h.len() > 1)).signal(),
@@ -223,17 +630,32 @@ pub fn use_router() -> (Router, RouteContext) {
 
 pub struct Router {
     routes: Vec<Route>,
+    redirects: Vec<(String, String)>,
     initial: String,
+    deep_link: Option<String>,
+    #[cfg(feature = "persist-history")]
+    persistence: Option<HistoryPersistence>,
 }
 
 impl Router {
     fn new() -> Self {
         Self {
             routes: Vec::default(),
+            redirects: Vec::default(),
             initial: DefaultRoute.to_route(),
+            deep_link: None,
+            #[cfg(feature = "persist-history")]
+            persistence: None,
         }
     }
 
+    /// Redirects any navigation that matches the `from` template to another route. Params in
+    /// the template can be referenced in the target using the same `{param}` syntax.
+    pub fn redirect(mut self, from: impl Into<String>, to: impl ToRoute) -> Self {
+        self.redirects.push((from.into(), to.to_route()));
+        self
+    }
+
     pub fn routes(mut self, routes: impl Into<Vec<Route>>) -> Self {
         self.routes = routes.into();
         self
@@ -244,27 +666,200 @@ impl Router {
         self
     }
 
+    /// Starts the app at a route from outside the app, such as one returned by
+    /// [`deep_link_from_args`] or [`deep_link_from_env`]. The initial route is kept as the
+    /// previous history entry so navigating back still works.
+    ///
+    /// Links with a custom scheme like `myapp://post/1` are accepted. The link is ignored and
+    /// the app starts at the initial route if it isn't a valid url or doesn't match any route.
+    pub fn deep_link(mut self, link: Option<impl ToRoute>) -> Self {
+        self.deep_link = link.map(|link| normalize_link(&link.to_route()));
+        self
+    }
+
+    /// Saves the history when it changes and restores it when the app starts. If there's a
+    /// deep link, it's navigated to after the history is restored.
+    #[cfg(feature = "persist-history")]
+    pub fn persist_history(mut self, persistence: HistoryPersistence) -> Self {
+        self.persistence = Some(persistence);
+        self
+    }
+
     pub fn render(self) -> impl rooibos_reactive::dom::RenderAny {
         let route_ctx = use_route_context_internal(&self.initial);
-        route_ctx.replace_root(&self.initial);
+        route_ctx.redirects.update_value(|r| {
+            for (from, to) in self.redirects {
+                r.insert(from, to).unwrap();
+            }
+        });
 
-        let mut routes = self.routes;
+        let mut views = Vec::new();
+        let mut loaders = Vec::new();
+        let mut chains = Vec::new();
+        flatten_routes(self.routes, "", &[], &mut views, &mut loaders, &mut chains);
 
         route_ctx.router.update_value(|r| {
-            for (i, child) in routes.iter().enumerate() {
-                r.insert(&child.path, i).unwrap();
+            for (i, (path, _)) in chains.iter().enumerate() {
+                r.insert(path, i).unwrap();
             }
         });
-        let owner = Owner::current().unwrap();
-        let route_index = Memo::new(move |_| {
+        route_ctx
+            .chains
+            .set_value(chains.into_iter().map(|(_, chain)| chain).collect());
+
+        // Routes need to be registered before the history is set up so restored entries and
+        // deep links can be validated
+        #[cfg(feature = "persist-history")]
+        let restored = self
+            .persistence
+            .as_ref()
+            .is_some_and(|persistence| route_ctx.restore(persistence));
+        #[cfg(not(feature = "persist-history"))]
+        let restored = false;
+        // The redirects have to be registered before this so they apply to the initial route
+        if !restored {
+            route_ctx.replace_root(&self.initial);
+        }
+        if let Some(url) = self
+            .deep_link
+            .and_then(|link| try_parse_url(&link))
+            .map(|url| route_ctx.redirect(url))
+            .filter(|url| route_ctx.matches(url))
+        {
+            route_ctx.push_url(url, true);
+        }
+        #[cfg(feature = "persist-history")]
+        if let Some(persistence) = self.persistence {
+            route_ctx.persist(persistence);
+        }
+
+        let chain = Memo::new(move |_| {
             let current_route = route_ctx.current_route();
             let path = current_route.path();
             let router = route_ctx.router.get_value();
-            *router.at(path).unwrap().value
+            route_ctx
+                .chains
+                .with_value(|c| c[*router.at(path).unwrap().value].clone())
         });
+        let outlet = OutletContext {
+            chain,
+            views: Arc::new(views),
+            loaders: Arc::new(loaders),
+            owner: Owner::current().unwrap(),
+            depth: 0,
+        };
+        outlet.render()
+    }
+}
+
+type RouteView = Arc<Mutex<ChildrenFnMut>>;
+
+// Routes are flattened so the full path of each route can be matched directly. Each path maps to
+// the chain of views from the outermost layout down to the matched route.
+fn flatten_routes(
+    routes: Vec<Route>,
+    prefix: &str,
+    parents: &[usize],
+    views: &mut Vec<RouteView>,
+    loaders: &mut Vec<Option<LoaderFn>>,
+    chains: &mut Vec<(String, Vec<usize>)>,
+) {
+    for route in routes {
+        let id = views.len();
+        views.push(Arc::new(Mutex::new(route.view)));
+        loaders.push(route.loader);
+        let path = join_paths(prefix, &route.path);
+        let mut chain = parents.to_vec();
+        chain.push(id);
+
+        // The index route is matched instead of the parent if there is one
+        let has_index = route.children.iter().any(|c| is_index(&c.path));
+        if !has_index {
+            chains.push((path.clone(), chain.clone()));
+        }
+        flatten_routes(route.children, &path, &chain, views, loaders, chains);
+    }
+}
+
+fn is_index(path: &str) -> bool {
+    path.is_empty() || path == "/"
+}
+
+fn join_paths(prefix: &str, path: &str) -> String {
+    if prefix.is_empty() {
+        path.to_string()
+    } else if is_index(path) {
+        prefix.to_string()
+    } else {
+        format!(
+            "{}/{}",
+            prefix.trim_end_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
+}
+
+#[derive(Clone)]
+struct OutletContext {
+    chain: Memo<Vec<usize>>,
+    views: Arc<Vec<RouteView>>,
+    loaders: Arc<Vec<Option<LoaderFn>>>,
+    owner: Owner,
+    depth: usize,
+}
+
+impl OutletContext {
+    fn render(self) -> impl rooibos_reactive::dom::RenderAny {
+        let Self { chain, depth, .. } = self;
+        // Only re-render when the route at this depth changes so parent layouts aren't rebuilt
+        // when navigating between their children
+        let view_index = Memo::new(move |_| chain.with(|c| c.get(depth).copied()));
+        let mut route_owner = None;
         move || {
-            let index = route_index.get();
-            owner.with(|| (routes[index].children)())
+            let index = view_index.get()?;
+            let view = self.views[index].clone();
+            let context = Self {
+                depth: depth + 1,
+                ..self.clone()
+            };
+            // Each route gets its own owner so its loader and guards are cleaned up when
+            // navigating away from it
+            let owner = self.owner.child();
+            let rendered = owner.with(|| {
+                // Any outlets in this view render the next route in the chain
+                provide_context(context);
+                provide_context(RouteScope(index));
+                if let Some(loader) = &self.loaders[index] {
+                    loader();
+                }
+                (view.lock().unwrap())()
+            });
+            if let Some(previous) = route_owner.replace(owner) {
+                previous.cleanup();
+            }
+            Some(rendered)
         }
     }
 }
+
+/// Renders the child route that matches the current URL inside a parent route's view.
+///
+/// Renders nothing if the parent route was matched directly.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Outlet;
+
+impl Outlet {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn render(self) -> impl rooibos_reactive::dom::RenderAny {
+        use_context::<OutletContext>()
+            .expect("Outlet must be rendered inside a route")
+            .render()
+    }
+}
+
+#[cfg(test)]
+#[path = "./lib_test.rs"]
+mod lib_test;
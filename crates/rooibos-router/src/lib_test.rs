@@ -0,0 +1,32 @@
+use super::normalize_link;
+
+#[test]
+fn normalize_custom_scheme() {
+    assert_eq!(normalize_link("myapp://post/1"), "/post/1");
+    assert_eq!(normalize_link("myapp:///post/1"), "/post/1");
+    assert_eq!(normalize_link("myapp://post/1?tab=2"), "/post/1?tab=2");
+}
+
+#[test]
+fn normalize_path() {
+    assert_eq!(normalize_link("/post/1"), "/post/1");
+    assert_eq!(normalize_link("post/1"), "post/1");
+}
+
+#[test]
+fn normalize_nested_url() {
+    // A url inside the path or query isn't a scheme
+    assert_eq!(
+        normalize_link("/search?q=https://example.com"),
+        "/search?q=https://example.com"
+    );
+    assert_eq!(
+        normalize_link("/redirect/https://example.com"),
+        "/redirect/https://example.com"
+    );
+    assert_eq!(
+        normalize_link("/post/1#https://example.com"),
+        "/post/1#https://example.com"
+    );
+    assert_eq!(normalize_link("://post/1"), "://post/1");
+}
@@ -0,0 +1,348 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::de::{self, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Visitor};
+use serde::forward_to_deserialize_any;
+
+/// Error returned when the current route's params or query can't be deserialized into the
+/// requested type.
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
+pub enum ParamsError {
+    #[error("missing param {0}")]
+    Missing(String),
+    #[error("invalid value {value:?} for param {param}: {message}")]
+    Invalid {
+        param: String,
+        value: String,
+        message: String,
+    },
+    #[error("{0}")]
+    Custom(String),
+}
+
+impl de::Error for ParamsError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self::Custom(msg.to_string())
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        Self::Missing(field.to_string())
+    }
+}
+
+// Each key may appear multiple times in the query, so values are grouped by key. Anything that
+// isn't a sequence uses the last value.
+pub(crate) struct ParamsDeserializer {
+    params: Vec<(String, Vec<String>)>,
+}
+
+impl ParamsDeserializer {
+    pub(crate) fn new<K, V>(pairs: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let mut params: Vec<(String, Vec<String>)> = Vec::new();
+        for (key, value) in pairs {
+            let key = key.into();
+            match params.iter_mut().find(|(k, _)| *k == key) {
+                Some((_, values)) => values.push(value.into()),
+                None => params.push((key, vec![value.into()])),
+            }
+        }
+        Self { params }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for ParamsDeserializer {
+    type Error = ParamsError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_map(ParamsAccess {
+            params: self.params.into_iter(),
+            value: None,
+        })
+    }
+
+    fn deserialize_unit_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+struct ParamsAccess {
+    params: std::vec::IntoIter<(String, Vec<String>)>,
+    value: Option<(String, Vec<String>)>,
+}
+
+impl<'de> MapAccess<'de> for ParamsAccess {
+    type Error = ParamsError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        let Some((key, values)) = self.params.next() else {
+            return Ok(None);
+        };
+        let key_de: de::value::StringDeserializer<ParamsError> = key.clone().into_deserializer();
+        self.value = Some((key, values));
+        seed.deserialize(key_de).map(Some)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let (param, values) = self.value.take().expect("value requested before key");
+        seed.deserialize(ValuesDeserializer { param, values })
+    }
+}
+
+struct ValuesDeserializer {
+    param: String,
+    values: Vec<String>,
+}
+
+impl ValuesDeserializer {
+    fn last(mut self) -> ValueDeserializer {
+        ValueDeserializer {
+            value: self.values.pop().unwrap_or_default(),
+            param: self.param,
+        }
+    }
+}
+
+macro_rules! forward_to_last {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                self.last().$method(visitor)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for ValuesDeserializer {
+    type Error = ParamsError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        if self.values.len() > 1 {
+            self.deserialize_seq(visitor)
+        } else {
+            self.last().deserialize_any(visitor)
+        }
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_seq(ValuesAccess {
+            param: self.param,
+            values: self.values.into_iter(),
+        })
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.last().deserialize_enum(name, variants, visitor)
+    }
+
+    forward_to_last! {
+        deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64
+        deserialize_i128 deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64
+        deserialize_u128 deserialize_f32 deserialize_f64 deserialize_char deserialize_str
+        deserialize_string
+    }
+
+    forward_to_deserialize_any! {
+        bytes byte_buf unit unit_struct tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+struct ValuesAccess {
+    param: String,
+    values: std::vec::IntoIter<String>,
+}
+
+impl<'de> SeqAccess<'de> for ValuesAccess {
+    type Error = ParamsError;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        let Some(value) = self.values.next() else {
+            return Ok(None);
+        };
+        seed.deserialize(ValueDeserializer {
+            param: self.param.clone(),
+            value,
+        })
+        .map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.values.len())
+    }
+}
+
+struct ValueDeserializer {
+    param: String,
+    value: String,
+}
+
+impl ValueDeserializer {
+    fn parse<T>(self) -> Result<T, ParamsError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.value
+            .parse()
+            .map_err(|e: T::Err| ParamsError::Invalid {
+                message: e.to_string(),
+                param: self.param,
+                value: self.value,
+            })
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                visitor.$visit(self.parse()?)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for ValueDeserializer {
+    type Error = ParamsError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_string(self.value)
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        // Only unit variants can be represented by a single value
+        let param = self.param;
+        let value = self.value;
+        visitor
+            .visit_enum(value.clone().into_deserializer())
+            .map_err(|e: ParamsError| match e {
+                ParamsError::Custom(message) => ParamsError::Invalid {
+                    param,
+                    value,
+                    message,
+                },
+                e => e,
+            })
+    }
+
+    deserialize_parsed! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    forward_to_deserialize_any! {
+        str string bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+#[path = "./params_test.rs"]
+mod params_test;
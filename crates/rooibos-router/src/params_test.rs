@@ -0,0 +1,134 @@
+use serde::Deserialize;
+
+use super::{ParamsDeserializer, ParamsError};
+
+fn deserialize<T>(pairs: &[(&str, &str)]) -> Result<T, ParamsError>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    T::deserialize(ParamsDeserializer::new(pairs.iter().copied()))
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Page {
+    id: u32,
+    page: Option<u32>,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Tags {
+    tag: Vec<String>,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Ids {
+    id: Vec<u32>,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum Sort {
+    Asc,
+    Desc,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Search {
+    sort: Sort,
+    dir: Option<Sort>,
+}
+
+#[test]
+fn missing_optional_values() {
+    assert_eq!(
+        deserialize::<Page>(&[("id", "1")]),
+        Ok(Page { id: 1, page: None })
+    );
+    assert_eq!(
+        deserialize::<Page>(&[("id", "1"), ("page", "2")]),
+        Ok(Page {
+            id: 1,
+            page: Some(2)
+        })
+    );
+    assert_eq!(
+        deserialize::<Page>(&[("page", "2")]),
+        Err(ParamsError::Missing("id".to_string()))
+    );
+}
+
+#[test]
+fn repeated_keys() {
+    assert_eq!(
+        deserialize::<Tags>(&[("tag", "a"), ("other", "x"), ("tag", "b")]),
+        Ok(Tags {
+            tag: vec!["a".to_string(), "b".to_string()]
+        })
+    );
+    // A single value is still a sequence
+    assert_eq!(
+        deserialize::<Tags>(&[("tag", "a")]),
+        Ok(Tags {
+            tag: vec!["a".to_string()]
+        })
+    );
+    // Fields that aren't sequences use the last value
+    assert_eq!(
+        deserialize::<Page>(&[("id", "1"), ("id", "2")]),
+        Ok(Page { id: 2, page: None })
+    );
+}
+
+#[test]
+fn enum_variants() {
+    assert_eq!(
+        deserialize::<Search>(&[("sort", "desc"), ("dir", "asc")]),
+        Ok(Search {
+            sort: Sort::Desc,
+            dir: Some(Sort::Asc)
+        })
+    );
+    assert_eq!(
+        deserialize::<Search>(&[("sort", "asc")]),
+        Ok(Search {
+            sort: Sort::Asc,
+            dir: None
+        })
+    );
+
+    let err = deserialize::<Search>(&[("sort", "up")]).unwrap_err();
+    assert_eq!(
+        err,
+        ParamsError::Invalid {
+            param: "sort".to_string(),
+            value: "up".to_string(),
+            message: "unknown variant `up`, expected `asc` or `desc`".to_string(),
+        }
+    );
+}
+
+#[test]
+fn type_mismatch_errors() {
+    let err = deserialize::<Page>(&[("id", "abc")]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value \"abc\" for param id: invalid digit found in string"
+    );
+
+    let err = deserialize::<Page>(&[("id", "1"), ("page", "-1")]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value \"-1\" for param page: invalid digit found in string"
+    );
+
+    // Each value in a sequence is checked
+    let err = deserialize::<Ids>(&[("id", "1"), ("id", "two")]).unwrap_err();
+    assert_eq!(
+        err,
+        ParamsError::Invalid {
+            param: "id".to_string(),
+            value: "two".to_string(),
+            message: "invalid digit found in string".to_string(),
+        }
+    );
+}
@@ -13,6 +13,7 @@ use educe::Educe;
 use ratatui::Terminal;
 use ratatui::backend::Backend as TuiBackend;
 use ratatui::text::Text;
+use rooibos_components::ProgressReporter;
 use rooibos_reactive::graph::computed::ScopedFuture;
 use rooibos_reactive::graph::owner::Owner;
 use tokio::runtime::Handle;
@@ -163,6 +164,21 @@ pub fn spawn_service<S: BackgroundService + Send + 'static>(service: S) -> TaskI
     with_state(|s| s.context.spawn(ScopedBackgroundService::new(service)))
 }
 
+/// Spawns a service that reports its progress through a [`ProgressReporter`]. The returned
+/// reporter can be passed to [`ProgressBar::render_reporter`] or [`Gauge::render_reporter`].
+///
+/// [`ProgressBar::render_reporter`]: rooibos_components::ProgressBar::render_reporter
+/// [`Gauge::render_reporter`]: rooibos_components::Gauge::render_reporter
+pub fn spawn_progress_service<S, F>(f: F) -> (TaskId, ProgressReporter)
+where
+    F: FnOnce(ProgressReporter) -> S,
+    S: BackgroundService + Send + 'static,
+{
+    let reporter = ProgressReporter::new();
+    let task_id = spawn_service(f(reporter.clone()));
+    (task_id, reporter)
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub fn spawn_service_on<S: BackgroundService + Send + 'static>(
     service: S,
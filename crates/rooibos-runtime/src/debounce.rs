@@ -2,13 +2,12 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
+use rooibos_dom::clock;
 use rooibos_reactive::spawn_local;
 use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
 use tracing::error;
 
-use crate::wasm_compat;
-
 #[derive(Debug)]
 pub(crate) struct Debouncer<T> {
     pending: Option<T>,
@@ -37,7 +36,7 @@ impl<T> Debouncer<T> {
 
             async move {
                 loop {
-                    let timeout = wasm_compat::sleep(debounce_time);
+                    let timeout = clock::sleep(debounce_time);
                     tokio::select! {
                         Some(_) = update_rx.recv() => {},
                         _ = timeout => {
@@ -2,6 +2,8 @@ mod commands;
 mod debounce;
 pub mod error;
 mod input_handler;
+#[cfg(feature = "recording")]
+pub mod recording;
 mod runtime;
 mod settings;
 mod signal_handler;
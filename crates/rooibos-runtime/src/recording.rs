@@ -0,0 +1,177 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::sync::mpsc;
+use std::thread;
+
+use rooibos_dom::{Event, KeyEvent, MouseEvent, clock};
+use serde::{Deserialize, Serialize};
+use tracing::error;
+
+/// Input events captured from a running app, along with the size of the terminal they were
+/// captured in.
+///
+/// Recordings are stored as JSON lines. The first line holds the terminal size and each line
+/// after it holds one event, so a recording can still be read if the app crashed while it was
+/// being written.
+///
+/// Set [`RuntimeSettings::record_input`](crate::RuntimeSettings::record_input) to create one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Recording {
+    pub width: u16,
+    pub height: u16,
+    pub events: Vec<RecordedEvent>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RecordedEvent {
+    /// Milliseconds since the recording started.
+    pub time_ms: u64,
+    pub event: InputEvent,
+}
+
+/// An [`Event`] read from the terminal. Events created by the DOM, like focus changes, are never
+/// seen by the recorder since they're created again when the input is replayed.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum InputEvent {
+    Key(KeyEvent),
+    Mouse(MouseEvent),
+    WindowFocusGained,
+    WindowFocusLost,
+    Paste(String),
+    Resize { cols: u32, rows: u32 },
+}
+
+impl From<&Event> for InputEvent {
+    fn from(value: &Event) -> Self {
+        match value {
+            Event::Key(key_event) => Self::Key(*key_event),
+            Event::Mouse(mouse_event) => Self::Mouse(*mouse_event),
+            Event::FocusGained => Self::WindowFocusGained,
+            Event::FocusLost => Self::WindowFocusLost,
+            Event::Paste(text) => Self::Paste(text.clone()),
+            Event::Resize { cols, rows } => Self::Resize {
+                cols: *cols,
+                rows: *rows,
+            },
+        }
+    }
+}
+
+impl From<InputEvent> for Event {
+    fn from(value: InputEvent) -> Self {
+        match value {
+            InputEvent::Key(key_event) => Event::Key(key_event),
+            InputEvent::Mouse(mouse_event) => Event::Mouse(mouse_event),
+            InputEvent::WindowFocusGained => Event::FocusGained,
+            InputEvent::WindowFocusLost => Event::FocusLost,
+            InputEvent::Paste(text) => Event::Paste(text),
+            InputEvent::Resize { cols, rows } => Event::Resize { cols, rows },
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Header {
+    width: u16,
+    height: u16,
+}
+
+impl Recording {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            events: Vec::new(),
+        }
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let mut lines = BufReader::new(File::open(path)?).lines();
+        let header: Header = match lines.next() {
+            Some(line) => serde_json::from_str(&line?)?,
+            None => {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "recording is empty",
+                ));
+            }
+        };
+        let mut recording = Self::new(header.width, header.height);
+        for line in lines {
+            let line = line?;
+            if !line.trim().is_empty() {
+                recording.events.push(serde_json::from_str(&line)?);
+            }
+        }
+        Ok(recording)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        write_line(
+            &mut writer,
+            &Header {
+                width: self.width,
+                height: self.height,
+            },
+        )?;
+        for event in &self.events {
+            write_line(&mut writer, event)?;
+        }
+        writer.flush()
+    }
+}
+
+fn write_line<T>(writer: &mut impl Write, value: &T) -> io::Result<()>
+where
+    T: Serialize,
+{
+    serde_json::to_writer(&mut *writer, value)?;
+    writeln!(writer)
+}
+
+/// Writes input events to a [`Recording`] file as they happen.
+///
+/// Events are written on a background thread so reading input never waits on the file.
+#[derive(Debug)]
+pub(crate) struct Recorder {
+    tx: mpsc::Sender<RecordedEvent>,
+    start_ms: f64,
+}
+
+impl Recorder {
+    pub(crate) fn create(path: impl AsRef<Path>, width: u16, height: u16) -> io::Result<Self> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        write_line(&mut writer, &Header { width, height })?;
+        writer.flush()?;
+
+        let (tx, rx) = mpsc::channel::<RecordedEvent>();
+        thread::spawn(move || {
+            while let Ok(event) = rx.recv() {
+                // Flush once the pending events are written so the recording is usable even if
+                // the app crashes
+                let result = write_line(&mut writer, &event)
+                    .and_then(|_| rx.try_iter().try_for_each(|e| write_line(&mut writer, &e)))
+                    .and_then(|_| writer.flush());
+                if let Err(e) = result {
+                    error!("failed to record input event: {e:?}");
+                    return;
+                }
+            }
+        });
+        Ok(Self {
+            tx,
+            start_ms: clock::now(),
+        })
+    }
+
+    pub(crate) fn record(&mut self, event: &Event) {
+        let time_ms = (clock::now() - self.start_ms).max(0.0) as u64;
+        // The writer only stops if writing failed, which was already logged
+        let _ = self.tx.send(RecordedEvent {
+            time_ms,
+            event: event.into(),
+        });
+    }
+}
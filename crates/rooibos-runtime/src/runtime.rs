@@ -28,6 +28,8 @@ use tracing::{error, warn};
 use crate::debounce::Debouncer;
 use crate::error::RuntimeError;
 use crate::input_handler::InputHandler;
+#[cfg(feature = "recording")]
+use crate::recording::Recorder;
 use crate::signal_handler::proc_exit;
 use crate::{
     ControlFlow, ExitPayload, RuntimeSettings, TerminalCommand, TerminalFnBoxed, restore_terminal,
@@ -54,6 +56,9 @@ pub struct Runtime<B: Backend, P = ()> {
     service_context: ServiceContext,
     signal_handler_running: bool,
     before_read_events: Arc<dyn Fn() -> P + Send + Sync>,
+    #[cfg(feature = "recording")]
+    #[educe(Debug(ignore))]
+    recorder: Arc<wasm_compat::Mutex<Option<Recorder>>>,
 }
 
 #[derive(Debug, Clone)]
@@ -128,6 +133,8 @@ where
             service_context,
             signal_handler_running: false,
             before_read_events: Arc::new(|| {}),
+            #[cfg(feature = "recording")]
+            recorder: Arc::new(wasm_compat::Mutex::new(None)),
         }
     }
 }
@@ -161,6 +168,8 @@ where
             service_context: self.service_context,
             signal_handler_running: self.signal_handler_running,
             before_read_events: Arc::new(f),
+            #[cfg(feature = "recording")]
+            recorder: self.recorder,
         }
     }
 
@@ -196,6 +205,14 @@ where
             .with_terminal_mut(move |t| backend.window_size(t.backend_mut()))
             .await
             .ok();
+        #[cfg(feature = "recording")]
+        if let (Some(path), Some(window_size)) = (&self.settings.record_input, &window_size) {
+            let size = window_size.columns_rows;
+            match Recorder::create(path, size.width, size.height) {
+                Ok(recorder) => *self.recorder.lock_mut() = Some(recorder),
+                Err(e) => error!("failed to create input recording: {e:?}"),
+            }
+        }
         mount(|| f(params), window_size);
     }
 
@@ -215,6 +232,8 @@ where
     pub async fn configure_terminal_events(&mut self) -> io::Result<()> {
         if self.settings.enable_input_reader {
             let term_parser_tx = self.term_parser_tx.clone();
+            #[cfg(feature = "recording")]
+            let recorder = self.recorder.clone();
 
             let input_task_cancellation = self.service_context.cancellation_token().child_token();
             let input_stream = self
@@ -226,6 +245,10 @@ where
                     pin_mut!(input_stream);
 
                     while let Some(event) = input_stream.next().await {
+                        #[cfg(feature = "recording")]
+                        if let Some(recorder) = recorder.lock_mut().as_mut() {
+                            recorder.record(&event);
+                        }
                         let _ = term_parser_tx.send(event);
                     }
                     Ok(())
@@ -1,4 +1,6 @@
 use std::num::NonZeroU16;
+#[cfg(feature = "recording")]
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -26,6 +28,8 @@ pub struct RuntimeSettings {
     pub(crate) resize_debounce: Duration,
     pub(crate) viewport: Viewport,
     pub(crate) max_fps: f32,
+    #[cfg(feature = "recording")]
+    pub(crate) record_input: Option<PathBuf>,
     #[educe(Debug(ignore))]
     pub(crate) is_quit_event: Arc<IsQuitEvent>,
     #[educe(Debug(ignore))]
@@ -40,6 +44,8 @@ impl Default for RuntimeSettings {
             show_final_output: None,
             viewport: Viewport::Fullscreen,
             max_fps: 60.0,
+            #[cfg(feature = "recording")]
+            record_input: None,
             hover_debounce: Duration::from_millis(20),
             resize_debounce: Duration::from_millis(20),
             is_quit_event: Arc::new(|key_event| {
@@ -88,6 +94,14 @@ impl RuntimeSettings {
         self
     }
 
+    /// Records every input event to a file at `path` so the session can be replayed later. See
+    /// [`Recording`](crate::recording::Recording) for the file format.
+    #[cfg(feature = "recording")]
+    pub fn record_input(mut self, path: impl Into<PathBuf>) -> Self {
+        self.record_input = Some(path.into());
+        self
+    }
+
     pub fn is_quit_event<F>(mut self, f: F) -> Self
     where
         F: Fn(KeyEvent) -> bool + Send + Sync + 'static,
@@ -0,0 +1,345 @@
+use std::fmt::Write;
+use std::io;
+use std::path::Path;
+
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::style::{Color, Modifier};
+use rooibos_theme::{ColorPalette, SetTheme, TermProfile};
+use unicode_width::UnicodeWidthStr;
+
+use crate::snapshot::visible_cells;
+
+// Monospace fonts are roughly 0.6em wide. Each run of text is stretched to fit its cells anyway,
+// so this only needs to be close.
+const CELL_WIDTH_EM: f32 = 0.6;
+const LINE_HEIGHT_EM: f32 = 1.2;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ExportError {
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+    #[error("unsupported file extension {0:?}")]
+    UnsupportedFormat(String),
+    #[cfg(feature = "png")]
+    #[error("failed to render PNG: {0}")]
+    Png(String),
+}
+
+/// Renders a [`Buffer`] to a styled SVG, PNG or standalone HTML page, so screenshots can be
+/// created without a real terminal.
+///
+/// Colors are adapted to the [`TermProfile`] the same way they would be when drawn to a
+/// terminal, and the default foreground and background come from the [`ColorPalette`]. Both
+/// default to the current values, which [`TestHarness`](crate::TestHarness) sets to true color
+/// with white text on a black background.
+///
+/// ```ignore
+/// let buffer = harness.buffer().await;
+/// Screenshot::new(&buffer).title("counter").save("target/counter.svg")?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct Screenshot<'a> {
+    buffer: &'a Buffer,
+    palette: ColorPalette,
+    profile: TermProfile,
+    font_family: String,
+    font_size: f32,
+    title: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Eq)]
+struct CellStyle {
+    fg: String,
+    bg: Option<String>,
+    modifier: Modifier,
+}
+
+impl<'a> Screenshot<'a> {
+    pub fn new(buffer: &'a Buffer) -> Self {
+        Self {
+            buffer,
+            palette: ColorPalette::current(),
+            profile: TermProfile::current(),
+            font_family: "'DejaVu Sans Mono', Menlo, Consolas, monospace".to_string(),
+            font_size: 14.0,
+            title: None,
+        }
+    }
+
+    pub fn palette(mut self, palette: ColorPalette) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    pub fn profile(mut self, profile: TermProfile) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    /// CSS font family list used for the text.
+    pub fn font_family(mut self, font_family: impl Into<String>) -> Self {
+        self.font_family = font_family.into();
+        self
+    }
+
+    /// Font size in pixels.
+    pub fn font_size(mut self, font_size: f32) -> Self {
+        self.font_size = font_size;
+        self
+    }
+
+    /// Title of the SVG or HTML document.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Writes the screenshot to `path`. The format is chosen from the file extension, which
+    /// must be `svg`, `html`, `htm` or `png`.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ExportError> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match extension.as_str() {
+            "svg" => std::fs::write(path, self.to_svg())?,
+            "html" | "htm" => std::fs::write(path, self.to_html())?,
+            #[cfg(feature = "png")]
+            "png" => std::fs::write(path, self.to_png()?)?,
+            _ => return Err(ExportError::UnsupportedFormat(extension)),
+        }
+        Ok(())
+    }
+
+    pub fn to_svg(&self) -> String {
+        let cell_width = self.font_size * CELL_WIDTH_EM;
+        let line_height = self.font_size * LINE_HEIGHT_EM;
+        let width = self.buffer.area.width as f32 * cell_width;
+        let height = self.buffer.area.height as f32 * line_height;
+
+        let mut svg = String::new();
+        writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+        )
+        .unwrap();
+        if let Some(title) = &self.title {
+            writeln!(svg, "<title>{}</title>", escape(title)).unwrap();
+        }
+        writeln!(
+            svg,
+            "<style>text {{ font-family: {}; font-size: {}px; white-space: pre; }}</style>",
+            escape(&self.font_family),
+            self.font_size
+        )
+        .unwrap();
+        writeln!(
+            svg,
+            r#"<rect width="100%" height="100%" fill="{}"/>"#,
+            self.default_bg()
+        )
+        .unwrap();
+
+        for row in 0..self.buffer.area.height {
+            let y = row as f32 * line_height;
+            for (col, len, text, style) in self.runs(row) {
+                let x = col as f32 * cell_width;
+                let run_width = len as f32 * cell_width;
+                if let Some(bg) = &style.bg {
+                    writeln!(
+                        svg,
+                        r#"<rect x="{x}" y="{y}" width="{run_width}" height="{line_height}" fill="{bg}"/>"#
+                    )
+                    .unwrap();
+                }
+                if text.trim().is_empty() {
+                    continue;
+                }
+                let mut attrs = format!(r#"fill="{}""#, style.fg);
+                if style.modifier.contains(Modifier::BOLD) {
+                    attrs += r#" font-weight="bold""#;
+                }
+                if style.modifier.contains(Modifier::ITALIC) {
+                    attrs += r#" font-style="italic""#;
+                }
+                if style.modifier.contains(Modifier::DIM) {
+                    attrs += r#" fill-opacity="0.5""#;
+                }
+                if let Some(decoration) = text_decoration(style.modifier) {
+                    write!(attrs, r#" text-decoration="{decoration}""#).unwrap();
+                }
+                // The baseline sits a little above the bottom of the line to leave room for
+                // descenders
+                writeln!(
+                    svg,
+                    r#"<text x="{x}" y="{}" textLength="{run_width}" lengthAdjust="spacingAndGlyphs" {attrs}>{}</text>"#,
+                    y + self.font_size,
+                    escape(&text)
+                )
+                .unwrap();
+            }
+        }
+        svg += "</svg>\n";
+        svg
+    }
+
+    pub fn to_html(&self) -> String {
+        let mut body = String::new();
+        for row in 0..self.buffer.area.height {
+            for (_, _, text, style) in self.runs(row) {
+                let mut css = format!("color:{}", style.fg);
+                if let Some(bg) = &style.bg {
+                    write!(css, ";background:{bg}").unwrap();
+                }
+                if style.modifier.contains(Modifier::BOLD) {
+                    css += ";font-weight:bold";
+                }
+                if style.modifier.contains(Modifier::ITALIC) {
+                    css += ";font-style:italic";
+                }
+                if style.modifier.contains(Modifier::DIM) {
+                    css += ";opacity:0.5";
+                }
+                if let Some(decoration) = text_decoration(style.modifier) {
+                    write!(css, ";text-decoration:{decoration}").unwrap();
+                }
+                write!(body, r#"<span style="{css}">{}</span>"#, escape(&text)).unwrap();
+            }
+            body += "\n";
+        }
+
+        let title = escape(self.title.as_deref().unwrap_or("rooibos"));
+        format!(
+            r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<style>
+body {{ margin: 0; background: {bg}; }}
+pre {{ margin: 0; padding: 8px; color: {fg}; background: {bg}; font-family: {font_family}; font-size: {font_size}px; line-height: {LINE_HEIGHT_EM}; }}
+</style>
+</head>
+<body>
+<pre>{body}</pre>
+</body>
+</html>
+"#,
+            fg = self.default_fg(),
+            bg = self.default_bg(),
+            // Entities aren't decoded inside of an HTML style tag
+            font_family = self.font_family,
+            font_size = self.font_size,
+        )
+    }
+
+    /// Renders the SVG to a PNG. Text is drawn using the fonts installed on the system, so the
+    /// output may differ between machines.
+    #[cfg(feature = "png")]
+    pub fn to_png(&self) -> Result<Vec<u8>, ExportError> {
+        use resvg::{tiny_skia, usvg};
+
+        let mut options = usvg::Options::default();
+        options.fontdb_mut().load_system_fonts();
+        let tree = usvg::Tree::from_str(&self.to_svg(), &options)
+            .map_err(|e| ExportError::Png(e.to_string()))?;
+        let size = tree.size().to_int_size();
+        let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height())
+            .ok_or_else(|| ExportError::Png("buffer is empty".to_string()))?;
+        resvg::render(&tree, tiny_skia::Transform::default(), &mut pixmap.as_mut());
+        pixmap
+            .encode_png()
+            .map_err(|e| ExportError::Png(e.to_string()))
+    }
+
+    // Groups consecutive cells in the row that have the same style
+    fn runs(&self, row: u16) -> Vec<(u16, u16, String, CellStyle)> {
+        let mut runs: Vec<(u16, u16, String, CellStyle)> = Vec::new();
+        for (col, cell) in visible_cells(self.buffer, row) {
+            let width = cell.symbol().width().max(1) as u16;
+            let style = self.cell_style(cell);
+            match runs.last_mut() {
+                Some((start, len, text, last)) if *last == style && *start + *len == col => {
+                    *len += width;
+                    *text += cell.symbol();
+                }
+                _ => runs.push((col, width, cell.symbol().to_string(), style)),
+            }
+        }
+        runs
+    }
+
+    fn cell_style(&self, cell: &Cell) -> CellStyle {
+        let modifier = cell.modifier;
+        let fg = self.fg_color(cell.fg);
+        let bg = self.bg_color(cell.bg);
+        // Reversed cells always need a background, even if both colors are the defaults
+        let (fg, bg) = if modifier.contains(Modifier::REVERSED) {
+            (
+                bg.unwrap_or_else(|| self.default_bg()),
+                Some(fg.unwrap_or_else(|| self.default_fg())),
+            )
+        } else {
+            (fg.unwrap_or_else(|| self.default_fg()), bg)
+        };
+        let fg = if modifier.contains(Modifier::HIDDEN) {
+            bg.clone().unwrap_or_else(|| self.default_bg())
+        } else {
+            fg
+        };
+        CellStyle { fg, bg, modifier }
+    }
+
+    // Returns None for the default color
+    fn fg_color(&self, color: Color) -> Option<String> {
+        match self.profile.adapt_color(color)? {
+            Color::Reset => None,
+            color => Some(rooibos_theme::Color::from(color).to_hex_fg()),
+        }
+    }
+
+    // Returns None for the default color
+    fn bg_color(&self, color: Color) -> Option<String> {
+        match self.profile.adapt_color(color)? {
+            Color::Reset => None,
+            color => Some(rooibos_theme::Color::from(color).to_hex_bg()),
+        }
+    }
+
+    fn default_fg(&self) -> String {
+        self.palette.terminal_fg.to_hex_fg()
+    }
+
+    fn default_bg(&self) -> String {
+        self.palette.terminal_bg.to_hex_bg()
+    }
+}
+
+fn text_decoration(modifier: Modifier) -> Option<&'static str> {
+    match (
+        modifier.contains(Modifier::UNDERLINED),
+        modifier.contains(Modifier::CROSSED_OUT),
+    ) {
+        (true, true) => Some("underline line-through"),
+        (true, false) => Some("underline"),
+        (false, true) => Some("line-through"),
+        (false, false) => None,
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped += "&amp;",
+            '<' => escaped += "&lt;",
+            '>' => escaped += "&gt;",
+            '"' => escaped += "&quot;",
+            '\'' => escaped += "&#39;",
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
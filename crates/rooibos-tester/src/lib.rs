@@ -1,24 +1,45 @@
+mod export;
+mod query;
+mod snapshot;
 mod test_backend;
+#[cfg(feature = "runtime")]
+mod user_event;
 
 use std::fmt::Write;
 use std::mem;
 use std::time::{Duration, Instant};
 
+pub use export::*;
+pub use query::*;
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
+#[cfg(feature = "runtime")]
+use rooibos_dom::clock;
 use rooibos_dom::{
     DomNodeRepr, Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
     NodeTypeRepr, NonblockingTerminal, focus_next, render_terminal,
 };
 use rooibos_reactive::dom::Render;
+#[cfg(feature = "recording")]
+use rooibos_runtime::recording::{InputEvent, Recording};
 #[cfg(feature = "runtime")]
 use rooibos_runtime::wasm_compat::{self, Lazy, RwLock};
 #[cfg(feature = "runtime")]
 use rooibos_runtime::{Runtime, RuntimeSettings, TickResult};
+#[doc(hidden)]
+pub use snapshot::__assert_buffer_snapshot;
+pub use snapshot::{buffer_diff, parse_styled_view};
 pub use test_backend::*;
 use tokio::sync::broadcast;
 use unicode_width::UnicodeWidthStr;
 
+// Ticks that can run without the app becoming idle before we assume it's stuck in a loop
+#[cfg(feature = "runtime")]
+const MAX_IDLE_TICKS: usize = 10_000;
+// How far the virtual clock moves between checks in wait_for
+#[cfg(feature = "runtime")]
+const VIRTUAL_WAIT_STEP: Duration = Duration::from_millis(10);
+
 #[cfg(feature = "runtime")]
 wasm_compat::static_init! {
     static DEFAULT_TIMEOUT: Lazy<RwLock<Duration>> =
@@ -27,6 +48,17 @@ wasm_compat::static_init! {
 
 pub trait TerminalView {
     fn terminal_view(&self) -> String;
+    /// Like [`terminal_view`](TerminalView::terminal_view), but includes the styles of each cell.
+    ///
+    /// Each row is wrapped in `|`. Any styled cells in the row are marked with `^` on the lines
+    /// below it, followed by the style.
+    ///
+    /// ```text
+    /// |count: 0  |
+    ///  ^^^^^^^ mod=BOLD
+    ///         ^ fg=Cyan
+    /// ```
+    fn styled_view(&self) -> String;
     fn slice(&self, rect: Rect) -> Buffer;
 }
 
@@ -45,6 +77,10 @@ impl TerminalView for Buffer {
         string_buf
     }
 
+    fn styled_view(&self) -> String {
+        snapshot::styled_view(self)
+    }
+
     fn slice(&self, rect: Rect) -> Buffer {
         let mut buf = Buffer::empty(Rect {
             x: 0,
@@ -80,6 +116,18 @@ impl TestHarness<()> {
         Self::new_with_settings(RuntimeSettings::default(), width, height).await
     }
 
+    /// Creates a harness that uses a virtual clock. Timers, debouncers and animations only move
+    /// forward when the clock is advanced with [`advance`](TestHarness::advance), so time-based
+    /// tests run instantly.
+    ///
+    /// To use custom settings, call [`clock::enable_virtual`] before
+    /// [`new_with_settings`](TestHarness::new_with_settings).
+    #[cfg(feature = "runtime")]
+    pub async fn new_with_virtual_clock(width: u16, height: u16) -> Self {
+        clock::enable_virtual();
+        Self::new(width, height).await
+    }
+
     #[cfg(feature = "runtime")]
     pub async fn new_with_settings(
         runtime_settings: RuntimeSettings,
@@ -267,6 +315,18 @@ where
         self.event_tx.send(event).unwrap();
     }
 
+    /// Resizes the terminal and sends a resize event to the app.
+    pub async fn resize(&mut self, width: u16, height: u16) {
+        self.terminal
+            .with_terminal_mut(move |terminal| terminal.backend_mut().resize(width, height))
+            .await;
+        self.terminal.auto_resize().await;
+        self.send_event(Event::Resize {
+            cols: width as u32,
+            rows: height as u32,
+        });
+    }
+
     pub fn send_text(&self, text: impl Into<String>) {
         let text = text.into();
         for char in text.chars() {
@@ -303,6 +363,10 @@ where
     {
         use rooibos_dom::render_terminal;
 
+        if let Some(start) = clock::virtual_elapsed() {
+            return self.wait_for_virtual(f, start, timeout).await;
+        }
+
         let start = Instant::now();
         let mut last_tick_events = TickEvents::default();
         loop {
@@ -310,28 +374,9 @@ where
                 biased;
 
                 tick_result = self.runtime.tick() => {
-                    let tick_result = tick_result.unwrap();
-                    match tick_result {
-                        TickResult::Redraw => {
-                            render_terminal(&mut self.terminal).await.unwrap();
-                        }
-                        TickResult::Restart => {
-                            let mut new_term = self.runtime.create_terminal().unwrap();
-                            mem::swap(&mut new_term, &mut self.terminal);
-                            new_term.join().await;
-                            self.runtime.configure_terminal_events().await.unwrap();
-                            render_terminal(&mut self.terminal).await.unwrap();
-                            last_tick_events.restart = true;
-                        }
-                        TickResult::Exit(_) => {
-                            panic!("application exited");
-                        }
-                        TickResult::Command(command) => {
-                            self.runtime
-                                .handle_terminal_command(command,&mut self.terminal)
-                                .await.unwrap();
-                        }
-                        TickResult::Continue => {}
+                    self.handle_tick(tick_result.unwrap(), &mut last_tick_events).await;
+                    if last_tick_events.exit {
+                        panic!("application exited");
                     }
                     // Don't check output until debounce timeout has completed
                     // This ensures we don't check the output against partial updates
@@ -352,6 +397,191 @@ where
         }
     }
 
+    // The timeout is measured on the virtual clock, so waiting never takes real time
+    #[cfg(feature = "runtime")]
+    async fn wait_for_virtual<F>(
+        &mut self,
+        mut f: F,
+        start: Duration,
+        timeout: Duration,
+    ) -> Result<(), Buffer>
+    where
+        F: AsyncFnMut(&Self, &TickEvents) -> bool,
+    {
+        loop {
+            let tick_events = self.run_until_idle().await;
+            if tick_events.exit {
+                panic!("application exited");
+            }
+            if f(self, &tick_events).await {
+                render_terminal(&mut self.terminal).await.unwrap();
+                return Ok(());
+            }
+            let elapsed = clock::virtual_elapsed().unwrap_or_default() - start;
+            if elapsed > timeout {
+                return Err(self.buffer().await.clone());
+            }
+            self.advance(VIRTUAL_WAIT_STEP).await;
+        }
+    }
+
+    /// Moves the virtual clock forward, firing every timer that's due along the way in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the harness wasn't created with
+    /// [`new_with_virtual_clock`](TestHarness::new_with_virtual_clock).
+    #[cfg(feature = "runtime")]
+    pub async fn advance(&mut self, duration: Duration) {
+        let target = clock::virtual_elapsed().expect("virtual clock is not enabled") + duration;
+        self.run_until_idle().await;
+        while let Some(deadline) = clock::next_deadline().filter(|d| *d <= target) {
+            clock::advance_to(deadline);
+            self.run_until_idle().await;
+        }
+        clock::advance_to(target);
+        self.run_until_idle().await;
+    }
+
+    /// Processes events, timers that are due and updates until there's nothing left to do
+    /// without moving the virtual clock forward. Stops early if the app exits.
+    ///
+    /// Only work that's driven by the current thread is waited for. Tasks running on other
+    /// threads, blocking IO and timers that don't use [`clock::sleep`], such as
+    /// `tokio::time::sleep`, can still be pending when this returns. Use
+    /// [`wait_for`](TestHarness::wait_for) to wait for those instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the app never becomes idle, e.g. if a timer keeps scheduling itself with no
+    /// delay.
+    #[cfg(feature = "runtime")]
+    pub async fn run_until_idle(&mut self) -> TickEvents {
+        let mut tick_events = TickEvents::default();
+        for _ in 0..MAX_IDLE_TICKS {
+            if let Some(elapsed) = clock::virtual_elapsed() {
+                clock::advance_to(elapsed);
+            }
+            // Give spawned tasks a chance to react to events and timers
+            for _ in 0..10 {
+                tokio::task::yield_now().await;
+            }
+            let tick_result = tokio::select! {
+                biased;
+
+                tick_result = self.runtime.tick() => Some(tick_result.unwrap()),
+                _ = std::future::ready(()) => None,
+            };
+            match tick_result {
+                Some(tick_result) => {
+                    self.handle_tick(tick_result, &mut tick_events).await;
+                    if tick_events.exit {
+                        return tick_events;
+                    }
+                }
+                None if clock::next_deadline()
+                    .is_some_and(|d| Some(d) <= clock::virtual_elapsed()) => {}
+                None => return tick_events,
+            }
+        }
+        panic!("app did not become idle");
+    }
+
+    /// Sends the events from a [`Recording`] with the same timing they were recorded with, then
+    /// waits for the app to become idle. Replaying stops early if the app exits.
+    ///
+    /// The harness should be created with the size stored in the recording. With a virtual clock
+    /// the replay runs instantly, otherwise it takes as long as the recorded session did.
+    ///
+    /// ```ignore
+    /// let recording = Recording::load("bug-report.jsonl")?;
+    /// let mut harness = TestHarness::new_with_virtual_clock(recording.width, recording.height).await;
+    /// harness.mount((), app).await;
+    /// harness.replay(&recording).await;
+    /// assert_buffer_snapshot!("bug_report", harness.buffer().await);
+    /// ```
+    #[cfg(feature = "recording")]
+    pub async fn replay(&mut self, recording: &Recording) -> TickEvents {
+        let start = clock::virtual_elapsed();
+        let real_start = Instant::now();
+        let mut tick_events = self.run_until_idle().await;
+        for recorded in &recording.events {
+            if tick_events.exit {
+                return tick_events;
+            }
+            let time = Duration::from_millis(recorded.time_ms);
+            match start {
+                Some(start) => {
+                    let now = clock::virtual_elapsed().unwrap_or_default();
+                    self.advance((start + time).saturating_sub(now)).await;
+                }
+                None => {
+                    tick_events = self
+                        .run_for(time.saturating_sub(real_start.elapsed()))
+                        .await;
+                    if tick_events.exit {
+                        return tick_events;
+                    }
+                }
+            }
+            match recorded.event {
+                InputEvent::Resize { cols, rows } => self.resize(cols as u16, rows as u16).await,
+                ref event => self.send_event(event.clone().into()),
+            }
+            tick_events = self.run_until_idle().await;
+        }
+        if !tick_events.exit {
+            // Ensure the screen is updated in case we're still in a debouncer timeout
+            render_terminal(&mut self.terminal).await.unwrap();
+        }
+        tick_events
+    }
+
+    // Keeps processing ticks in real time until the duration has passed
+    #[cfg(feature = "runtime")]
+    async fn run_for(&mut self, duration: Duration) -> TickEvents {
+        let deadline = Instant::now() + duration;
+        let mut tick_events = TickEvents::default();
+        while !tick_events.exit {
+            tokio::select! {
+                tick_result = self.runtime.tick() => {
+                    self.handle_tick(tick_result.unwrap(), &mut tick_events).await;
+                }
+                _ = tokio::time::sleep(deadline.saturating_duration_since(Instant::now())) => {
+                    break;
+                }
+            }
+        }
+        tick_events
+    }
+
+    #[cfg(feature = "runtime")]
+    async fn handle_tick(&mut self, tick_result: TickResult, tick_events: &mut TickEvents) {
+        match tick_result {
+            TickResult::Redraw => {
+                render_terminal(&mut self.terminal).await.unwrap();
+            }
+            TickResult::Restart => {
+                let mut new_term = self.runtime.create_terminal().unwrap();
+                mem::swap(&mut new_term, &mut self.terminal);
+                new_term.join().await;
+                self.runtime.configure_terminal_events().await.unwrap();
+                render_terminal(&mut self.terminal).await.unwrap();
+                tick_events.restart = true;
+            }
+            TickResult::Exit(_) => {
+                tick_events.exit = true;
+            }
+            TickResult::Command(command) => {
+                self.runtime
+                    .handle_terminal_command(command, &mut self.terminal)
+                    .await
+                    .unwrap();
+            }
+            TickResult::Continue => {}
+        }
+    }
+
     #[cfg(feature = "runtime")]
     pub async fn exit(mut self) {
         use rooibos_runtime::proc_exit;
@@ -419,4 +649,12 @@ where
 #[derive(Clone, Default)]
 pub struct TickEvents {
     pub restart: bool,
+    exit: bool,
+}
+
+impl TickEvents {
+    /// Returns `true` if the app exited while the events were processed.
+    pub fn exited(&self) -> bool {
+        self.exit
+    }
 }
@@ -0,0 +1,362 @@
+use std::fmt::{self, Write};
+use std::future::{Future, IntoFuture};
+use std::marker::PhantomData;
+use std::pin::Pin;
+
+use ratatui::buffer::Buffer;
+use rooibos_dom::widgets::Role;
+use rooibos_dom::{DomNodeRepr, NonblockingTerminal, print_dom_lines, root};
+
+use crate::{TerminalView, TestHarness};
+
+/// Queries that only match nodes inside of a given node. Created with
+/// [`TestHarness::within`].
+pub struct Within<'a> {
+    terminal: &'a NonblockingTerminal<ratatui::backend::TestBackend>,
+    scope: Option<DomNodeRepr>,
+}
+
+impl<'a> Within<'a> {
+    /// Finds the only node with the given role. Panics if there isn't exactly one match.
+    pub fn get_by_role(&self, role: Role) -> Query<'a, GetOne> {
+        self.query(Matcher::Role(role))
+    }
+
+    /// Finds every node with the given role. Panics if there are no matches.
+    pub fn get_all_by_role(&self, role: Role) -> Query<'a, GetAll> {
+        self.query(Matcher::Role(role))
+    }
+
+    /// Finds the node with the given role if it exists. Panics if there's more than one match.
+    pub fn query_by_role(&self, role: Role) -> Query<'a, QueryOne> {
+        self.query(Matcher::Role(role))
+    }
+
+    /// Finds every node with the given role.
+    pub fn query_all_by_role(&self, role: Role) -> Query<'a, QueryAll> {
+        self.query(Matcher::Role(role))
+    }
+
+    /// Finds the only node that's labelled with the given text. Panics if there isn't exactly
+    /// one match.
+    ///
+    /// Labels are associated with a node using the `label_for` property.
+    pub fn get_by_label_text(&self, text: impl AsRef<str>) -> Query<'a, GetOne> {
+        self.query(Matcher::LabelText(normalize_text(text.as_ref())))
+    }
+
+    /// Finds every node that's labelled with the given text. Panics if there are no matches.
+    pub fn get_all_by_label_text(&self, text: impl AsRef<str>) -> Query<'a, GetAll> {
+        self.query(Matcher::LabelText(normalize_text(text.as_ref())))
+    }
+
+    /// Finds the node that's labelled with the given text if it exists. Panics if there's more
+    /// than one match.
+    pub fn query_by_label_text(&self, text: impl AsRef<str>) -> Query<'a, QueryOne> {
+        self.query(Matcher::LabelText(normalize_text(text.as_ref())))
+    }
+
+    /// Finds every node that's labelled with the given text.
+    pub fn query_all_by_label_text(&self, text: impl AsRef<str>) -> Query<'a, QueryAll> {
+        self.query(Matcher::LabelText(normalize_text(text.as_ref())))
+    }
+
+    fn query<M>(&self, matcher: Matcher) -> Query<'a, M> {
+        Query {
+            terminal: self.terminal,
+            scope: self.scope.clone(),
+            matcher,
+            name: None,
+            _mode: PhantomData,
+        }
+    }
+}
+
+impl<P> TestHarness<P>
+where
+    P: 'static,
+{
+    /// Scopes queries to the given node and its descendants.
+    ///
+    /// ```ignore
+    /// let sidebar = root().get_by_id("sidebar");
+    /// let save = harness.within(&sidebar).get_by_role(Role::Button).with_name("Save").await;
+    /// ```
+    pub fn within(&self, node: &DomNodeRepr) -> Within<'_> {
+        Within {
+            terminal: &self.terminal,
+            scope: Some(node.clone()),
+        }
+    }
+
+    fn screen(&self) -> Within<'_> {
+        Within {
+            terminal: &self.terminal,
+            scope: None,
+        }
+    }
+
+    /// See [`Within::get_by_role`].
+    pub fn get_by_role(&self, role: Role) -> Query<'_, GetOne> {
+        self.screen().get_by_role(role)
+    }
+
+    /// See [`Within::get_all_by_role`].
+    pub fn get_all_by_role(&self, role: Role) -> Query<'_, GetAll> {
+        self.screen().get_all_by_role(role)
+    }
+
+    /// See [`Within::query_by_role`].
+    pub fn query_by_role(&self, role: Role) -> Query<'_, QueryOne> {
+        self.screen().query_by_role(role)
+    }
+
+    /// See [`Within::query_all_by_role`].
+    pub fn query_all_by_role(&self, role: Role) -> Query<'_, QueryAll> {
+        self.screen().query_all_by_role(role)
+    }
+
+    /// See [`Within::get_by_label_text`].
+    pub fn get_by_label_text(&self, text: impl AsRef<str>) -> Query<'_, GetOne> {
+        self.screen().get_by_label_text(text)
+    }
+
+    /// See [`Within::get_all_by_label_text`].
+    pub fn get_all_by_label_text(&self, text: impl AsRef<str>) -> Query<'_, GetAll> {
+        self.screen().get_all_by_label_text(text)
+    }
+
+    /// See [`Within::query_by_label_text`].
+    pub fn query_by_label_text(&self, text: impl AsRef<str>) -> Query<'_, QueryOne> {
+        self.screen().query_by_label_text(text)
+    }
+
+    /// See [`Within::query_all_by_label_text`].
+    pub fn query_all_by_label_text(&self, text: impl AsRef<str>) -> Query<'_, QueryAll> {
+        self.screen().query_all_by_label_text(text)
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Matcher {
+    Role(Role),
+    LabelText(String),
+}
+
+/// A query for nodes in the DOM. Await the query to run it.
+///
+/// What's returned depends on how the query was created:
+///
+/// - `get_by_*` returns the only match and panics if there are none or more than one
+/// - `get_all_by_*` returns every match and panics if there are none
+/// - `query_by_*` returns `None` if there are no matches and panics if there's more than one
+/// - `query_all_by_*` returns every match, which may be empty
+///
+/// The panic message includes the DOM to make it easier to see why the query failed.
+pub struct Query<'a, M> {
+    terminal: &'a NonblockingTerminal<ratatui::backend::TestBackend>,
+    scope: Option<DomNodeRepr>,
+    matcher: Matcher,
+    name: Option<String>,
+    _mode: PhantomData<M>,
+}
+
+impl<M> Query<'_, M> {
+    /// Only matches nodes with the given accessible name.
+    ///
+    /// A node's name is the text of the nodes that label it, or the text it displays if it
+    /// doesn't have a label. Whitespace is collapsed and borders are ignored.
+    pub fn with_name(mut self, name: impl AsRef<str>) -> Self {
+        self.name = Some(normalize_text(name.as_ref()));
+        self
+    }
+
+    async fn run(self) -> QueryResult {
+        let buffer = self
+            .terminal
+            .with_terminal(|t| t.backend().buffer().clone())
+            .await;
+        let scope = self.scope.unwrap_or_else(root);
+        let labels = root().find_all(|node| node.label_for().is_some());
+
+        let candidates = match &self.matcher {
+            Matcher::Role(role) => scope.find_all_by_role(*role),
+            Matcher::LabelText(text) => labels
+                .iter()
+                .filter(|label| text_content(label, &buffer) == *text)
+                .filter_map(|label| scope.find_by_id(label.label_for()?))
+                .collect(),
+        };
+        let matches = match &self.name {
+            Some(name) => candidates
+                .iter()
+                .filter(|node| accessible_name(node, &labels, &buffer) == *name)
+                .cloned()
+                .collect(),
+            None => candidates.clone(),
+        };
+        let description = match &self.name {
+            Some(name) => format!("{} and name {name:?}", self.matcher),
+            None => self.matcher.to_string(),
+        };
+        let candidates = if self.name.is_some() {
+            candidates
+                .iter()
+                .map(|node| (node.rect(), accessible_name(node, &labels, &buffer)))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        QueryResult {
+            description,
+            matches,
+            candidates,
+        }
+    }
+}
+
+impl<'a, M> IntoFuture for Query<'a, M>
+where
+    M: QueryMode + 'a,
+{
+    type Output = M::Output;
+    type IntoFuture = Pin<Box<dyn Future<Output = M::Output> + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move { M::resolve(self.run().await) })
+    }
+}
+
+impl fmt::Display for Matcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Role(role) => write!(f, "role {role:?}"),
+            Self::LabelText(text) => write!(f, "label {text:?}"),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct QueryResult {
+    description: String,
+    matches: Vec<DomNodeRepr>,
+    // Nodes that matched before filtering by name, used to explain failed queries
+    candidates: Vec<(ratatui::layout::Rect, String)>,
+}
+
+impl QueryResult {
+    #[track_caller]
+    fn fail(&self, message: &str) -> ! {
+        let mut output = format!("{message} with {}\n", self.description);
+        if !self.candidates.is_empty() {
+            writeln!(output, "\nmatching nodes before filtering by name:").unwrap();
+            for (rect, name) in &self.candidates {
+                writeln!(output, "  {name:?} at {rect:?}").unwrap();
+            }
+        }
+        writeln!(output, "\nDOM:").unwrap();
+        for line in print_dom_lines() {
+            writeln!(output, "{line}").unwrap();
+        }
+        panic!("{output}");
+    }
+}
+
+/// Determines what a [`Query`] returns.
+pub trait QueryMode {
+    type Output;
+
+    #[doc(hidden)]
+    fn resolve(result: QueryResult) -> Self::Output;
+}
+
+/// Marker for queries created with `get_by_*`.
+pub struct GetOne;
+
+/// Marker for queries created with `get_all_by_*`.
+pub struct GetAll;
+
+/// Marker for queries created with `query_by_*`.
+pub struct QueryOne;
+
+/// Marker for queries created with `query_all_by_*`.
+pub struct QueryAll;
+
+impl QueryMode for GetOne {
+    type Output = DomNodeRepr;
+
+    fn resolve(mut result: QueryResult) -> Self::Output {
+        match result.matches.len() {
+            0 => result.fail("unable to find a node"),
+            1 => result.matches.remove(0),
+            n => result.fail(&format!("found {n} nodes")),
+        }
+    }
+}
+
+impl QueryMode for GetAll {
+    type Output = Vec<DomNodeRepr>;
+
+    fn resolve(result: QueryResult) -> Self::Output {
+        if result.matches.is_empty() {
+            result.fail("unable to find any nodes");
+        }
+        result.matches
+    }
+}
+
+impl QueryMode for QueryOne {
+    type Output = Option<DomNodeRepr>;
+
+    fn resolve(mut result: QueryResult) -> Self::Output {
+        match result.matches.len() {
+            0 => None,
+            1 => Some(result.matches.remove(0)),
+            n => result.fail(&format!("found {n} nodes")),
+        }
+    }
+}
+
+impl QueryMode for QueryAll {
+    type Output = Vec<DomNodeRepr>;
+
+    fn resolve(result: QueryResult) -> Self::Output {
+        result.matches
+    }
+}
+
+fn accessible_name(node: &DomNodeRepr, labels: &[DomNodeRepr], buffer: &Buffer) -> String {
+    if let Some(id) = node.id() {
+        let label_text: Vec<_> = labels
+            .iter()
+            .filter(|label| label.label_for().as_ref() == Some(&id))
+            .map(|label| text_content(label, buffer))
+            // Hidden labels don't take up any space
+            .filter(|text| !text.is_empty())
+            .collect();
+        if !label_text.is_empty() {
+            return label_text.join(" ");
+        }
+    }
+    text_content(node, buffer)
+}
+
+fn text_content(node: &DomNodeRepr, buffer: &Buffer) -> String {
+    normalize_text(&buffer.slice(node.rect()).terminal_view())
+}
+
+fn normalize_text(text: &str) -> String {
+    // Box drawing characters are used for borders
+    let text: String = text
+        .chars()
+        .map(|c| {
+            if ('\u{2500}'..='\u{257F}').contains(&c) {
+                ' '
+            } else {
+                c
+            }
+        })
+        .collect();
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
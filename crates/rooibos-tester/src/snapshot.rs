@@ -0,0 +1,318 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use unicode_width::UnicodeWidthStr;
+
+use crate::TerminalView;
+
+// Maximum number of mismatched cells to list below the side-by-side diff
+const MAX_LISTED_CELLS: usize = 20;
+
+/// Asserts that a [`Buffer`] matches the snapshot with the given name, including the styles of
+/// each cell. Pass a [`Rect`] to only snapshot part of the buffer.
+///
+/// Snapshots are stored in a `snapshots` folder next to the test file using the format from
+/// [`TerminalView::styled_view`]. Missing snapshots are written automatically unless running in
+/// CI. Set `INSTA_UPDATE=always` to overwrite snapshots that don't match.
+///
+/// ```ignore
+/// assert_buffer_snapshot!("counter", harness.buffer().await);
+/// assert_buffer_snapshot!("counter_title", harness.buffer().await, Rect::new(0, 0, 20, 1));
+/// ```
+#[macro_export]
+macro_rules! assert_buffer_snapshot {
+    ($name:expr, $buffer:expr $(,)?) => {
+        $crate::__assert_buffer_snapshot($name, &$buffer, env!("CARGO_MANIFEST_DIR"), file!())
+    };
+    ($name:expr, $buffer:expr, $area:expr $(,)?) => {
+        $crate::__assert_buffer_snapshot(
+            $name,
+            &$crate::TerminalView::slice(&$buffer, $area),
+            env!("CARGO_MANIFEST_DIR"),
+            file!(),
+        )
+    };
+}
+
+/// Asserts that two buffers have the same contents and styles. A side-by-side diff is printed if
+/// they don't.
+#[macro_export]
+macro_rules! assert_buffer_eq {
+    ($actual:expr, $expected:expr $(,)?) => {
+        if let Some(diff) = $crate::buffer_diff(&$expected, &$actual) {
+            panic!("buffers are not equal\n{diff}");
+        }
+    };
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn __assert_buffer_snapshot(name: &str, buffer: &Buffer, manifest_dir: &str, file: &str) {
+    let path = snapshot_path(name, manifest_dir, file);
+    let actual = buffer.styled_view();
+    let update = env::var("INSTA_UPDATE").unwrap_or_default();
+
+    let Ok(expected) = fs::read_to_string(&path) else {
+        if update == "no" || env::var_os("CI").is_some() {
+            panic!("missing snapshot {}\n{actual}", path.display());
+        }
+        write_snapshot(&path, &actual);
+        return;
+    };
+    if expected == actual {
+        return;
+    }
+    if update == "always" {
+        write_snapshot(&path, &actual);
+        return;
+    }
+    let diff = match parse_styled_view(&expected) {
+        Some(expected) => buffer_diff(&expected, buffer).unwrap_or_default(),
+        // Fall back to showing both snapshots if the stored one was edited by hand
+        None => format!("expected:\n{expected}\nactual:\n{actual}"),
+    };
+    panic!("snapshot {} does not match\n{diff}", path.display());
+}
+
+fn snapshot_path(name: &str, manifest_dir: &str, file: &str) -> PathBuf {
+    // file!() is relative to the workspace root, which may be a parent of the crate's manifest
+    // dir
+    let manifest_dir = Path::new(manifest_dir);
+    let source = manifest_dir
+        .ancestors()
+        .map(|dir| dir.join(file))
+        .find(|path| path.exists())
+        .unwrap_or_else(|| manifest_dir.join(file));
+    source
+        .parent()
+        .unwrap_or(manifest_dir)
+        .join("snapshots")
+        .join(format!("{name}.buffer"))
+}
+
+fn write_snapshot(path: &Path, contents: &str) {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).expect("failed to create snapshot dir");
+    }
+    fs::write(path, contents).expect("failed to write snapshot");
+}
+
+// Cells hidden behind a wide character aren't printed so the columns stay aligned
+pub(crate) fn visible_cells(buffer: &Buffer, row: u16) -> impl Iterator<Item = (u16, &Cell)> {
+    let mut hidden = 0;
+    (0..buffer.area.width).filter_map(move |col| {
+        let cell = &buffer[(buffer.area.x + col, buffer.area.y + row)];
+        if hidden > 0 {
+            hidden -= 1;
+            return None;
+        }
+        hidden = cell.symbol().width().saturating_sub(1);
+        Some((col, cell))
+    })
+}
+
+pub(crate) fn styled_view(buffer: &Buffer) -> String {
+    let mut view = String::new();
+    for row in 0..buffer.area.height {
+        view += "|";
+        for (_, cell) in visible_cells(buffer, row) {
+            view += cell.symbol();
+        }
+        view += "|\n";
+
+        // Consecutive cells with the same style are grouped into a single annotation
+        let mut runs: Vec<(u16, u16, String)> = Vec::new();
+        for (col, cell) in visible_cells(buffer, row) {
+            let width = cell.symbol().width().max(1) as u16;
+            let spec = style_spec(cell.style());
+            match runs.last_mut() {
+                Some((start, len, last)) if *last == spec && *start + *len == col => {
+                    *len += width;
+                }
+                _ => runs.push((col, width, spec)),
+            }
+        }
+        for (start, len, spec) in runs.into_iter().filter(|(_, _, s)| !s.is_empty()) {
+            writeln!(
+                view,
+                " {}{} {spec}",
+                " ".repeat(start as usize),
+                "^".repeat(len as usize)
+            )
+            .unwrap();
+        }
+    }
+    view
+}
+
+fn style_spec(style: Style) -> String {
+    let mut spec = Vec::new();
+    let mut add_color = |key: &str, color: Option<Color>| {
+        if let Some(color) = color.filter(|c| *c != Color::Reset) {
+            spec.push(format!("{key}={color}"));
+        }
+    };
+    add_color("fg", style.fg);
+    add_color("bg", style.bg);
+    add_color("ul", style.underline_color);
+    if !style.add_modifier.is_empty() {
+        let names: Vec<_> = style.add_modifier.iter_names().map(|(n, _)| n).collect();
+        spec.push(format!("mod={}", names.join("|")));
+    }
+    spec.join(" ")
+}
+
+fn parse_style_spec(spec: &str) -> Option<Style> {
+    let mut style = Style::default();
+    for part in spec.split_whitespace() {
+        let (key, value) = part.split_once('=')?;
+        match key {
+            "fg" => style.fg = Some(value.parse().ok()?),
+            "bg" => style.bg = Some(value.parse().ok()?),
+            "ul" => style.underline_color = Some(value.parse().ok()?),
+            "mod" => {
+                for name in value.split('|') {
+                    style.add_modifier |= Modifier::from_name(name)?;
+                }
+            }
+            _ => return None,
+        }
+    }
+    Some(style)
+}
+
+/// Parses the output of [`TerminalView::styled_view`] back into a [`Buffer`].
+pub fn parse_styled_view(view: &str) -> Option<Buffer> {
+    let mut rows: Vec<(&str, Vec<(u16, u16, Style)>)> = Vec::new();
+    for line in view.lines() {
+        if let Some(row) = line.strip_prefix('|') {
+            rows.push((row.strip_suffix('|')?, Vec::new()));
+        } else if let Some(annotation) = line.strip_prefix(' ') {
+            let start = annotation.len() - annotation.trim_start_matches(' ').len();
+            let annotation = &annotation[start..];
+            let len = annotation.len() - annotation.trim_start_matches('^').len();
+            let style = parse_style_spec(&annotation[len..])?;
+            rows.last_mut()?.1.push((start as u16, len as u16, style));
+        } else if !line.is_empty() {
+            return None;
+        }
+    }
+
+    let width = rows.iter().map(|(row, _)| row.width()).max().unwrap_or(0) as u16;
+    let mut buffer = Buffer::empty(Rect::new(0, 0, width, rows.len() as u16));
+    for (y, (row, styles)) in rows.into_iter().enumerate() {
+        let y = y as u16;
+        let mut x = 0;
+        for char in row.chars() {
+            let char_width = char.to_string().width() as u16;
+            if char_width == 0 && x > 0 {
+                // Zero-width characters are combined with the previous cell
+                let cell = &mut buffer[(x - 1, y)];
+                let symbol = format!("{}{char}", cell.symbol());
+                cell.set_symbol(&symbol);
+                continue;
+            }
+            buffer[(x, y)].set_char(char);
+            x += char_width.max(1);
+        }
+        for (start, len, style) in styles {
+            for x in start..(start + len).min(width) {
+                buffer[(x, y)].set_style(style);
+            }
+        }
+    }
+    Some(buffer)
+}
+
+/// Renders a side-by-side diff of two buffers with their styles applied. Returns `None` if the
+/// buffers are equal.
+pub fn buffer_diff(expected: &Buffer, actual: &Buffer) -> Option<String> {
+    if expected == actual {
+        return None;
+    }
+    let expected_width = expected.area.width as usize;
+    let height = expected.area.height.max(actual.area.height);
+
+    let mut diff = String::new();
+    writeln!(
+        diff,
+        "  {:expected_width$} │ actual",
+        "expected",
+        expected_width = expected_width
+    )
+    .unwrap();
+
+    let mut mismatches = Vec::new();
+    for row in 0..height {
+        let width = expected.area.width.max(actual.area.width);
+        let mut changed = false;
+        for col in 0..width {
+            let expected_cell = expected.cell((col, row));
+            let actual_cell = actual.cell((col, row));
+            if expected_cell != actual_cell {
+                changed = true;
+                mismatches.push((col, row, expected_cell.cloned(), actual_cell.cloned()));
+            }
+        }
+        let marker = if changed { "\x1b[31m>\x1b[0m " } else { "  " };
+        writeln!(
+            diff,
+            "{marker}{} │ {}",
+            styled_row(expected, row, expected_width),
+            styled_row(actual, row, actual.area.width as usize)
+        )
+        .unwrap();
+    }
+
+    writeln!(diff).unwrap();
+    for (col, row, expected, actual) in mismatches.iter().take(MAX_LISTED_CELLS) {
+        writeln!(
+            diff,
+            "({col}, {row}): expected {}, got {}",
+            describe_cell(expected.as_ref()),
+            describe_cell(actual.as_ref())
+        )
+        .unwrap();
+    }
+    if mismatches.len() > MAX_LISTED_CELLS {
+        writeln!(diff, "... and {} more", mismatches.len() - MAX_LISTED_CELLS).unwrap();
+    }
+    Some(diff)
+}
+
+fn styled_row(buffer: &Buffer, row: u16, width: usize) -> String {
+    if row >= buffer.area.height {
+        return " ".repeat(width);
+    }
+    let mut line = String::new();
+    for (_, cell) in visible_cells(buffer, row) {
+        let style: anstyle::Style = rooibos_theme::Style::from(cell.style()).into();
+        write!(
+            line,
+            "{}{}{}",
+            style.render(),
+            cell.symbol(),
+            style.render_reset()
+        )
+        .unwrap();
+    }
+    line
+}
+
+fn describe_cell(cell: Option<&Cell>) -> String {
+    match cell {
+        Some(cell) => {
+            let spec = style_spec(cell.style());
+            if spec.is_empty() {
+                format!("{:?}", cell.symbol())
+            } else {
+                format!("{:?} {spec}", cell.symbol())
+            }
+        }
+        None => "nothing".to_string(),
+    }
+}
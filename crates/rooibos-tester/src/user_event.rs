@@ -0,0 +1,96 @@
+use std::time::Duration;
+
+use ratatui::layout::Position;
+use rooibos_dom::{
+    DomNodeRepr, Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+    ScrollDirection, clock, render_terminal,
+};
+
+use crate::TestHarness;
+
+// Gives debounced handlers, like the ones for hover and resize events, time to run after each
+// user event
+const SETTLE_TIME: Duration = Duration::from_millis(50);
+
+/// Helpers that simulate a user interacting with the app. Events are sent through the runtime
+/// like real input, so they're filtered and dispatched the same way.
+///
+/// Each helper waits for the app to handle the events before returning.
+impl<P> TestHarness<P>
+where
+    P: 'static,
+{
+    /// Presses and releases the left mouse button over the node.
+    pub async fn click(&mut self, node: &DomNodeRepr) {
+        let target = target_position(node);
+        for kind in [
+            MouseEventKind::Down(MouseButton::Left),
+            MouseEventKind::Up(MouseButton::Left),
+        ] {
+            self.send_mouse(kind, target);
+        }
+        self.settle().await;
+    }
+
+    /// Clicks the node to focus it and then types the text one character at a time.
+    pub async fn type_text(&mut self, node: &DomNodeRepr, text: impl AsRef<str>) {
+        self.click(node).await;
+        self.send_text(text.as_ref());
+        self.settle().await;
+    }
+
+    /// Moves focus to the next focusable node.
+    pub async fn tab(&mut self) {
+        self.send_key(KeyCode::Tab);
+        self.settle().await;
+    }
+
+    /// Moves focus to the previous focusable node.
+    pub async fn tab_back(&mut self) {
+        self.send_event(Event::Key(
+            KeyEvent::new(KeyCode::Tab).modifiers(KeyModifiers::SHIFT),
+        ));
+        self.settle().await;
+    }
+
+    /// Moves the mouse over the node.
+    pub async fn hover(&mut self, node: &DomNodeRepr) {
+        self.send_mouse(MouseEventKind::Moved, target_position(node));
+        self.settle().await;
+    }
+
+    /// Scrolls the mouse wheel over the node `amount` times.
+    pub async fn scroll(&mut self, node: &DomNodeRepr, direction: ScrollDirection, amount: u16) {
+        let target = target_position(node);
+        for _ in 0..amount {
+            self.send_mouse(MouseEventKind::Scroll(direction), target);
+        }
+        self.settle().await;
+    }
+
+    fn send_mouse(&self, kind: MouseEventKind, position: Position) {
+        self.send_event(Event::Mouse(MouseEvent {
+            kind,
+            column: position.x,
+            row: position.y,
+            modifiers: KeyModifiers::empty(),
+        }));
+    }
+
+    async fn settle(&mut self) {
+        if clock::is_virtual() {
+            self.advance(SETTLE_TIME).await;
+        } else {
+            self.run_for(SETTLE_TIME).await;
+        }
+        // Ensure the screen is updated in case we're still in a debouncer timeout
+        render_terminal(&mut self.terminal).await.unwrap();
+    }
+}
+
+// Mouse events target the center of the node. The top left corner is often a border, which
+// doesn't always belong to the node the user means to interact with.
+fn target_position(node: &DomNodeRepr) -> Position {
+    let rect = node.rect();
+    Position::new(rect.x + rect.width / 2, rect.y + rect.height / 2)
+}
@@ -183,7 +183,8 @@ impl From<Style> for anstyle::Style {
         let style = anstyle::Style::new()
             .fg_color(val.fg.and_then(Into::into))
             .bg_color(val.bg.and_then(Into::into))
-            .underline_color(val.underline_color.and_then(Into::into));
+            .underline_color(val.underline_color.and_then(Into::into))
+            .effects(val.add_modifier.into());
 
         let profile = TermProfile::current();
         profile.adapt_style(style)
@@ -0,0 +1,67 @@
+use std::process::ExitCode;
+
+use rooibos::components::{Checkbox, RadioGroup, Toggle};
+use rooibos::reactive::dom::layout::{Borders, borders, full, gap, height, padding, width};
+use rooibos::reactive::dom::{Render, UpdateLayoutProps, line};
+use rooibos::reactive::graph::signal::RwSignal;
+use rooibos::reactive::graph::traits::Get;
+use rooibos::reactive::{col, wgt};
+use rooibos::runtime::error::RuntimeError;
+use rooibos::runtime::{Runtime, max_viewport_height, max_viewport_width};
+use rooibos::terminal::DefaultBackend;
+
+type Result = std::result::Result<ExitCode, RuntimeError>;
+
+#[rooibos::main]
+async fn main() -> Result {
+    Runtime::initialize(DefaultBackend::auto().await?)
+        .run(|_| app())
+        .await
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Size {
+    Small,
+    Medium,
+    Large,
+}
+
+fn app() -> impl Render {
+    max_viewport_width(60).unwrap();
+    max_viewport_height(20).unwrap();
+
+    let subscribe = RwSignal::new(false);
+    let notifications = RwSignal::new(true);
+    let size = RwSignal::new(Some(Size::Medium));
+
+    col![
+        style(
+            width(full()),
+            height(full()),
+            padding(1),
+            gap(1),
+            borders(Borders::all())
+        ),
+        Checkbox::new()
+            .checked(subscribe)
+            .render(line!("Subscribe to the newsletter")),
+        Toggle::new()
+            .on(notifications)
+            .render(line!("Notifications")),
+        RadioGroup::new()
+            .value(size)
+            .padding_left(2)
+            .render(vec![Size::Small, Size::Medium, Size::Large], |size| {
+                format!("{size:?}")
+            }),
+        wgt!(line!(format!(
+            "subscribe={} notifications={} size={:?}",
+            subscribe.get(),
+            notifications.get(),
+            size.get()
+        )))
+    ]
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,42 @@
+use rooibos::reactive::KeyCode;
+use rooibos::reactive::dom::root;
+use rooibos::tester::TestHarness;
+
+use crate::app;
+
+#[rooibos::test]
+async fn test_radio_group_selection() {
+    let mut harness = TestHarness::new(60, 20).await;
+    harness.mount((), |_| app()).await;
+
+    // Layout props are applied to the group: border + padding + padding_left(2)
+    let small = harness.get_position_of_text("( ) Small").await;
+    assert_eq!(small.x, 4);
+    assert!(harness.terminal_view().await.contains("(•) Medium"));
+
+    let large = harness.get_by_text(&root(), "Large").await;
+    harness.click(&large).await;
+    harness
+        .wait_for(async |harness, _| {
+            let view = harness.terminal_view().await;
+            view.contains("(•) Large") && view.contains("size=Some(Large)")
+        })
+        .await
+        .unwrap();
+    assert!(harness.terminal_view().await.contains("( ) Medium"));
+
+    // Focus moves through the options with the arrow keys and space selects one
+    harness.send_key(KeyCode::Up);
+    harness.send_key(KeyCode::Up);
+    harness.send_key(KeyCode::Char(' '));
+    harness
+        .wait_for(async |harness, _| {
+            let view = harness.terminal_view().await;
+            view.contains("(•) Small") && view.contains("size=Some(Small)")
+        })
+        .await
+        .unwrap();
+    assert!(harness.terminal_view().await.contains("( ) Large"));
+
+    harness.exit().await;
+}
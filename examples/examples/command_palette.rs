@@ -0,0 +1,77 @@
+use std::process::ExitCode;
+
+use rooibos::keybind::{
+    Bind, CommandFilter, CommandHandler, CommandPalette, Commands, KeyActionMap, extract, keys,
+    on_command,
+};
+use rooibos::reactive::dom::layout::{Borders, chars, full, height, width};
+use rooibos::reactive::dom::{Render, UpdateLayoutProps, line};
+use rooibos::reactive::graph::signal::{RwSignal, signal};
+use rooibos::reactive::graph::traits::{Get, Set, Update};
+use rooibos::reactive::{col, wgt};
+use rooibos::runtime::error::RuntimeError;
+use rooibos::runtime::{Runtime, RuntimeSettings};
+use rooibos::terminal::DefaultBackend;
+use rooibos::theme::Stylize;
+
+type Result = std::result::Result<ExitCode, RuntimeError>;
+
+#[rooibos::main]
+async fn main() -> Result {
+    let mut cmd_handler = CommandHandler::<AppAction>::new();
+    cmd_handler.generate_commands();
+
+    let runtime = Runtime::initialize_with(
+        RuntimeSettings::default().handle_commands(cmd_handler),
+        DefaultBackend::auto().await?,
+    );
+    runtime.run(|_| app()).await
+}
+
+fn app() -> impl Render {
+    let (count, set_count) = signal(0);
+    let palette_open = RwSignal::new(false);
+
+    on_command(extract!(val, AppAction::Count { val }), move |val| {
+        set_count.update(|c| *c += val);
+    });
+    on_command(extract!(AppAction::Reset), move |_| set_count.set(0));
+
+    col![
+        style(width(full()), height(full())),
+        wgt!(line!("count: ".bold(), count.get().cyan())),
+        wgt!(line!(
+            "Press Ctrl+P to open the command palette".dark_gray()
+        )),
+        CommandPalette::<AppAction>::new()
+            .width(chars(50))
+            .borders(Borders::all().title("Commands"))
+            .render(palette_open)
+    ]
+    .on_key_down(
+        [
+            KeyActionMap::action(
+                keys::combine([keys::CTRL, keys::UP]),
+                AppAction::Count { val: 1 },
+            ),
+            KeyActionMap::action(
+                keys::combine([keys::CTRL, keys::DOWN]),
+                AppAction::Count { val: -1 },
+            ),
+            KeyActionMap::action(keys::combine([keys::CTRL, 'r'.into()]), AppAction::Reset),
+            KeyActionMap::handler(keys::combine([keys::CTRL, 'p'.into()]), move |_, _| {
+                palette_open.set(true)
+            }),
+        ]
+        .bind(),
+    )
+}
+
+#[derive(clap::Parser, Commands, Clone, Debug, PartialEq, Eq)]
+pub enum AppAction {
+    Count {
+        #[arg(allow_hyphen_values(true))]
+        val: i32,
+    },
+    Reset,
+}
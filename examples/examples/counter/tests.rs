@@ -1,5 +1,7 @@
 use rooibos::reactive::KeyCode;
-use rooibos::tester::TestHarness;
+use rooibos::runtime::recording::{InputEvent, RecordedEvent, Recording};
+use rooibos::tester::{Screenshot, TestHarness, assert_buffer_snapshot};
+use rooibos::tui::layout::Rect;
 
 use crate::app;
 
@@ -26,6 +28,66 @@ async fn test_counter() {
         .wait_for(async |harness, _| harness.terminal_view().await.contains("count: 1"))
         .await
         .unwrap();
+    assert_buffer_snapshot!(
+        "counter_styled",
+        harness.buffer().await,
+        Rect::new(0, 0, 20, 1)
+    );
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_counter_replay() {
+    let mut recording = Recording::new(20, 10);
+    for time_ms in [0, 250, 900] {
+        recording.events.push(RecordedEvent {
+            time_ms,
+            event: InputEvent::Key(KeyCode::Enter.into()),
+        });
+    }
+    recording.events.push(RecordedEvent {
+        time_ms: 1000,
+        event: InputEvent::Resize { cols: 30, rows: 5 },
+    });
+    let path = std::env::temp_dir().join(format!("counter-{}.jsonl", std::process::id()));
+    recording.save(&path).unwrap();
+    let recording = Recording::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let mut harness = TestHarness::new_with_virtual_clock(recording.width, recording.height).await;
+    harness.mount((), |_| app()).await;
+    let tick_events = harness.replay(&recording).await;
+
+    assert!(!tick_events.exited());
+    assert!(harness.terminal_view().await.contains("count: 3"));
+    assert_eq!(harness.buffer().await.area, Rect::new(0, 0, 30, 5));
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_counter_screenshot() {
+    let mut harness = TestHarness::new(20, 10).await;
+    harness.mount((), |_| app()).await;
+    let buffer = harness.buffer().await;
+    let screenshot = Screenshot::new(&buffer).title("counter");
+
+    let svg = screenshot.to_svg();
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.contains("<title>counter</title>"));
+    // The label and the count have different styles, so they're drawn separately
+    assert!(svg.contains(r#"font-weight="bold">count: </text>"#));
+    assert!(svg.contains(">0</text>"));
+
+    let html = screenshot.to_html();
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains(r#"font-weight:bold">count: </span>"#));
+
+    let path = std::env::temp_dir().join(format!("counter-{}.svg", std::process::id()));
+    screenshot.save(&path).unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), svg);
+    std::fs::remove_file(&path).unwrap();
 
     harness.exit().await;
 }
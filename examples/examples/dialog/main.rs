@@ -0,0 +1,94 @@
+use std::process::ExitCode;
+
+use rooibos::components::{Button, Dialog, DialogManager, Dialogs};
+use rooibos::reactive::dom::layout::{Borders, borders, full, gap, height, padding, width};
+use rooibos::reactive::dom::{Render, after_render_async, line, text};
+use rooibos::reactive::graph::signal::signal;
+use rooibos::reactive::graph::traits::{Get, Set};
+use rooibos::reactive::{col, row, wgt};
+use rooibos::runtime::error::RuntimeError;
+use rooibos::runtime::{Runtime, max_viewport_height, max_viewport_width};
+use rooibos::terminal::DefaultBackend;
+
+type Result = std::result::Result<ExitCode, RuntimeError>;
+
+#[rooibos::main]
+async fn main() -> Result {
+    Runtime::initialize(DefaultBackend::auto().await?)
+        .run(|_| app())
+        .await
+}
+
+fn app() -> impl Render {
+    max_viewport_width(80).unwrap();
+    max_viewport_height(20).unwrap();
+
+    // Dialogs need to be created first so the context is available to the rest of the app
+    let dialogs = Dialogs::new();
+    let manager = DialogManager::new();
+    let (result, set_result) = signal("nothing yet".to_string());
+
+    let nested = manager.create_ref();
+
+    col![
+        style(
+            width(full()),
+            height(full()),
+            padding(1),
+            gap(1),
+            borders(Borders::all())
+        ),
+        row![
+            style(gap(1)),
+            Button::new()
+                .on_click(move || {
+                    let answer = manager.confirm("Confirm", "Delete all files?");
+                    after_render_async(async move {
+                        set_result.set(format!("confirmed: {}", answer.await));
+                    });
+                })
+                .render(text!("Confirm")),
+            Button::new()
+                .on_click(move || {
+                    let answer = manager.prompt("Prompt", "What's your name?", "");
+                    after_render_async(async move {
+                        set_result.set(format!("name: {:?}", answer.await));
+                    });
+                })
+                .render(text!("Prompt")),
+            Button::new()
+                .on_click(move || {
+                    let done = manager.alert("Alert", "Something happened");
+                    after_render_async(async move {
+                        done.await;
+                        set_result.set("alert closed".to_string());
+                    });
+                })
+                .render(text!("Alert")),
+            Button::new()
+                .on_click(move || nested.open(nested_dialog(manager)))
+                .render(text!("Stacked")),
+        ],
+        wgt!(line!("result: ", result.get())),
+        dialogs.render()
+    ]
+}
+
+fn nested_dialog(manager: DialogManager) -> Dialog {
+    Dialog::new(move || {
+        col![
+            style(gap(1)),
+            wgt!(line!("Dialogs can be stacked on top of each other")),
+            Button::new()
+                .on_click(move || {
+                    let _ = manager.alert("Stacked", "Press Esc to go back");
+                })
+                .render(text!("Open another")),
+        ]
+    })
+    .title(line!("Stacked"))
+    .width(50)
+}
+
+#[cfg(test)]
+mod tests;
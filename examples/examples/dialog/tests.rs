@@ -0,0 +1,71 @@
+use rooibos::reactive::KeyCode;
+use rooibos::reactive::dom::root;
+use rooibos::tester::TestHarness;
+
+use crate::app;
+
+#[rooibos::test]
+async fn test_alert_dismissed_with_escape() {
+    let mut harness = TestHarness::new(80, 20).await;
+    harness.mount((), |_| app()).await;
+
+    let alert = harness.get_by_text(&root(), "Alert").await;
+    harness.click(&alert).await;
+    harness
+        .wait_for(async |harness, _| harness.terminal_view().await.contains("Something happened"))
+        .await
+        .unwrap();
+
+    harness.send_key(KeyCode::Esc);
+    harness
+        .wait_for(async |harness, _| {
+            let view = harness.terminal_view().await;
+            !view.contains("Something happened") && view.contains("result: alert closed")
+        })
+        .await
+        .unwrap();
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_confirm() {
+    let mut harness = TestHarness::new(80, 20).await;
+    harness.mount((), |_| app()).await;
+
+    let confirm = harness.get_by_text(&root(), "Confirm").await;
+    harness.click(&confirm).await;
+    harness
+        .wait_for(async |harness, _| harness.terminal_view().await.contains("Delete all files?"))
+        .await
+        .unwrap();
+
+    // Focus starts on the first button inside the dialog
+    harness.send_key(KeyCode::Enter);
+    harness
+        .wait_for(async |harness, _| {
+            let view = harness.terminal_view().await;
+            !view.contains("Delete all files?") && view.contains("result: confirmed: true")
+        })
+        .await
+        .unwrap();
+
+    // Dismissing the dialog resolves to false
+    harness.click(&confirm).await;
+    harness
+        .wait_for(async |harness, _| harness.terminal_view().await.contains("Delete all files?"))
+        .await
+        .unwrap();
+    harness.send_key(KeyCode::Esc);
+    harness
+        .wait_for(async |harness, _| {
+            harness
+                .terminal_view()
+                .await
+                .contains("result: confirmed: false")
+        })
+        .await
+        .unwrap();
+
+    harness.exit().await;
+}
@@ -0,0 +1,82 @@
+use std::process::ExitCode;
+use std::time::Duration;
+
+use rooibos::components::{Button, Form, Input, required};
+use rooibos::reactive::dom::layout::{Borders, borders, full, gap, height, padding, width};
+use rooibos::reactive::dom::{Render, UpdateLayoutProps, line, text};
+use rooibos::reactive::graph::signal::signal;
+use rooibos::reactive::graph::traits::{Get, Set};
+use rooibos::reactive::{col, error_map, wgt};
+use rooibos::runtime::error::RuntimeError;
+use rooibos::runtime::{Runtime, max_viewport_height, max_viewport_width};
+use rooibos::terminal::DefaultBackend;
+use rooibos::tui::text::Text;
+
+type Result = std::result::Result<ExitCode, RuntimeError>;
+
+#[rooibos::main]
+async fn main() -> Result {
+    Runtime::initialize(DefaultBackend::auto().await?)
+        .run(|_| app())
+        .await
+}
+
+fn app() -> impl Render {
+    max_viewport_width(60).unwrap();
+    max_viewport_height(24).unwrap();
+
+    let (submitted, set_submitted) = signal(String::new());
+    let form = Form::new().on_submit(move |values| {
+        set_submitted.set(format!(
+            "saved {} <{}>",
+            values.get("name").unwrap_or_default(),
+            values.get("email").unwrap_or_default()
+        ));
+    });
+    let errors = form.errors();
+
+    col![
+        style(
+            width(full()),
+            height(full()),
+            padding(1),
+            gap(1),
+            borders(Borders::all())
+        ),
+        form.field("name")
+            .label("Name")
+            .input(Input::default().placeholder_text("Jane Doe"))
+            .validate(required("Name is required"))
+            .render(),
+        form.field("email")
+            .label("Email")
+            .input(Input::default().placeholder_text("jane@example.com"))
+            .validate(required("Email is required"))
+            .validate(|value| {
+                if value.contains('@') {
+                    Ok(())
+                } else {
+                    Err("Email must contain an @".to_string())
+                }
+            })
+            .validate_async(|value| async move {
+                // Simulate checking the address against a remote service
+                tokio::time::sleep(Duration::from_millis(300)).await;
+                if value.ends_with("@example.org") {
+                    Err("That address is already taken".to_string())
+                } else {
+                    Ok(())
+                }
+            })
+            .render(),
+        Button::new()
+            .width(10)
+            .on_click(move || form.submit())
+            .render(text!("Save")),
+        wgt!(Text::from(error_map(&errors, |_, e| line!(e.to_string())))),
+        wgt!(line!(submitted.get()))
+    ]
+}
+
+#[cfg(test)]
+mod tests;
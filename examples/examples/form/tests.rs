@@ -0,0 +1,244 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use rooibos::components::Form;
+use rooibos::reactive::KeyCode;
+use rooibos::reactive::col;
+use rooibos::reactive::dom::Render;
+use rooibos::reactive::dom::widgets::Role;
+use rooibos::reactive::graph::traits::{GetUntracked, WithUntracked};
+use rooibos::tester::TestHarness;
+use tokio::sync::oneshot;
+
+use crate::app;
+
+#[rooibos::test]
+async fn test_submit() {
+    let mut harness = TestHarness::new(60, 24).await;
+    harness.mount((), |_| app()).await;
+
+    let name = harness.get_by_label_text("Name").await;
+    harness.type_text(&name, "Jane").await;
+    let email = harness.get_by_label_text("Email").await;
+    harness.type_text(&email, "jane@example.com").await;
+
+    let save = harness.get_by_role(Role::Button).with_name("Save").await;
+    harness.click(&save).await;
+    harness
+        .wait_for(async |harness, _| {
+            harness
+                .terminal_view()
+                .await
+                .contains("saved Jane <jane@example.com>")
+        })
+        .await
+        .unwrap();
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_queries() {
+    let mut harness = TestHarness::new(60, 24).await;
+    harness.mount((), |_| app()).await;
+
+    let inputs = harness.get_all_by_role(Role::TextInput).await;
+    assert_eq!(inputs.len(), 2);
+    let email = harness
+        .get_by_role(Role::TextInput)
+        .with_name("Email")
+        .await;
+    assert_eq!(email.rect(), inputs[1].rect());
+    assert!(
+        harness
+            .query_by_role(Role::Button)
+            .with_name("Cancel")
+            .await
+            .is_none()
+    );
+    assert!(harness.query_all_by_label_text("Phone").await.is_empty());
+
+    harness.tab().await;
+    assert!(email.is_focused());
+
+    let save = harness.get_by_role(Role::Button).await;
+    harness.click(&save).await;
+    harness
+        .wait_for(async |harness, _| harness.terminal_view().await.contains("Name is required"))
+        .await
+        .unwrap();
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_tab_back() {
+    let mut harness = TestHarness::new(60, 24).await;
+    harness.mount((), |_| app()).await;
+
+    let name = harness.get_by_label_text("Name").await;
+    let email = harness.get_by_label_text("Email").await;
+    assert!(name.is_focused());
+
+    harness.tab().await;
+    assert!(email.is_focused());
+    harness.tab_back().await;
+    assert!(name.is_focused());
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_sync_validation() {
+    let mut harness = TestHarness::new(60, 24).await;
+    harness.mount((), |_| app()).await;
+
+    let email = harness.get_by_label_text("Email").await;
+    harness.type_text(&email, "jane").await;
+    // Fields are validated when they lose focus
+    harness.tab().await;
+    // The error is shown beneath the field and in the summary from the form's errors
+    harness
+        .wait_for(async |harness, _| {
+            harness
+                .terminal_view()
+                .await
+                .contains("email: Email must contain an @")
+        })
+        .await
+        .unwrap();
+
+    // Once an error is shown, the field is validated again whenever it changes
+    harness.type_text(&email, "@example.com").await;
+    harness
+        .wait_for(async |harness, _| {
+            !harness
+                .terminal_view()
+                .await
+                .contains("Email must contain an @")
+        })
+        .await
+        .unwrap();
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_async_validation() {
+    let mut harness = TestHarness::new(60, 24).await;
+    harness.mount((), |_| app()).await;
+
+    let name = harness.get_by_label_text("Name").await;
+    harness.type_text(&name, "Jane").await;
+    let email = harness.get_by_label_text("Email").await;
+    harness.type_text(&email, "jane@example.org").await;
+
+    let save = harness.get_by_role(Role::Button).with_name("Save").await;
+    harness.click(&save).await;
+    harness
+        .wait_for(async |harness, _| {
+            harness
+                .terminal_view()
+                .await
+                .contains("email: That address is already taken")
+        })
+        .await
+        .unwrap();
+    assert!(!harness.terminal_view().await.contains("saved"));
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_submit_focuses_first_invalid_field() {
+    let mut harness = TestHarness::new(60, 24).await;
+    harness.mount((), |_| app()).await;
+
+    let name = harness.get_by_label_text("Name").await;
+    harness.type_text(&name, "Jane").await;
+
+    let save = harness.get_by_role(Role::Button).with_name("Save").await;
+    harness.click(&save).await;
+    let email = harness.get_by_label_text("Email").await;
+    harness
+        .wait_for(async |_, _| email.is_focused())
+        .await
+        .unwrap();
+
+    let view = harness.terminal_view().await;
+    assert!(view.contains("email: Email is required"));
+    assert!(!view.contains("Name is required"));
+
+    harness.exit().await;
+}
+
+fn slow_form(
+    form: Form,
+    pending: Arc<Mutex<Option<oneshot::Receiver<()>>>>,
+    finished: Arc<AtomicBool>,
+) -> impl Render {
+    col![
+        form.field("user")
+            .label("User")
+            .validate_async(move |value| {
+                let pending = pending.clone();
+                let finished = finished.clone();
+                async move {
+                    if value != "slow" {
+                        return Ok(());
+                    }
+                    let receiver = pending.lock().unwrap().take();
+                    if let Some(receiver) = receiver {
+                        let _ = receiver.await;
+                    }
+                    finished.store(true, Ordering::SeqCst);
+                    Err("User is taken".to_string())
+                }
+            })
+            .render(),
+        form.field("other").label("Other").render()
+    ]
+}
+
+#[rooibos::test]
+async fn test_stale_async_validation() {
+    let mut harness = TestHarness::new(60, 24).await;
+    let form = Form::new();
+    let (sender, receiver) = oneshot::channel();
+    let pending = Arc::new(Mutex::new(Some(receiver)));
+    let finished = Arc::new(AtomicBool::new(false));
+    harness
+        .mount((), {
+            let finished = finished.clone();
+            move |_| slow_form(form, pending, finished)
+        })
+        .await;
+
+    // The first validation waits until it's released below
+    let user = harness.get_by_label_text("User").await;
+    harness.type_text(&user, "slow").await;
+    harness.tab().await;
+
+    // The second validation passes right away
+    harness.click(&user).await;
+    for _ in 0.."slow".len() {
+        harness.send_key(KeyCode::Backspace);
+    }
+    harness.type_text(&user, "ok").await;
+    harness.tab().await;
+
+    // The first result arrives last and shouldn't replace the newer one
+    sender.send(()).unwrap();
+    harness
+        .wait_for(async |_, _| finished.load(Ordering::SeqCst))
+        .await
+        .unwrap();
+    harness.run_until_idle().await;
+
+    assert_eq!(form.values().get("user"), Some("ok"));
+    assert_eq!(form.field_error("user").get_untracked(), None);
+    assert!(form.errors().with_untracked(|errors| errors.is_empty()));
+    assert!(!harness.terminal_view().await.contains("User is taken"));
+
+    harness.exit().await;
+}
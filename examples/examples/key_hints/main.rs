@@ -0,0 +1,69 @@
+use std::process::ExitCode;
+use std::time::Duration;
+
+use rooibos::keybind::{Bind, Commands, KeyActionMap, KeyHints, extract, keys, on_command};
+use rooibos::reactive::dom::layout::{Borders, full, height, width};
+use rooibos::reactive::dom::{Render, UpdateLayoutProps, line};
+use rooibos::reactive::graph::signal::signal;
+use rooibos::reactive::graph::traits::{Get, Set, Update};
+use rooibos::reactive::{col, wgt};
+use rooibos::runtime::Runtime;
+use rooibos::runtime::error::RuntimeError;
+use rooibos::terminal::DefaultBackend;
+use rooibos::theme::Stylize;
+
+type Result = std::result::Result<ExitCode, RuntimeError>;
+
+#[rooibos::main]
+async fn main() -> Result {
+    Runtime::initialize(DefaultBackend::auto().await?)
+        .run(|_| app())
+        .await
+}
+
+fn app() -> impl Render {
+    let (count, set_count) = signal(0);
+
+    on_command(extract!(val, AppAction::Add { val }), move |val| {
+        set_count.update(|c| *c += val);
+    });
+    on_command(extract!(AppAction::Reset), move |_| set_count.set(0));
+
+    col![
+        style(width(full()), height(full())),
+        wgt!(line!("count: ".bold(), count.get().cyan())),
+        wgt!(line!("Press c or cc to see the available keys".dark_gray())),
+        KeyHints::<AppAction>::new()
+            .delay(Duration::from_millis(300))
+            .action_label(|action| match action {
+                AppAction::Add { val } => format!("add {val}"),
+                AppAction::Reset => "reset".to_string(),
+            })
+            .width(full())
+            .borders(Borders::all())
+            .render()
+    ]
+    .on_key_down(
+        [
+            KeyActionMap::action("ci", AppAction::Add { val: 1 }),
+            KeyActionMap::action("cd", AppAction::Add { val: -1 }),
+            KeyActionMap::action("cr", AppAction::Reset),
+            KeyActionMap::action("cci", AppAction::Add { val: 10 }),
+            KeyActionMap::action("ccd", AppAction::Add { val: -10 }),
+            KeyActionMap::action(keys::combine([keys::CTRL, 'r'.into()]), AppAction::Reset),
+        ]
+        .bind(),
+    )
+}
+
+#[derive(clap::Parser, Commands, Clone, Debug, PartialEq, Eq)]
+pub enum AppAction {
+    Add {
+        #[arg(allow_hyphen_values(true))]
+        val: i32,
+    },
+    Reset,
+}
+
+#[cfg(test)]
+mod tests;
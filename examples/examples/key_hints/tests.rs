@@ -0,0 +1,63 @@
+use std::time::Duration;
+
+use rooibos::tester::TestHarness;
+
+use crate::app;
+
+// Leaves time for the render debouncer after each step
+const RENDER_TIME: Duration = Duration::from_millis(50);
+
+#[rooibos::test]
+async fn test_hints_shown_after_delay() {
+    let mut harness = TestHarness::new_with_virtual_clock(60, 20).await;
+    harness.mount((), |_| app()).await;
+    harness.run_until_idle().await;
+
+    harness.send_text("c");
+    harness.advance(Duration::from_millis(250)).await;
+    assert!(!harness.terminal_view().await.contains("→"));
+
+    harness.advance(RENDER_TIME + RENDER_TIME).await;
+    let view = harness.terminal_view().await;
+    assert!(view.contains("i → add 1"));
+    assert!(view.contains("r → reset"));
+    assert!(view.contains("c → +2 more"));
+
+    // The hints stay open and update as the sequence continues
+    harness.send_text("c");
+    harness.advance(RENDER_TIME).await;
+    let view = harness.terminal_view().await;
+    assert!(view.contains("i → add 10"));
+    assert!(!view.contains("r → reset"));
+
+    // Completing the sequence hides them
+    harness.send_text("i");
+    harness.advance(RENDER_TIME).await;
+    let view = harness.terminal_view().await;
+    assert!(view.contains("count: 10"));
+    assert!(!view.contains("→"));
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_delay_restarts_with_each_key() {
+    let mut harness = TestHarness::new_with_virtual_clock(60, 20).await;
+    harness.mount((), |_| app()).await;
+    harness.run_until_idle().await;
+
+    harness.send_text("c");
+    harness.advance(Duration::from_millis(200)).await;
+    harness.send_text("c");
+
+    // The timer from the first key is ignored
+    harness.advance(Duration::from_millis(200)).await;
+    assert!(!harness.terminal_view().await.contains("→"));
+
+    harness
+        .advance(Duration::from_millis(100) + RENDER_TIME)
+        .await;
+    assert!(harness.terminal_view().await.contains("d → add -10"));
+
+    harness.exit().await;
+}
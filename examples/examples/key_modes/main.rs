@@ -0,0 +1,81 @@
+use std::process::ExitCode;
+
+use rooibos::keybind::{
+    Bind, CommandBar, CommandFilter, CommandHandler, Commands, Emacs, KeyActionMap, Vim, extract,
+    keys, on_command, use_command_context,
+};
+use rooibos::reactive::dom::layout::{full, height, width};
+use rooibos::reactive::dom::{Render, UpdateLayoutProps, line};
+use rooibos::reactive::graph::signal::signal;
+use rooibos::reactive::graph::traits::{Get, Set, Update};
+use rooibos::reactive::{col, wgt};
+use rooibos::runtime::error::RuntimeError;
+use rooibos::runtime::{Runtime, RuntimeSettings};
+use rooibos::terminal::DefaultBackend;
+use rooibos::theme::Stylize;
+
+type Result = std::result::Result<ExitCode, RuntimeError>;
+
+#[rooibos::main]
+async fn main() -> Result {
+    // Pass --emacs to open the command bar with M-x instead of :
+    let mut cmd_handler = if std::env::args().any(|a| a == "--emacs") {
+        CommandHandler::<AppAction>::with_backend(Emacs)
+    } else {
+        CommandHandler::<AppAction>::with_backend(Vim)
+    };
+    cmd_handler.generate_commands();
+
+    let runtime = Runtime::initialize_with(
+        RuntimeSettings::default().handle_commands(cmd_handler),
+        DefaultBackend::auto().await?,
+    );
+    runtime.run(|_| app()).await
+}
+
+fn app() -> impl Render {
+    let (count, set_count) = signal(0);
+    let command_context = use_command_context::<AppAction>();
+    let initial_mode = command_context.mode().get();
+    let mode_indicator = command_context.mode_indicator();
+
+    on_command(extract!(val, AppAction::Count { val }), move |val| {
+        set_count.update(|c| *c += val);
+    });
+    on_command(extract!(AppAction::Reset), move |_| set_count.set(0));
+
+    col![
+        style(width(full()), height(full())),
+        wgt!(line!("count: ".bold(), count.get().cyan())),
+        wgt!(line!(
+            "Press c to enter count mode, then j/k to change the count".dark_gray()
+        )),
+        wgt!(
+            style(height(1)),
+            line!(mode_indicator.get().unwrap_or_default().yellow())
+        ),
+        CommandBar::<AppAction>::new().height(1).render()
+    ]
+    .on_key_down(
+        [
+            KeyActionMap::goto("c", "count"),
+            KeyActionMap::action("j", AppAction::Count { val: 1 }).in_mode("count"),
+            KeyActionMap::action("k", AppAction::Count { val: -1 }).in_mode("count"),
+            KeyActionMap::goto(keys::ESC, initial_mode).in_mode("count"),
+            KeyActionMap::action(keys::combine([keys::CTRL, 'r'.into()]), AppAction::Reset),
+        ]
+        .bind(),
+    )
+}
+
+#[derive(clap::Parser, Commands, Clone, Debug, PartialEq, Eq)]
+pub enum AppAction {
+    Count {
+        #[arg(allow_hyphen_values(true))]
+        val: i32,
+    },
+    Reset,
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,123 @@
+use rooibos::keybind::{CommandFilter, CommandHandler, Emacs, ModalBackend, Vim};
+use rooibos::reactive::{Event, KeyCode, KeyEvent, KeyModifiers};
+use rooibos::runtime::RuntimeSettings;
+use rooibos::tester::TestHarness;
+
+use crate::{AppAction, app};
+
+async fn harness_with_backend(backend: impl ModalBackend<AppAction>) -> TestHarness {
+    let mut cmd_handler = CommandHandler::<AppAction>::with_backend(backend);
+    cmd_handler.generate_commands();
+
+    let mut harness = TestHarness::new_with_settings(
+        RuntimeSettings::default().handle_commands(cmd_handler),
+        60,
+        10,
+    )
+    .await;
+    harness.mount((), |_| app()).await;
+    harness
+}
+
+fn send_modified_key(harness: &TestHarness, c: char, modifiers: KeyModifiers) {
+    harness.send_event(Event::Key(
+        KeyEvent::new(KeyCode::Char(c)).modifiers(modifiers),
+    ));
+}
+
+async fn wait_for_text(harness: &mut TestHarness, text: &str) {
+    harness
+        .wait_for(async |harness, _| harness.terminal_view().await.contains(text))
+        .await
+        .unwrap();
+}
+
+async fn wait_for_no_text(harness: &mut TestHarness, text: &str) {
+    harness
+        .wait_for(async |harness, _| !harness.terminal_view().await.contains(text))
+        .await
+        .unwrap();
+}
+
+#[rooibos::test]
+async fn test_custom_mode() {
+    let mut harness = harness_with_backend(Vim).await;
+
+    // j does nothing outside of count mode
+    harness.send_text("j");
+    harness.send_text("c");
+    wait_for_text(&mut harness, "-- COUNT --").await;
+    assert!(harness.terminal_view().await.contains("count: 0"));
+
+    harness.send_text("j");
+    harness.send_text("j");
+    harness.send_text("k");
+    wait_for_text(&mut harness, "count: 1").await;
+
+    harness.send_key(KeyCode::Esc);
+    wait_for_no_text(&mut harness, "-- COUNT --").await;
+    harness.send_text("j");
+    harness.send_text("c");
+    harness.send_text("j");
+    wait_for_text(&mut harness, "count: 2").await;
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_vim_command_bar() {
+    let mut harness = harness_with_backend(Vim).await;
+
+    harness.send_text(":");
+
+    // Keys go to the command bar while it's open instead of the app's bindings
+    harness.send_text("c");
+    harness.send_text("j");
+    wait_for_text(&mut harness, ":cj").await;
+    assert!(!harness.terminal_view().await.contains("-- COUNT --"));
+
+    // Escape closes the command bar without running the command
+    harness.send_key(KeyCode::Esc);
+    wait_for_no_text(&mut harness, ":cj").await;
+    harness.send_text("c");
+    harness.send_text("j");
+    wait_for_text(&mut harness, "count: 1").await;
+    harness.send_key(KeyCode::Esc);
+    wait_for_no_text(&mut harness, "-- COUNT --").await;
+
+    harness.send_text(":");
+    harness.send_text("count 5");
+    harness.send_key(KeyCode::Enter);
+    wait_for_text(&mut harness, "count: 6").await;
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_emacs_command_bar() {
+    let mut harness = harness_with_backend(Emacs).await;
+
+    // : is a normal key with emacs bindings
+    harness.send_text(":");
+    send_modified_key(&harness, 'x', KeyModifiers::ALT);
+    wait_for_text(&mut harness, "M-x ").await;
+
+    harness.send_text("c");
+    harness.send_text("j");
+    wait_for_text(&mut harness, "M-x cj").await;
+    assert!(!harness.terminal_view().await.contains("COUNT"));
+
+    // C-g closes the command bar
+    send_modified_key(&harness, 'g', KeyModifiers::CTRL);
+    wait_for_no_text(&mut harness, "M-x cj").await;
+    harness.send_text("c");
+    harness.send_text("j");
+    wait_for_text(&mut harness, "count: 1").await;
+    wait_for_text(&mut harness, "COUNT").await;
+
+    // Escape goes back to the initial mode, which is insert mode for emacs
+    harness.send_key(KeyCode::Esc);
+    wait_for_no_text(&mut harness, "COUNT").await;
+
+    harness.exit().await;
+}
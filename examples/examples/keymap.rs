@@ -0,0 +1,103 @@
+use std::process::ExitCode;
+
+use confique::toml;
+use rooibos::config::provide_config;
+use rooibos::config::watch_config::ConfigDir;
+use rooibos::config::watch_config::backend::confique::{AppConfig, ConfigSettings, ConfiqueConfig};
+use rooibos::config::watch_config::confique::Config;
+use rooibos::keybind::{
+    Bind, Commands, KeyActionMap, KeyMapper, KeymapConfig, extract, keys, on_command, parse_action,
+    use_keymap,
+};
+use rooibos::reactive::dom::layout::{full, height, padding, width};
+use rooibos::reactive::dom::{Render, line, span};
+use rooibos::reactive::graph::signal::signal;
+use rooibos::reactive::graph::traits::{Get, Set, Update};
+use rooibos::reactive::{col, wgt};
+use rooibos::runtime::Runtime;
+use rooibos::runtime::error::RuntimeError;
+use rooibos::terminal::DefaultBackend;
+use rooibos::theme::Stylize;
+use rooibos::tui::text::Text;
+
+type KeymapAppConfig = AppConfig<KeymapExample, ConfigBuilder>;
+
+#[derive(Config, PartialEq, Eq, Clone, Debug)]
+#[config(layer_attr(derive(Clone)))]
+struct KeymapExample {
+    #[config(default = { "<C-Up>": "count 1", "<C-Down>": "count -1", "gg": "reset" })]
+    pub keys: KeymapConfig,
+}
+
+#[derive(Clone)]
+struct ConfigBuilder;
+
+impl ConfiqueConfig<KeymapExample> for ConfigBuilder {
+    fn builder(&self, path: &std::path::Path) -> confique::Builder<KeymapExample> {
+        KeymapExample::builder().file(path)
+    }
+
+    fn template(&self) -> String {
+        toml::template::<KeymapExample>(toml::FormatOptions::default())
+    }
+}
+
+#[rooibos::main]
+async fn main() -> Result<ExitCode, RuntimeError> {
+    let config = AppConfig::new(ConfigSettings::new(
+        ConfigDir::Custom("./.config".into()),
+        "keymap.toml".to_owned(),
+        ConfigBuilder,
+    ))
+    .unwrap();
+    provide_config(config);
+
+    Runtime::initialize(DefaultBackend::auto().await?)
+        .run(|_| app())
+        .await
+}
+
+fn app() -> impl Render {
+    let (count, set_count) = signal(0);
+
+    on_command(extract!(val, AppAction::Count { val }), move |val| {
+        set_count.update(|c| *c += val);
+    });
+    on_command(extract!(AppAction::Reset), move |_| set_count.set(0));
+
+    let keymap = use_keymap::<KeymapAppConfig, AppAction, _, _>(|c| &c.keys, parse_action);
+
+    // Bindings from the config are applied on top of the ones defined in code
+    let mut mapper = KeyMapper::from([KeyActionMap::action(
+        keys::combine([keys::CTRL, 'r'.into()]),
+        AppAction::Reset,
+    )]);
+    let conflicts = mapper.keymap(keymap.keymap);
+
+    col![
+        style(width(full()), height(full()), padding(1)),
+        wgt!(line!("count: ".bold(), count.get().cyan())),
+        wgt!(line!(
+            "Update ./.config/keymap.toml and the bindings will update live".dark_gray()
+        )),
+        wgt!(Text::from(
+            keymap
+                .errors
+                .get()
+                .into_iter()
+                .chain(conflicts.get())
+                .map(|e| line!(span!("{e}").red()))
+                .collect::<Vec<_>>()
+        ))
+    ]
+    .on_key_down(mapper.bind())
+}
+
+#[derive(clap::Parser, Commands, Clone, Debug, PartialEq, Eq)]
+pub enum AppAction {
+    Count {
+        #[arg(allow_hyphen_values(true))]
+        val: i32,
+    },
+    Reset,
+}
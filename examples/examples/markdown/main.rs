@@ -0,0 +1,72 @@
+use std::process::ExitCode;
+
+use rooibos::components::Markdown;
+use rooibos::reactive::dom::layout::{Borders, full, height, padding_left, width};
+use rooibos::reactive::dom::{Render, UpdateLayoutProps, line};
+use rooibos::reactive::graph::signal::RwSignal;
+use rooibos::reactive::graph::traits::{Get, Set};
+use rooibos::reactive::{col, wgt};
+use rooibos::runtime::error::RuntimeError;
+use rooibos::runtime::{Runtime, max_viewport_height, max_viewport_width};
+use rooibos::terminal::DefaultBackend;
+
+type Result = std::result::Result<ExitCode, RuntimeError>;
+
+const SOURCE: &str = r#"# Rooibos
+
+A *reactive* TUI framework with **flexbox** layouts. See the
+[repository](https://github.com/aschey/rooibos) for more details.
+
+## Features
+
+- Fine-grained reactivity
+- Themes with ~~hardcoded~~ adaptive colors
+  1. Terminal
+  2. SSH
+  3. Web
+
+> Block quotes can contain `inline code`
+> and wrap across multiple lines when the text is too long to fit.
+
+```rust
+let count = RwSignal::new(0);
+```
+
+| Backend  | Supported |
+| -------- | :-------: |
+| Terminal | yes       |
+| [GUI](https://github.com/aschey/rooibos/tree/main/examples) | yes |
+
+---
+"#;
+
+#[rooibos::main]
+async fn main() -> Result {
+    Runtime::initialize(DefaultBackend::auto().await?)
+        .run(|_| app())
+        .await
+}
+
+fn app() -> impl Render {
+    max_viewport_width(70).unwrap();
+    max_viewport_height(40).unwrap();
+
+    let clicked = RwSignal::new(String::new());
+
+    col![
+        style(width(full()), height(full())),
+        Markdown::new()
+            .on_link_click(move |url| clicked.set(url))
+            .width(full())
+            .flex_grow(1.)
+            .borders(Borders::all())
+            .render(SOURCE.to_string()),
+        wgt!(
+            style(padding_left(1)),
+            line!(format!("Clicked link: {}", clicked.get()))
+        )
+    ]
+}
+
+#[cfg(test)]
+mod tests;
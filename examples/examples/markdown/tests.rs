@@ -0,0 +1,74 @@
+use rooibos::reactive::KeyCode;
+use rooibos::reactive::dom::root;
+use rooibos::tester::TestHarness;
+use rooibos::tui::style::Modifier;
+
+use crate::app;
+
+const REPOSITORY: &str = "https://github.com/aschey/rooibos";
+const EXAMPLES: &str = "https://github.com/aschey/rooibos/tree/main/examples";
+
+async fn wait_for_text<P: 'static>(harness: &mut TestHarness<P>, text: &str) {
+    harness
+        .wait_for(async |harness, _| harness.terminal_view().await.contains(text))
+        .await
+        .unwrap();
+}
+
+// Links are added on top of the text once the document has been laid out
+async fn wait_for_link<P: 'static>(harness: &mut TestHarness<P>, text: &str) {
+    harness
+        .wait_for(async |harness, _| harness.find_all_by_text(&root(), text).await.len() > 1)
+        .await
+        .unwrap();
+}
+
+async fn wait_for_focus<P: 'static>(harness: &mut TestHarness<P>, text: &str) {
+    harness
+        .wait_for(async |harness, _| {
+            let pos = harness.get_position_of_text(text).await;
+            harness.buffer().await[(pos.x, pos.y)]
+                .modifier
+                .contains(Modifier::REVERSED)
+        })
+        .await
+        .unwrap();
+}
+
+#[rooibos::test]
+async fn test_click_link() {
+    let mut harness = TestHarness::new(70, 40).await;
+    harness.mount((), |_| app()).await;
+
+    wait_for_link(&mut harness, "repository").await;
+    let link = harness.get_position_of_text("repository").await;
+    harness.click_pos(link);
+    wait_for_text(&mut harness, &format!("Clicked link: {REPOSITORY}")).await;
+
+    // Links inside tables can be clicked too
+    wait_for_link(&mut harness, "GUI").await;
+    let link = harness.get_position_of_text("GUI").await;
+    harness.click_pos(link);
+    wait_for_text(&mut harness, &format!("Clicked link: {EXAMPLES}")).await;
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_keyboard_links() {
+    let mut harness = TestHarness::new(70, 40).await;
+    harness.mount((), |_| app()).await;
+    wait_for_link(&mut harness, "repository").await;
+
+    harness.tab().await;
+    wait_for_focus(&mut harness, "repository").await;
+    harness.send_key(KeyCode::Enter);
+    wait_for_text(&mut harness, &format!("Clicked link: {REPOSITORY}")).await;
+
+    harness.tab().await;
+    wait_for_focus(&mut harness, "GUI").await;
+    harness.send_key(KeyCode::Enter);
+    wait_for_text(&mut harness, &format!("Clicked link: {EXAMPLES}")).await;
+
+    harness.exit().await;
+}
@@ -54,3 +54,6 @@ fn task(id: usize, notifier: Notifier) -> impl RenderAny {
 fn get_random_delay() -> Duration {
     Duration::from_millis(((rand::random::<f32>() * 3. + 1.0) * 1000.0).round() as u64)
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,29 @@
+use std::time::Duration;
+
+use rooibos::tester::TestHarness;
+
+use crate::app;
+
+#[rooibos::test]
+async fn test_notifications() {
+    // Tasks finish after a random delay of up to 4 seconds, which would make this test slow and
+    // flaky with a real clock
+    let mut harness = TestHarness::new_with_virtual_clock(80, 20).await;
+    harness.mount((), |_| app()).await;
+    harness.run_until_idle().await;
+    assert!(!harness.terminal_view().await.contains('✓'));
+
+    // Leave a little extra time for the render debouncer
+    harness.advance(Duration::from_millis(4100)).await;
+    let view = harness.terminal_view().await;
+    for id in 1..=5 {
+        assert!(view.contains(&format!("✓ task {id}")));
+    }
+    assert!(view.contains("completed"));
+
+    // Notifications are removed after their timeout
+    harness.advance(Duration::from_secs(3)).await;
+    assert!(!harness.terminal_view().await.contains("completed"));
+
+    harness.exit().await;
+}
@@ -0,0 +1,79 @@
+use std::process::ExitCode;
+use std::time::Duration;
+
+use rooibos::components::{Gauge, ProgressBar};
+use rooibos::reactive::col;
+use rooibos::reactive::dom::layout::{Borders, borders, full, gap, height, padding, width};
+use rooibos::reactive::dom::{Render, UpdateLayoutProps, span};
+use rooibos::runtime::error::RuntimeError;
+use rooibos::runtime::{
+    Runtime, ServiceContext, max_viewport_height, max_viewport_width, spawn_progress_service,
+    wasm_compat,
+};
+use rooibos::terminal::DefaultBackend;
+use tokio_util::future::FutureExt;
+
+type Result = std::result::Result<ExitCode, RuntimeError>;
+
+const DOWNLOAD_SIZE: f64 = 48.0;
+
+#[rooibos::main]
+async fn main() -> Result {
+    Runtime::initialize(DefaultBackend::auto().await?)
+        .run(|_| app())
+        .await
+}
+
+fn app() -> impl Render {
+    max_viewport_width(70).unwrap();
+    max_viewport_height(12).unwrap();
+
+    let (_, download) = spawn_progress_service(|reporter| {
+        ("download", move |context: ServiceContext| async move {
+            // Pretend to resolve the download size before starting
+            reporter.set_indeterminate(true);
+            if wasm_compat::sleep(Duration::from_secs(2))
+                .with_cancellation_token(context.cancellation_token())
+                .await
+                .is_none()
+            {
+                return Ok(());
+            }
+            for _ in 0..100 {
+                if wasm_compat::sleep(Duration::from_millis(100))
+                    .with_cancellation_token(context.cancellation_token())
+                    .await
+                    .is_none()
+                {
+                    return Ok(());
+                }
+                reporter.inc(0.01);
+            }
+            reporter.finish();
+            Ok(())
+        })
+    });
+
+    col![
+        style(
+            width(full()),
+            height(full()),
+            padding(1),
+            gap(1),
+            borders(Borders::all())
+        ),
+        ProgressBar::new()
+            .label(span!("Downloading"))
+            .show_eta(true)
+            .show_throughput(true)
+            .total(DOWNLOAD_SIZE)
+            .unit("MB")
+            .width(full())
+            .render_reporter(&download),
+        Gauge::new()
+            .show_eta(true)
+            .width(full())
+            .height(3)
+            .render_reporter(&download)
+    ]
+}
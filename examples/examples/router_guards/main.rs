@@ -0,0 +1,121 @@
+use std::process::ExitCode;
+use std::time::Duration;
+
+use rooibos::components::{Button, Checkbox, DialogManager, Dialogs};
+use rooibos::reactive::dom::layout::{Borders, borders, gap, height, padding, width};
+use rooibos::reactive::dom::{Render, UpdateLayoutProps, after_render_async, line, span, text};
+use rooibos::reactive::graph::signal::RwSignal;
+use rooibos::reactive::graph::traits::{GetUntracked, Set};
+use rooibos::reactive::{col, fallback, row, transition, wgt};
+use rooibos::router::{Route, RouteContext, RouteFromStatic, Url, use_loader, use_router};
+use rooibos::runtime::Runtime;
+use rooibos::runtime::error::RuntimeError;
+use rooibos::terminal::DefaultBackend;
+use rooibos::theme::Stylize;
+
+type Result = std::result::Result<ExitCode, RuntimeError>;
+
+#[derive(Route)]
+struct Home;
+
+#[derive(Route)]
+struct Editor;
+
+#[derive(Route)]
+struct Post {
+    id: usize,
+}
+
+#[rooibos::main]
+async fn main() -> Result {
+    Runtime::initialize(DefaultBackend::auto().await?)
+        .run(|_| app())
+        .await
+}
+
+fn app() -> impl Render {
+    let dialogs = Dialogs::new();
+    let manager = DialogManager::new();
+    let (router, route_context) = use_router();
+
+    col![
+        style(padding(1), gap(1)),
+        row![
+            style(gap(1), height(3)),
+            Button::new()
+                .on_click(move || route_context.push(Home))
+                .render(text!("Home")),
+            Button::new()
+                .on_click(move || route_context.push(Editor))
+                .render(text!("Editor")),
+            Button::new()
+                .on_click(move || route_context.push(Post { id: 1 }))
+                .render(text!("Post 1")),
+            Button::new()
+                .on_click(move || route_context.push(Post { id: 2 }))
+                .render(text!("Post 2")),
+            // Old links are redirected to the new location
+            Button::new()
+                .on_click(move || route_context.push("/article/2".to_string()))
+                .render(text!("Old post link")),
+        ],
+        col![
+            style(width(50), height(8), padding(1), borders(Borders::all())),
+            router
+                .routes([
+                    Route::new::<Home>(|| wgt!("This is the home page")),
+                    Route::new::<Editor>(move || editor(route_context, manager)),
+                    Route::new::<Post>(post).loader(load_post),
+                ])
+                .redirect("/article/{id}", "/post/{id}".to_string())
+                .initial(Home)
+                .render()
+        ],
+        dialogs.render()
+    ]
+}
+
+fn editor(route_context: RouteContext, manager: DialogManager) -> impl Render {
+    let unsaved = RwSignal::new(false);
+
+    route_context.on_before_leave(move |navigation| {
+        if !unsaved.get_untracked() {
+            return true;
+        }
+        let confirmed = manager.confirm("Unsaved changes", "Discard your changes?");
+        let navigation = navigation.clone();
+        after_render_async(async move {
+            if confirmed.await {
+                unsaved.set(false);
+                route_context.proceed(navigation);
+            }
+        });
+        false
+    });
+
+    col![
+        wgt!("Leaving this page with unsaved changes asks for confirmation"),
+        Checkbox::new()
+            .checked(unsaved)
+            .render(line!("Unsaved changes"))
+    ]
+}
+
+async fn load_post(url: Url) -> rooibos::reactive::error::Result<String> {
+    // Simulate a slow request
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    Ok(format!("Contents of {}", url.path()))
+}
+
+fn post() -> impl Render {
+    let contents = use_loader::<rooibos::reactive::error::Result<String>>();
+    // The previous post stays on screen while the next one loads
+    transition!(
+        wgt!("Loading...".gray()),
+        contents.await.map(|c| wgt!(line!(c.green()))),
+        |err| fallback(err, |e| span!(e).red())
+    )
+}
+
+#[cfg(test)]
+mod tests;
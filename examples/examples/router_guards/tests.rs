@@ -0,0 +1,291 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use rooibos::reactive::col;
+use rooibos::reactive::dom::{Render, root};
+use rooibos::reactive::graph::traits::GetUntracked;
+use rooibos::reactive::wgt;
+use rooibos::router::{
+    Navigation, NavigationKind, Outlet, Route, RouteContext, RouteFromStatic, use_router,
+};
+use rooibos::tester::TestHarness;
+
+use crate::app;
+
+// The example's loader takes a while to simulate a slow request
+const LOAD_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(Route)]
+struct Home;
+
+#[derive(Route)]
+struct About;
+
+#[derive(Route)]
+struct Settings;
+
+#[derive(Route)]
+struct General;
+
+#[derive(Route)]
+struct Advanced;
+
+#[derive(Route)]
+struct Ping;
+
+#[derive(Route)]
+struct Pong;
+
+type SharedContext = Arc<Mutex<Option<RouteContext>>>;
+
+/// Records the navigations a guard was asked about and decides whether they're allowed.
+#[derive(Clone)]
+struct GuardState {
+    allow: Arc<AtomicBool>,
+    calls: Arc<Mutex<Vec<Navigation>>>,
+}
+
+impl GuardState {
+    fn new() -> Self {
+        Self {
+            allow: Arc::new(AtomicBool::new(true)),
+            calls: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    fn guard(&self) -> impl Fn(&Navigation) -> bool + Send + Sync + 'static {
+        let state = self.clone();
+        move |navigation| {
+            state.calls.lock().unwrap().push(navigation.clone());
+            state.allow.load(Ordering::SeqCst)
+        }
+    }
+
+    fn allow(&self, allow: bool) {
+        self.allow.store(allow, Ordering::SeqCst);
+    }
+
+    fn call_count(&self) -> usize {
+        self.calls.lock().unwrap().len()
+    }
+
+    fn last_call(&self) -> Navigation {
+        self.calls.lock().unwrap().last().cloned().unwrap()
+    }
+}
+
+fn guarded_app(context: SharedContext, scoped: GuardState, global: GuardState) -> impl Render {
+    let (router, route_context) = use_router();
+    *context.lock().unwrap() = Some(route_context);
+    // Registered outside of any route, so it runs for every navigation
+    route_context.on_before_leave(global.guard());
+
+    router
+        .routes([
+            Route::new::<Home>(|| wgt!("home page")),
+            Route::new::<About>(|| wgt!("about page")),
+            Route::new::<Settings>(move || {
+                route_context.on_before_leave(scoped.guard());
+                col![wgt!("settings page"), Outlet::new().render()]
+            })
+            .children([
+                Route::new::<General>(|| wgt!("general tab")),
+                Route::new::<Advanced>(|| wgt!("advanced tab")),
+            ]),
+            Route::new::<Ping>(|| wgt!("ping page")),
+            Route::new::<Pong>(|| wgt!("pong page")),
+        ])
+        .redirect("/ping", "/pong")
+        .redirect("/pong", "/ping")
+        .initial(Home)
+        .render()
+}
+
+async fn mount_guarded(scoped: &GuardState, global: &GuardState) -> (TestHarness, RouteContext) {
+    let mut harness = TestHarness::new(40, 10).await;
+    let context = SharedContext::default();
+    harness
+        .mount((), {
+            let context = context.clone();
+            let scoped = scoped.clone();
+            let global = global.clone();
+            move |_| guarded_app(context, scoped, global)
+        })
+        .await;
+    let route_context = context.lock().unwrap().unwrap();
+    wait_for_text(&mut harness, "home page").await;
+    (harness, route_context)
+}
+
+async fn wait_for_text<P: 'static>(harness: &mut TestHarness<P>, text: &str) {
+    harness
+        .wait_for(async |harness, _| harness.terminal_view().await.contains(text))
+        .await
+        .unwrap();
+}
+
+#[rooibos::test]
+async fn test_guard_scope() {
+    let scoped = GuardState::new();
+    let (mut harness, route_context) = mount_guarded(&scoped, &GuardState::new()).await;
+
+    route_context.push("/settings/general");
+    wait_for_text(&mut harness, "general tab").await;
+
+    // Moving between the route's children doesn't leave it
+    route_context.push("/settings/advanced");
+    wait_for_text(&mut harness, "advanced tab").await;
+    assert_eq!(scoped.call_count(), 0);
+
+    scoped.allow(false);
+    route_context.push(About);
+    harness.run_until_idle().await;
+    assert_eq!(scoped.call_count(), 1);
+    assert_eq!(scoped.last_call().to.path(), "/about");
+    assert!(harness.terminal_view().await.contains("advanced tab"));
+
+    scoped.allow(true);
+    route_context.push(About);
+    wait_for_text(&mut harness, "about page").await;
+    assert_eq!(scoped.call_count(), 2);
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_guard_cleanup() {
+    let scoped = GuardState::new();
+    let (mut harness, route_context) = mount_guarded(&scoped, &GuardState::new()).await;
+
+    route_context.push("/settings/general");
+    wait_for_text(&mut harness, "general tab").await;
+    route_context.push(About);
+    wait_for_text(&mut harness, "about page").await;
+    assert_eq!(scoped.call_count(), 1);
+
+    // The guard was removed along with the settings route
+    scoped.allow(false);
+    route_context.push(Home);
+    wait_for_text(&mut harness, "home page").await;
+    assert_eq!(scoped.call_count(), 1);
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_proceed_back_and_forward() {
+    let global = GuardState::new();
+    let (mut harness, route_context) = mount_guarded(&GuardState::new(), &global).await;
+
+    route_context.push("/settings/general");
+    wait_for_text(&mut harness, "general tab").await;
+
+    global.allow(false);
+    route_context.back();
+    harness.run_until_idle().await;
+    assert!(harness.terminal_view().await.contains("general tab"));
+    let navigation = global.last_call();
+    assert_eq!(navigation.kind, NavigationKind::Back);
+    assert_eq!(navigation.to.path(), "/home");
+
+    // Proceeding skips the guards
+    route_context.proceed(navigation);
+    wait_for_text(&mut harness, "home page").await;
+    assert!(route_context.can_go_forward().get_untracked());
+
+    route_context.forward();
+    harness.run_until_idle().await;
+    assert!(harness.terminal_view().await.contains("home page"));
+    let navigation = global.last_call();
+    assert_eq!(navigation.kind, NavigationKind::Forward);
+    assert_eq!(navigation.to.path(), "/settings/general");
+
+    route_context.proceed(navigation);
+    wait_for_text(&mut harness, "general tab").await;
+    assert!(!route_context.can_go_forward().get_untracked());
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_redirect_loop() {
+    let (mut harness, route_context) = mount_guarded(&GuardState::new(), &GuardState::new()).await;
+
+    // Redirects that point at each other stop after a fixed number of hops
+    route_context.push(Ping);
+    harness
+        .wait_for(async |harness, _| {
+            let view = harness.terminal_view().await;
+            view.contains("ping page") || view.contains("pong page")
+        })
+        .await
+        .unwrap();
+
+    route_context.push(About);
+    wait_for_text(&mut harness, "about page").await;
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_redirect_params() {
+    let mut harness = TestHarness::new(80, 16).await;
+    harness.mount((), |_| app()).await;
+
+    let old_link = harness.get_by_text(&root(), "Old post link").await;
+    harness.click(&old_link).await;
+    harness
+        .wait_for_timeout(
+            async |harness, _| {
+                harness
+                    .terminal_view()
+                    .await
+                    .contains("Contents of /post/2")
+            },
+            LOAD_TIMEOUT,
+        )
+        .await
+        .unwrap();
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_loader_reruns() {
+    let mut harness = TestHarness::new(80, 16).await;
+    harness.mount((), |_| app()).await;
+
+    let post_1 = harness.get_by_text(&root(), "Post 1").await;
+    harness.click(&post_1).await;
+    harness
+        .wait_for_timeout(
+            async |harness, _| {
+                harness
+                    .terminal_view()
+                    .await
+                    .contains("Contents of /post/1")
+            },
+            LOAD_TIMEOUT,
+        )
+        .await
+        .unwrap();
+
+    // The loader runs again when the url changes while the route stays active
+    let post_2 = harness.get_by_text(&root(), "Post 2").await;
+    harness.click(&post_2).await;
+    harness
+        .wait_for_timeout(
+            async |harness, _| {
+                harness
+                    .terminal_view()
+                    .await
+                    .contains("Contents of /post/2")
+            },
+            LOAD_TIMEOUT,
+        )
+        .await
+        .unwrap();
+
+    harness.exit().await;
+}
@@ -0,0 +1,91 @@
+use std::process::ExitCode;
+
+use rooibos::components::Button;
+use rooibos::reactive::dom::layout::{Borders, borders, gap, height, padding, width};
+use rooibos::reactive::dom::{Render, UpdateLayoutProps, line, text};
+use rooibos::reactive::graph::traits::Get;
+use rooibos::reactive::{col, row, wgt};
+use rooibos::router::{
+    HistoryPersistence, Route, RouteContext, RouteFromStatic, deep_link_from_args,
+    deep_link_from_env, use_router,
+};
+use rooibos::runtime::Runtime;
+use rooibos::runtime::error::RuntimeError;
+use rooibos::terminal::DefaultBackend;
+use rooibos::theme::Stylize;
+
+type Result = std::result::Result<ExitCode, RuntimeError>;
+
+#[derive(Route)]
+struct Home;
+
+#[derive(Route)]
+struct Post {
+    id: usize,
+}
+
+#[rooibos::main]
+async fn main() -> Result {
+    Runtime::initialize(DefaultBackend::auto().await?)
+        .run(|_| app())
+        .await
+}
+
+fn app() -> impl Render {
+    history_app(
+        HistoryPersistence::new("rooibos-router-history").depth(20),
+        deep_link_from_args("--route").or_else(|| deep_link_from_env("ROUTER_HISTORY_ROUTE")),
+    )
+}
+
+fn history_app(persistence: HistoryPersistence, deep_link: Option<String>) -> impl Render {
+    let (router, route_context) = use_router();
+
+    col![
+        style(padding(1), gap(1)),
+        row![
+            style(gap(1), height(3)),
+            Button::new()
+                .on_click(move || route_context.back())
+                .render(text!("Back")),
+            Button::new()
+                .on_click(move || route_context.forward())
+                .render(text!("Forward")),
+            Button::new()
+                .on_click(move || route_context.push(Home))
+                .render(text!("Home")),
+            Button::new()
+                .on_click(move || route_context.push(Post { id: 1 }))
+                .render(text!("Post 1")),
+            Button::new()
+                .on_click(move || route_context.push(Post { id: 2 }))
+                .render(text!("Post 2")),
+        ],
+        wgt!(line!(
+            "Restart the app to restore the history. Start at a specific page with ".dark_gray(),
+            "--route /post/2".cyan(),
+            " or ".dark_gray(),
+            "ROUTER_HISTORY_ROUTE=/post/2".cyan()
+        )),
+        col![
+            style(width(50), height(5), padding(1), borders(Borders::all())),
+            router
+                .routes([
+                    Route::new::<Home>(|| wgt!("This is the home page")),
+                    Route::new::<Post>(move || post(route_context)),
+                ])
+                .initial(Home)
+                .deep_link(deep_link)
+                .persist_history(persistence)
+                .render()
+        ]
+    ]
+}
+
+fn post(route_context: RouteContext) -> impl Render {
+    let id = route_context.use_param(Post::ID);
+    wgt!(line!("post ", id.get()))
+}
+
+#[cfg(test)]
+mod tests;
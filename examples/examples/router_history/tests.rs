@@ -0,0 +1,169 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rooibos::reactive::dom::root;
+use rooibos::router::{HistoryPersistence, Url};
+use rooibos::tester::TestHarness;
+
+use crate::history_app;
+
+// Each test gets its own file so they can run in parallel
+fn history_file(name: &str, contents: Option<&str>) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rooibos-router-history-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(name);
+    match contents {
+        Some(contents) => fs::write(&path, contents).unwrap(),
+        None => {
+            let _ = fs::remove_file(&path);
+        }
+    }
+    path
+}
+
+// Paths of the saved back history and forward buffer
+fn saved_paths(path: &Path) -> Option<(Vec<String>, Vec<String>)> {
+    let contents = fs::read_to_string(path).ok()?;
+    let (history, buffer) = contents.split_once("\n\n")?;
+    let paths = |lines: &str| {
+        lines
+            .lines()
+            .map(|line| Url::parse(line).map(|url| url.path().to_string()))
+            .collect::<Result<Vec<_>, _>>()
+            .ok()
+    };
+    Some((paths(history)?, paths(buffer)?))
+}
+
+async fn mount(path: PathBuf, deep_link: Option<&str>) -> TestHarness {
+    let mut harness = TestHarness::new(80, 14).await;
+    let deep_link = deep_link.map(|link| link.to_string());
+    harness
+        .mount((), move |_| {
+            history_app(HistoryPersistence::file(path), deep_link)
+        })
+        .await;
+    harness
+}
+
+async fn wait_for_text(harness: &mut TestHarness, text: &str) {
+    harness
+        .wait_for(async |harness, _| harness.terminal_view().await.contains(text))
+        .await
+        .unwrap();
+}
+
+async fn click_text(harness: &mut TestHarness, text: &str) {
+    let node = harness.get_by_text(&root(), text).await;
+    harness.click(&node).await;
+}
+
+#[rooibos::test]
+async fn test_save_history() {
+    let path = history_file("save", None);
+    let mut harness = mount(path.clone(), None).await;
+
+    click_text(&mut harness, "Post 1").await;
+    click_text(&mut harness, "Post 2").await;
+    click_text(&mut harness, "Back").await;
+    wait_for_text(&mut harness, "post 1").await;
+
+    // The history is written on a background thread
+    harness
+        .wait_for(async |_, _| {
+            saved_paths(&path)
+                == Some((
+                    vec!["/home".to_string(), "/post/1".to_string()],
+                    vec!["/post/2".to_string()],
+                ))
+        })
+        .await
+        .unwrap();
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_restore_history() {
+    let path = history_file(
+        "restore",
+        Some("app:///home\napp:///post/1\napp:///post/2\n\napp:///post/3\n"),
+    );
+    let mut harness = mount(path, None).await;
+    wait_for_text(&mut harness, "post 2").await;
+
+    click_text(&mut harness, "Forward").await;
+    wait_for_text(&mut harness, "post 3").await;
+
+    click_text(&mut harness, "Back").await;
+    click_text(&mut harness, "Back").await;
+    wait_for_text(&mut harness, "post 1").await;
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_restore_skips_removed_routes() {
+    // Routes that don't exist anymore are dropped from the restored history
+    let path = history_file(
+        "removed",
+        Some("app:///home\napp:///removed\napp:///post/1\n\napp:///gone\n"),
+    );
+    let mut harness = mount(path, None).await;
+    wait_for_text(&mut harness, "post 1").await;
+
+    click_text(&mut harness, "Forward").await;
+    click_text(&mut harness, "Back").await;
+    wait_for_text(&mut harness, "This is the home page").await;
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_restore_without_matching_routes() {
+    // The app starts at the initial route if nothing can be restored
+    let path = history_file("unmatched", Some("app:///removed\n\n"));
+    let mut harness = mount(path, None).await;
+    wait_for_text(&mut harness, "This is the home page").await;
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_deep_link_after_restore() {
+    let path = history_file("deep_link", Some("app:///home\napp:///post/1\n\n"));
+    let mut harness = mount(path, Some("myapp://post/2")).await;
+
+    // The deep link is pushed on top of the restored history
+    wait_for_text(&mut harness, "post 2").await;
+    click_text(&mut harness, "Back").await;
+    wait_for_text(&mut harness, "post 1").await;
+    click_text(&mut harness, "Back").await;
+    wait_for_text(&mut harness, "This is the home page").await;
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_invalid_deep_link_after_restore() {
+    let path = history_file("invalid_deep_link", Some("app:///home\napp:///post/1\n\n"));
+    let mut harness = mount(path, Some("/missing")).await;
+
+    // Links that don't match a route are ignored
+    wait_for_text(&mut harness, "post 1").await;
+    click_text(&mut harness, "Back").await;
+    wait_for_text(&mut harness, "This is the home page").await;
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_deep_link_with_url_in_query() {
+    let path = history_file("url_in_query", None);
+    let mut harness = mount(path, Some("/post/2?from=https://example.com")).await;
+
+    // Only a scheme at the start of the link is stripped
+    wait_for_text(&mut harness, "post 2").await;
+
+    harness.exit().await;
+}
@@ -0,0 +1,111 @@
+use std::process::ExitCode;
+
+use rooibos::components::Button;
+use rooibos::reactive::dom::layout::{Borders, borders, height, padding, width};
+use rooibos::reactive::dom::{Render, UpdateLayoutProps, line, text};
+use rooibos::reactive::graph::traits::Get;
+use rooibos::reactive::{col, row, wgt};
+use rooibos::router::{
+    DefaultRoute, Outlet, Route, RouteContext, RouteFromStatic, ToRoute, use_router,
+};
+use rooibos::runtime::Runtime;
+use rooibos::runtime::error::RuntimeError;
+use rooibos::terminal::DefaultBackend;
+
+type Result = std::result::Result<ExitCode, RuntimeError>;
+
+#[derive(Route)]
+struct Home;
+
+#[derive(Route)]
+struct Team {
+    id: usize,
+}
+
+#[derive(Route)]
+struct Members;
+
+#[derive(Route)]
+struct Projects;
+
+#[rooibos::main]
+async fn main() -> Result {
+    Runtime::initialize(DefaultBackend::auto().await?)
+        .run(|_| app())
+        .await
+}
+
+fn app() -> impl Render {
+    let (router, route_context) = use_router();
+
+    // The sidebar is shared by every route, so it's only defined once in the root layout
+    row![
+        sidebar(route_context),
+        col![
+            style(width(40), height(12), padding(1), borders(Borders::all())),
+            router
+                .routes([
+                    Route::new::<Home>(|| wgt!("Select a team")),
+                    Route::new::<Team>(move || team(route_context)).children([
+                        Route::new::<DefaultRoute>(|| wgt!("Team overview")),
+                        Route::new::<Members>(move || members(route_context)),
+                        Route::new::<Projects>(move || projects(route_context)),
+                    ]),
+                ])
+                .initial(Home)
+                .render()
+        ]
+    ]
+}
+
+fn sidebar(route_context: RouteContext) -> impl Render {
+    col![
+        style(width(12)),
+        Button::new()
+            .height(3)
+            .on_click(move || route_context.push(Team { id: 1 }))
+            .render(text!("Team 1")),
+        Button::new()
+            .height(3)
+            .on_click(move || route_context.push(Team { id: 2 }))
+            .render(text!("Team 2"))
+    ]
+}
+
+fn team(route_context: RouteContext) -> impl Render {
+    let id = route_context.use_param(Team::ID);
+    let push_child = move |child: &dyn ToRoute| {
+        let team = Team {
+            id: id.get().parse().unwrap(),
+        };
+        route_context.push(format!("{}{}", team.to_route(), child.to_route()));
+    };
+    col![
+        wgt!(line!("team ", id.get())),
+        row![
+            style(height(3)),
+            Button::new()
+                .on_click(move || push_child(&Members))
+                .render(text!("Members")),
+            Button::new()
+                .on_click(move || push_child(&Projects))
+                .render(text!("Projects"))
+        ],
+        // Child routes are rendered here
+        Outlet::new().render()
+    ]
+}
+
+fn members(route_context: RouteContext) -> impl Render {
+    // Params from the parent route are available to the children
+    let id = route_context.use_param(Team::ID);
+    wgt!(line!("members of team ", id.get()))
+}
+
+fn projects(route_context: RouteContext) -> impl Render {
+    let id = route_context.use_param(Team::ID);
+    wgt!(line!("projects for team ", id.get()))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,147 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use rooibos::reactive::dom::{Render, line, root};
+use rooibos::reactive::graph::traits::Get;
+use rooibos::reactive::{col, wgt};
+use rooibos::router::{DefaultRoute, Outlet, Route, RouteContext, RouteFromStatic, use_router};
+use rooibos::tester::TestHarness;
+
+use crate::app;
+
+#[derive(Route)]
+struct Org;
+
+#[derive(Route)]
+struct Team {
+    id: usize,
+}
+
+#[derive(Route)]
+struct Members;
+
+async fn wait_for_text<P: 'static>(harness: &mut TestHarness<P>, text: &str) {
+    harness
+        .wait_for(async |harness, _| harness.terminal_view().await.contains(text))
+        .await
+        .unwrap();
+}
+
+async fn click_text<P: 'static>(harness: &mut TestHarness<P>, text: &str) {
+    let node = harness.get_by_text(&root(), text).await;
+    harness.click(&node).await;
+}
+
+#[rooibos::test]
+async fn test_index_route() {
+    let mut harness = TestHarness::new(60, 16).await;
+    harness.mount((), |_| app()).await;
+    wait_for_text(&mut harness, "Select a team").await;
+
+    // The parent path renders the parent with its index route in the outlet
+    click_text(&mut harness, "Team 1").await;
+    wait_for_text(&mut harness, "Team overview").await;
+    let view = harness.terminal_view().await;
+    assert!(view.contains("team 1"));
+    assert!(!view.contains("Select a team"));
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_child_routes() {
+    let mut harness = TestHarness::new(60, 16).await;
+    harness.mount((), |_| app()).await;
+
+    click_text(&mut harness, "Team 1").await;
+    wait_for_text(&mut harness, "Team overview").await;
+
+    // Child paths are joined to the parent path and can read the parent's params
+    click_text(&mut harness, "Members").await;
+    wait_for_text(&mut harness, "members of team 1").await;
+    assert!(!harness.terminal_view().await.contains("Team overview"));
+
+    click_text(&mut harness, "Projects").await;
+    wait_for_text(&mut harness, "projects for team 1").await;
+
+    // Navigating to another team goes back to its index route
+    click_text(&mut harness, "Team 2").await;
+    wait_for_text(&mut harness, "team 2").await;
+    wait_for_text(&mut harness, "Team overview").await;
+
+    harness.exit().await;
+}
+
+#[derive(Clone, Default)]
+struct RenderCounts {
+    org: Arc<AtomicUsize>,
+    team: Arc<AtomicUsize>,
+}
+
+fn nested_app(counts: RenderCounts, context: Arc<Mutex<Option<RouteContext>>>) -> impl Render {
+    let (router, route_context) = use_router();
+    *context.lock().unwrap() = Some(route_context);
+    let RenderCounts { org, team } = counts;
+
+    router
+        .routes([Route::new::<Org>(move || {
+            org.fetch_add(1, Ordering::SeqCst);
+            col![wgt!("org layout"), Outlet::new().render()]
+        })
+        .children([Route::new::<Team>(move || {
+            team.fetch_add(1, Ordering::SeqCst);
+            let id = route_context.use_param(Team::ID);
+            col![wgt!(line!("team ", id.get())), Outlet::new().render()]
+        })
+        .children([
+            Route::new::<DefaultRoute>(|| wgt!("team index")),
+            Route::new::<Members>(|| wgt!("member list")),
+        ])])])
+        .initial(Org)
+        .render()
+}
+
+#[rooibos::test]
+async fn test_outlet_depth() {
+    let mut harness = TestHarness::new(40, 10).await;
+    let counts = RenderCounts::default();
+    let context = Arc::new(Mutex::new(None));
+    harness
+        .mount((), {
+            let counts = counts.clone();
+            let context = context.clone();
+            move |_| nested_app(counts, context)
+        })
+        .await;
+    let route_context = context.lock().unwrap().unwrap();
+
+    // A route without an index child renders nothing in its outlet when matched directly
+    wait_for_text(&mut harness, "org layout").await;
+    assert!(!harness.terminal_view().await.contains("team"));
+
+    // Each outlet renders the next route in the chain
+    route_context.push("/org/team/1");
+    wait_for_text(&mut harness, "team index").await;
+    assert!(harness.terminal_view().await.contains("team 1"));
+
+    route_context.push("/org/team/1/members");
+    wait_for_text(&mut harness, "member list").await;
+    assert!(!harness.terminal_view().await.contains("team index"));
+
+    // Changing params or moving between children doesn't rebuild the parent layouts
+    route_context.push("/org/team/2");
+    wait_for_text(&mut harness, "team 2").await;
+    wait_for_text(&mut harness, "team index").await;
+    assert_eq!(counts.org.load(Ordering::SeqCst), 1);
+    assert_eq!(counts.team.load(Ordering::SeqCst), 1);
+
+    // Leaving the nested routes removes them from every outlet
+    route_context.push("/org");
+    harness
+        .wait_for(async |harness, _| !harness.terminal_view().await.contains("team"))
+        .await
+        .unwrap();
+    assert!(harness.terminal_view().await.contains("org layout"));
+
+    harness.exit().await;
+}
@@ -0,0 +1,124 @@
+use std::fmt;
+use std::process::ExitCode;
+
+use rooibos::components::Button;
+use rooibos::reactive::dom::layout::{Borders, borders, gap, height, padding, width};
+use rooibos::reactive::dom::{Render, UpdateLayoutProps, line, span, text};
+use rooibos::reactive::graph::traits::Get;
+use rooibos::reactive::{col, row, wgt};
+use rooibos::router::{Route, RouteFromStatic, use_params, use_query, use_router};
+use rooibos::runtime::Runtime;
+use rooibos::runtime::error::RuntimeError;
+use rooibos::terminal::DefaultBackend;
+use rooibos::theme::Stylize;
+use serde::Deserialize;
+
+type Result = std::result::Result<ExitCode, RuntimeError>;
+
+#[derive(Route)]
+struct Home;
+
+// Optional fields and vectors are added to the query string, everything else is part of the path
+#[derive(Route, Deserialize, Clone, Debug)]
+struct Search {
+    term: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    sort: Option<Sort>,
+}
+
+#[derive(Route, Deserialize, Clone, Debug)]
+struct Author {
+    name: String,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug)]
+#[serde(rename_all = "kebab-case")]
+enum Sort {
+    Newest,
+    MostViewed,
+}
+
+impl fmt::Display for Sort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Newest => write!(f, "newest"),
+            Self::MostViewed => write!(f, "most-viewed"),
+        }
+    }
+}
+
+#[rooibos::main]
+async fn main() -> Result {
+    Runtime::initialize(DefaultBackend::auto().await?)
+        .run(|_| app())
+        .await
+}
+
+fn app() -> impl Render {
+    let (router, route_context) = use_router();
+
+    col![
+        style(padding(1), gap(1)),
+        row![
+            style(gap(1), height(3)),
+            Button::new()
+                .on_click(move || route_context.push(Home))
+                .render(text!("Home")),
+            Button::new()
+                .on_click(move || {
+                    route_context.push(Search {
+                        term: Some("hello world".to_string()),
+                        tags: vec!["rust".to_string(), "tui & cli".to_string()],
+                        sort: Some(Sort::MostViewed),
+                    })
+                })
+                .render(text!("Search")),
+            // Special characters in params are encoded in the URL and decoded when read
+            Button::new()
+                .on_click(move || {
+                    route_context.push(Author {
+                        name: "Jane Doe/Smith".to_string(),
+                    })
+                })
+                .render(text!("Author")),
+            Button::new()
+                .on_click(move || route_context.push("/search?sort=oldest".to_string()))
+                .render(text!("Invalid search")),
+        ],
+        col![
+            style(width(60), height(8), padding(1), borders(Borders::all())),
+            router
+                .routes([
+                    Route::new::<Home>(|| wgt!("This is the home page")),
+                    Route::new::<Search>(search),
+                    Route::new::<Author>(author),
+                ])
+                .initial(Home)
+                .render()
+        ],
+        wgt!(line!(
+            span!(route_context.current_route().to_string()).dark_gray()
+        ))
+    ]
+}
+
+fn search() -> impl Render {
+    let query = use_query::<Search>();
+    wgt!(match query.get() {
+        Ok(query) => text!(
+            line!("term: ", span!("{:?}", query.term).cyan()),
+            line!("tags: ", span!("{:?}", query.tags).cyan()),
+            line!("sort: ", span!("{:?}", query.sort).cyan())
+        ),
+        Err(e) => text!(line!(span!("{e}").red())),
+    })
+}
+
+fn author() -> impl Render {
+    let params = use_params::<Author>();
+    wgt!(match params.get() {
+        Ok(author) => line!("author: ", author.name.cyan()),
+        Err(e) => line!(span!("{e}").red()),
+    })
+}
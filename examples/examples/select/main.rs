@@ -0,0 +1,74 @@
+use std::process::ExitCode;
+
+use rooibos::components::{ComboBox, Select};
+use rooibos::reactive::dom::layout::{Borders, borders, full, gap, height, padding, width};
+use rooibos::reactive::dom::{Render, UpdateLayoutProps, line};
+use rooibos::reactive::graph::signal::RwSignal;
+use rooibos::reactive::graph::traits::Get;
+use rooibos::reactive::{col, wgt};
+use rooibos::runtime::error::RuntimeError;
+use rooibos::runtime::{Runtime, max_viewport_height, max_viewport_width};
+use rooibos::terminal::DefaultBackend;
+
+type Result = std::result::Result<ExitCode, RuntimeError>;
+
+const FRUITS: [&str; 12] = [
+    "Apple",
+    "Apricot",
+    "Banana",
+    "Blueberry",
+    "Cherry",
+    "Grape",
+    "Kiwi",
+    "Lemon",
+    "Mango",
+    "Orange",
+    "Peach",
+    "Pear",
+];
+
+#[rooibos::main]
+async fn main() -> Result {
+    Runtime::initialize(DefaultBackend::auto().await?)
+        .run(|_| app())
+        .await
+}
+
+fn app() -> impl Render {
+    max_viewport_width(60).unwrap();
+    max_viewport_height(20).unwrap();
+
+    let fruit = RwSignal::new(None::<&'static str>);
+    let searched = RwSignal::new(None::<&'static str>);
+
+    col![
+        style(
+            width(full()),
+            height(full()),
+            padding(1),
+            gap(1),
+            borders(Borders::all())
+        ),
+        wgt!(line!("Select a fruit:")),
+        Select::new()
+            .value(fruit)
+            .placeholder("Pick one")
+            .width(30)
+            .render(FRUITS.to_vec(), |f| f.to_string()),
+        wgt!(line!("Or search for one:")),
+        ComboBox::new()
+            .value(searched)
+            .placeholder("Type to filter")
+            .width(30)
+            .render(FRUITS.to_vec(), |f| f.to_string()),
+        wgt!(line!(
+            "selected: ",
+            format!("{:?}", fruit.get()),
+            " / ",
+            format!("{:?}", searched.get())
+        ))
+    ]
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,109 @@
+use rooibos::reactive::KeyCode;
+use rooibos::reactive::dom::root;
+use rooibos::tester::TestHarness;
+
+use crate::app;
+
+async fn open_select<P: 'static>(harness: &mut TestHarness<P>) {
+    let select = harness.get_by_text(&root(), "Pick one").await;
+    harness.click(&select).await;
+    harness
+        .wait_for(async |harness, _| harness.terminal_view().await.contains("Apricot"))
+        .await
+        .unwrap();
+}
+
+#[rooibos::test]
+async fn test_keyboard_selection() {
+    let mut harness = TestHarness::new(60, 20).await;
+    harness.mount((), |_| app()).await;
+    open_select(&mut harness).await;
+
+    // The first option is highlighted when nothing is selected yet
+    harness.send_key(KeyCode::Down);
+    harness.send_key(KeyCode::Down);
+    harness.send_key(KeyCode::Enter);
+    harness
+        .wait_for(async |harness, _| {
+            let view = harness.terminal_view().await;
+            view.contains(r#"selected: Some("Banana") / None"#) && !view.contains("Apricot")
+        })
+        .await
+        .unwrap();
+
+    // Reopening starts from the current value
+    harness.send_key(KeyCode::Enter);
+    harness
+        .wait_for(async |harness, _| harness.terminal_view().await.contains("Apricot"))
+        .await
+        .unwrap();
+    harness.send_key(KeyCode::Up);
+    harness.send_key(KeyCode::Enter);
+    harness
+        .wait_for(async |harness, _| {
+            harness
+                .terminal_view()
+                .await
+                .contains(r#"selected: Some("Apricot") / None"#)
+        })
+        .await
+        .unwrap();
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_escape_closes_without_selecting() {
+    let mut harness = TestHarness::new(60, 20).await;
+    harness.mount((), |_| app()).await;
+    open_select(&mut harness).await;
+
+    harness.send_key(KeyCode::Down);
+    harness.send_key(KeyCode::Esc);
+    harness
+        .wait_for(async |harness, _| !harness.terminal_view().await.contains("Apricot"))
+        .await
+        .unwrap();
+    assert!(
+        harness
+            .terminal_view()
+            .await
+            .contains("selected: None / None")
+    );
+
+    // Type-ahead picks a value directly while the list is closed
+    harness.send_key(KeyCode::Char('c'));
+    harness
+        .wait_for(async |harness, _| {
+            harness
+                .terminal_view()
+                .await
+                .contains(r#"selected: Some("Cherry") / None"#)
+        })
+        .await
+        .unwrap();
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_close_on_blur() {
+    let mut harness = TestHarness::new(60, 20).await;
+    harness.mount((), |_| app()).await;
+    open_select(&mut harness).await;
+
+    // Moving focus to the combo box closes the list
+    harness.tab().await;
+    harness
+        .wait_for(async |harness, _| !harness.terminal_view().await.contains("Apricot"))
+        .await
+        .unwrap();
+    assert!(
+        harness
+            .terminal_view()
+            .await
+            .contains("selected: None / None")
+    );
+
+    harness.exit().await;
+}
@@ -0,0 +1,78 @@
+use std::process::ExitCode;
+
+use rooibos::components::{Column, SelectionMode, TableView, TableViewState};
+use rooibos::reactive::dom::Render;
+use rooibos::reactive::graph::IntoReactiveValue;
+use rooibos::reactive::graph::wrappers::read::Signal;
+use rooibos::runtime::Runtime;
+use rooibos::runtime::error::RuntimeError;
+use rooibos::terminal::DefaultBackend;
+use rooibos::tui::layout::Constraint;
+
+type Result = std::result::Result<ExitCode, RuntimeError>;
+
+#[derive(Clone)]
+struct Fruit {
+    name: &'static str,
+    color: &'static str,
+    price: u32,
+}
+
+#[rooibos::main]
+async fn main() -> Result {
+    let runtime = Runtime::initialize(DefaultBackend::auto().await?);
+    runtime.run(|_| app(TableViewState::new())).await
+}
+
+fn app(state: TableViewState) -> impl Render {
+    fruit_table(state, fruits())
+}
+
+fn fruits() -> Vec<Fruit> {
+    vec![
+        Fruit {
+            name: "Apple",
+            color: "Red",
+            price: 3,
+        },
+        Fruit {
+            name: "Banana",
+            color: "Yellow",
+            price: 1,
+        },
+        Fruit {
+            name: "Grape",
+            color: "Purple",
+            price: 4,
+        },
+        Fruit {
+            name: "Kiwi",
+            color: "Green",
+            price: 2,
+        },
+    ]
+}
+
+fn fruit_table<M>(
+    state: TableViewState,
+    fruits: impl IntoReactiveValue<Signal<Vec<Fruit>>, M>,
+) -> impl Render {
+    TableView::new()
+        .columns([
+            Column::new("Name", |f: &Fruit| f.name.into())
+                .width(Constraint::Length(10))
+                .sort_by_key(|f| f.name),
+            Column::new("Color", |f: &Fruit| f.color.into())
+                .width(Constraint::Length(10))
+                .sort_by_key(|f| f.color),
+            Column::new("Price", |f: &Fruit| format!("${}", f.price).into())
+                .sort_by_key(|f| f.price),
+        ])
+        .state(state)
+        .selection_mode(SelectionMode::Multi)
+        .highlight_symbol("> ")
+        .render(fruits)
+}
+
+#[cfg(test)]
+mod tests;
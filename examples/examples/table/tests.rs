@@ -0,0 +1,175 @@
+use std::collections::BTreeSet;
+
+use rooibos::components::{SortDirection, TableSort, TableViewState};
+use rooibos::reactive::dom::layout::{focus_mode, vertical_list};
+use rooibos::reactive::focus_scope;
+use rooibos::reactive::graph::signal::RwSignal;
+use rooibos::reactive::graph::traits::{GetUntracked, Update};
+use rooibos::reactive::{Event, KeyCode, KeyEvent, KeyModifiers};
+use rooibos::tester::TestHarness;
+
+use crate::{app, fruit_table, fruits};
+
+fn send_shift_key<P>(harness: &TestHarness<P>, code: KeyCode) {
+    harness.send_event(Event::Key(
+        KeyEvent::new(code).modifiers(KeyModifiers::SHIFT),
+    ));
+}
+
+async fn wait_for_selection<P: 'static>(
+    harness: &mut TestHarness<P>,
+    state: TableViewState,
+    items: &[usize],
+) {
+    let expected: BTreeSet<_> = items.iter().copied().collect();
+    harness
+        .wait_for(async |_, _| state.selected().get_untracked() == expected)
+        .await
+        .unwrap();
+}
+
+#[rooibos::test]
+async fn test_range_selection() {
+    let mut harness = TestHarness::new(40, 10).await;
+    let state = TableViewState::new();
+    harness.mount((), move |_| app(state)).await;
+
+    let apple = harness.get_position_of_text("Apple").await;
+    harness.click_pos(apple);
+    harness
+        .wait_for(async |_, _| state.cursor().get_untracked() == Some(0))
+        .await
+        .unwrap();
+
+    send_shift_key(&harness, KeyCode::Down);
+    send_shift_key(&harness, KeyCode::Down);
+    wait_for_selection(&mut harness, state, &[0, 1, 2]).await;
+
+    // Moving back toward the anchor shrinks the range
+    send_shift_key(&harness, KeyCode::Up);
+    wait_for_selection(&mut harness, state, &[0, 1]).await;
+
+    // Extending past the last row keeps the range within the table
+    for _ in 0..5 {
+        send_shift_key(&harness, KeyCode::Down);
+    }
+    wait_for_selection(&mut harness, state, &[0, 1, 2, 3]).await;
+    assert_eq!(state.cursor().get_untracked(), Some(3));
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_sort_keeps_selection() {
+    let mut harness = TestHarness::new(40, 10).await;
+    let state = TableViewState::new();
+    harness.mount((), move |_| app(state)).await;
+
+    // Select Apple and Banana
+    let apple = harness.get_position_of_text("Apple").await;
+    harness.click_pos(apple);
+    send_shift_key(&harness, KeyCode::Down);
+    wait_for_selection(&mut harness, state, &[0, 1]).await;
+
+    // Sort by price: Banana, Kiwi, Apple, Grape
+    let price = harness.get_position_of_text("Price").await;
+    harness.click_pos(price);
+    harness
+        .wait_for(async |_, _| {
+            state.sort().get_untracked()
+                == Some(TableSort {
+                    column: 2,
+                    direction: SortDirection::Ascending,
+                })
+        })
+        .await
+        .unwrap();
+
+    // The selection refers to the same items and the cursor follows the anchor item
+    harness
+        .wait_for(async |_, _| state.cursor().get_untracked() == Some(2))
+        .await
+        .unwrap();
+    assert_eq!(state.selected().get_untracked(), BTreeSet::from([0, 1]));
+    let view = harness.terminal_view().await;
+    let rows: Vec<_> = view.lines().skip(1).take(4).collect();
+    assert!(rows[0].contains("Banana"));
+    assert!(rows[2].starts_with("> Apple"));
+
+    // Extending the selection after sorting uses the new row order
+    send_shift_key(&harness, KeyCode::Down);
+    wait_for_selection(&mut harness, state, &[0, 3]).await;
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_remove_rows_while_sorted() {
+    let mut harness = TestHarness::new(40, 10).await;
+    let state = TableViewState::new();
+    let items = RwSignal::new(fruits());
+    harness.mount((), move |_| fruit_table(state, items)).await;
+
+    // Sort by price: Banana, Kiwi, Apple, Grape
+    let price = harness.get_position_of_text("Price").await;
+    harness.click_pos(price);
+    harness
+        .wait_for(async |harness, _| {
+            let view = harness.terminal_view().await;
+            let rows: Vec<_> = view.lines().skip(1).take(4).collect();
+            rows[0].contains("Banana") && rows[3].contains("Grape")
+        })
+        .await
+        .unwrap();
+
+    // Only Apple is left, the old order had four rows
+    items.update(|fruits| fruits.truncate(1));
+    harness
+        .wait_for(async |harness, _| {
+            let view = harness.terminal_view().await;
+            view.contains("Apple")
+                && !view.contains("Banana")
+                && !view.contains("Grape")
+                && !view.contains("Kiwi")
+        })
+        .await
+        .unwrap();
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_navigation_in_list_focus_scope() {
+    let mut harness = TestHarness::new(40, 10).await;
+    let state = TableViewState::new();
+    harness
+        .mount((), move |_| {
+            focus_scope!(
+                style(focus_mode(vertical_list())),
+                fruit_table(state, fruits())
+            )
+        })
+        .await;
+
+    let apple = harness.get_position_of_text("Apple").await;
+    harness.click_pos(apple);
+    harness
+        .wait_for(async |_, _| state.cursor().get_untracked() == Some(0))
+        .await
+        .unwrap();
+
+    // The table handles Up and Down before they're used to move the focus within the list
+    harness.send_key(KeyCode::Down);
+    harness.send_key(KeyCode::Down);
+    harness
+        .wait_for(async |_, _| state.cursor().get_untracked() == Some(2))
+        .await
+        .unwrap();
+    harness.send_key(KeyCode::Up);
+    harness
+        .wait_for(async |_, _| state.cursor().get_untracked() == Some(1))
+        .await
+        .unwrap();
+
+    harness.exit().await;
+}
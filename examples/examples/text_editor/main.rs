@@ -0,0 +1,63 @@
+use std::process::ExitCode;
+
+use rooibos::components::{KeywordHighlighter, TextEditor};
+use rooibos::reactive::dom::layout::{Borders, borders, full, height, padding_left, width};
+use rooibos::reactive::dom::{Render, UpdateLayoutProps, line};
+use rooibos::reactive::graph::traits::Get;
+use rooibos::reactive::{col, wgt};
+use rooibos::runtime::error::RuntimeError;
+use rooibos::runtime::{Runtime, max_viewport_height, max_viewport_width};
+use rooibos::terminal::DefaultBackend;
+
+type Result = std::result::Result<ExitCode, RuntimeError>;
+
+const SOURCE: &str = r#"// Press Ctrl+Z to undo and Ctrl+Y to redo
+fn main() {
+    let greeting = "hello";
+    for i in 0..3 {
+        println!("{greeting} {i}");
+    }
+}
+"#;
+
+#[rooibos::main]
+async fn main() -> Result {
+    Runtime::initialize(DefaultBackend::auto().await?)
+        .run(|_| app())
+        .await
+}
+
+fn app() -> impl Render {
+    max_viewport_width(80).unwrap();
+    max_viewport_height(20).unwrap();
+
+    let editor_ref = TextEditor::get_ref();
+    let cursor = editor_ref.cursor();
+    let text = editor_ref.text();
+
+    col![
+        style(width(full()), height(full())),
+        TextEditor::default()
+            .highlighter(
+                KeywordHighlighter::new(["fn", "let", "for", "in", "mut", "return"])
+                    .line_comment("//")
+            )
+            .initial_value(SOURCE)
+            .width(full())
+            .flex_grow(1.)
+            .borders(Borders::all())
+            .render(editor_ref),
+        wgt!(
+            style(padding_left(1)),
+            line!(format!(
+                "Ln {}, Col {}  {} chars",
+                cursor.get().0 + 1,
+                cursor.get().1 + 1,
+                text.get().chars().count()
+            ))
+        )
+    ]
+}
+
+#[cfg(test)]
+mod tests;
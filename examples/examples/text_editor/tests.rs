@@ -0,0 +1,150 @@
+use rooibos::reactive::dom::root;
+use rooibos::reactive::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+    ScrollDirection,
+};
+use rooibos::tester::TestHarness;
+
+use crate::app;
+
+fn send_mouse<P>(harness: &TestHarness<P>, kind: MouseEventKind, column: u16, row: u16) {
+    harness.send_event(Event::Mouse(MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers: KeyModifiers::empty(),
+    }));
+}
+
+fn send_shift_key<P>(harness: &TestHarness<P>, code: KeyCode) {
+    harness.send_event(Event::Key(
+        KeyEvent::new(code).modifiers(KeyModifiers::SHIFT),
+    ));
+}
+
+async fn wait_for_text<P: 'static>(harness: &mut TestHarness<P>, text: &str) {
+    harness
+        .wait_for(async |harness, _| harness.terminal_view().await.contains(text))
+        .await
+        .unwrap();
+}
+
+#[rooibos::test]
+async fn test_editing() {
+    let mut harness = TestHarness::new(80, 20).await;
+    harness.mount((), |_| app()).await;
+
+    // Clicking places the cursor under the mouse
+    let main = harness.get_position_of_text("main()").await;
+    harness.click_pos(main);
+    wait_for_text(&mut harness, "Ln 2, Col 4").await;
+
+    harness.send_text("my_");
+    wait_for_text(&mut harness, "fn my_main() {").await;
+
+    for _ in 0..3 {
+        harness.send_event(Event::Key(
+            KeyEvent::new(KeyCode::Char('z')).modifiers(KeyModifiers::CTRL),
+        ));
+    }
+    wait_for_text(&mut harness, "fn main() {").await;
+
+    harness.send_key(KeyCode::Enter);
+    wait_for_text(&mut harness, "Ln 3, Col 1").await;
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_keyboard_selection() {
+    let mut harness = TestHarness::new(80, 20).await;
+    harness.mount((), |_| app()).await;
+
+    let main = harness.get_position_of_text("main()").await;
+    harness.click_pos(main);
+    wait_for_text(&mut harness, "Ln 2, Col 4").await;
+
+    for _ in 0..4 {
+        send_shift_key(&harness, KeyCode::Right);
+    }
+    // Typing replaces the selection
+    harness.send_text("start");
+    wait_for_text(&mut harness, "fn start() {").await;
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_mouse_selection() {
+    let mut harness = TestHarness::new(80, 20).await;
+    harness.mount((), |_| app()).await;
+
+    let greeting = harness.get_position_of_text("greeting =").await;
+    // Drag across "greeting"
+    send_mouse(
+        &harness,
+        MouseEventKind::Down(MouseButton::Left),
+        greeting.x,
+        greeting.y,
+    );
+    send_mouse(
+        &harness,
+        MouseEventKind::Drag(MouseButton::Left),
+        greeting.x + 8,
+        greeting.y,
+    );
+    send_mouse(
+        &harness,
+        MouseEventKind::Up(MouseButton::Left),
+        greeting.x + 8,
+        greeting.y,
+    );
+    wait_for_text(&mut harness, "Ln 3, Col 17").await;
+
+    harness.send_text("message");
+    wait_for_text(&mut harness, "let message = \"hello\";").await;
+
+    // Shift+click extends the selection from the cursor
+    let hello = harness.get_position_of_text("\"hello\"").await;
+    harness.send_event(Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column: hello.x + 7,
+        row: hello.y,
+        modifiers: KeyModifiers::SHIFT,
+    }));
+    harness.send_key(KeyCode::Backspace);
+    wait_for_text(&mut harness, "let message;").await;
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_mouse_scroll() {
+    // Leaves room for 4 rows of text inside the border. The first line is wrapped onto two rows.
+    let mut harness = TestHarness::new(40, 7).await;
+    harness.mount((), |_| app()).await;
+
+    let editor = harness.get_by_text(&root(), "fn main()").await;
+    harness.scroll(&editor, ScrollDirection::Down, 2).await;
+    let view = harness.terminal_view().await;
+    let lines: Vec<_> = view.lines().collect();
+    assert!(lines[1].contains("2 fn main() {"));
+    assert!(!view.contains("Press Ctrl+Z"));
+    // Scrolling doesn't move the cursor
+    assert!(view.contains("Ln 1, Col 1"));
+
+    // Scrolling stops at the end of the text
+    harness.scroll(&editor, ScrollDirection::Down, 10).await;
+    let view = harness.terminal_view().await;
+    assert!(view.lines().nth(1).unwrap().contains("5         println!"));
+
+    // Clicks account for the scroll position
+    let closing_brace = harness.get_position_of_text("6     }").await;
+    harness.click_pos(closing_brace);
+    wait_for_text(&mut harness, "Ln 6, Col 1").await;
+
+    harness.scroll(&editor, ScrollDirection::Up, 10).await;
+    assert!(harness.terminal_view().await.contains("Press Ctrl+Z"));
+
+    harness.exit().await;
+}
@@ -0,0 +1,96 @@
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use rooibos::components::{Keyed, TreeNode, TreeView, TreeViewState};
+use rooibos::reactive::dom::Render;
+use rooibos::reactive::dom::events::KeyEventProps;
+use rooibos::reactive::graph::traits::GetUntracked;
+use rooibos::reactive::{KeyCode, delay};
+use rooibos::runtime::Runtime;
+use rooibos::runtime::error::RuntimeError;
+use rooibos::terminal::DefaultBackend;
+use rooibos::tui::text::Line;
+use tokio::sync::oneshot;
+
+type Result = std::result::Result<ExitCode, RuntimeError>;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Entry {
+    path: String,
+    name: String,
+}
+
+impl Entry {
+    fn new(parent: &str, name: &str) -> Self {
+        Self {
+            path: format!("{parent}/{name}"),
+            name: name.to_string(),
+        }
+    }
+}
+
+impl Keyed for Entry {
+    type Key = String;
+
+    fn key(&self) -> &Self::Key {
+        &self.path
+    }
+}
+
+const LOAD_DELAY: Duration = Duration::from_secs(1);
+
+#[rooibos::main]
+async fn main() -> Result {
+    let runtime = Runtime::initialize(DefaultBackend::auto().await?);
+    runtime.run(|_| app(TreeViewState::new())).await
+}
+
+fn app(state: TreeViewState<Entry>) -> impl Render {
+    let roots = vec![
+        TreeNode::branch(
+            Entry::new("", "src"),
+            [
+                TreeNode::leaf(Entry::new("/src", "main.rs")),
+                TreeNode::lazy(Entry::new("/src", "components")),
+            ],
+        ),
+        TreeNode::leaf(Entry::new("", "Cargo.toml")),
+        TreeNode::leaf(Entry::new("", "README.md")),
+    ];
+    let loads = Arc::new(AtomicUsize::new(0));
+
+    TreeView::new()
+        .state(state)
+        .highlight_symbol("> ")
+        .load_children(move |entry: Entry| {
+            // Each load returns different file names so it's easy to see which one was used
+            let load = loads.fetch_add(1, Ordering::Relaxed) + 1;
+            async move {
+                let (tx, rx) = oneshot::channel();
+                delay(LOAD_DELAY, async move {
+                    let _ = tx.send(());
+                });
+                let _ = rx.await;
+                ["button", "list"]
+                    .into_iter()
+                    .map(|name| {
+                        TreeNode::leaf(Entry::new(&entry.path, &format!("{name}_{load}.rs")))
+                    })
+                    .collect()
+            }
+        })
+        .on_key_down(move |props: KeyEventProps| {
+            // Reload the selected node
+            if props.event.code == KeyCode::Char('r')
+                && let Some(selected) = state.selected().get_untracked()
+            {
+                state.invalidate(&selected);
+            }
+        })
+        .render(roots, |entry| Line::from(entry.name.clone()))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,107 @@
+use std::time::Duration;
+
+use rooibos::components::TreeViewState;
+use rooibos::reactive::KeyCode;
+use rooibos::reactive::graph::traits::GetUntracked;
+use rooibos::tester::TestHarness;
+
+use crate::{Entry, LOAD_DELAY, app};
+
+async fn select_src<P: 'static>(harness: &mut TestHarness<P>, state: TreeViewState<Entry>) {
+    let src = harness.get_position_of_text("src").await;
+    harness.click_pos(src);
+    harness
+        .wait_for(async |_, _| state.selected().get_untracked().as_deref() == Some("/src"))
+        .await
+        .unwrap();
+}
+
+#[rooibos::test]
+async fn test_expand_collapse() {
+    let mut harness = TestHarness::new(40, 10).await;
+    let state = TreeViewState::new();
+    harness.mount((), move |_| app(state)).await;
+    select_src(&mut harness, state).await;
+
+    harness.send_key(KeyCode::Right);
+    harness
+        .wait_for(async |harness, _| harness.terminal_view().await.contains("main.rs"))
+        .await
+        .unwrap();
+
+    harness.send_key(KeyCode::Down);
+    harness
+        .wait_for(async |_, _| state.selected().get_untracked().as_deref() == Some("/src/main.rs"))
+        .await
+        .unwrap();
+
+    // Left on a leaf moves to the parent, then collapses it
+    harness.send_key(KeyCode::Left);
+    harness.send_key(KeyCode::Left);
+    harness
+        .wait_for(async |harness, _| !harness.terminal_view().await.contains("main.rs"))
+        .await
+        .unwrap();
+    assert_eq!(state.selected().get_untracked().as_deref(), Some("/src"));
+    assert!(!state.is_expanded(&"/src".to_string()));
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_lazy_load() {
+    let mut harness = TestHarness::new_with_virtual_clock(40, 10).await;
+    let state = TreeViewState::new();
+    harness.mount((), move |_| app(state)).await;
+    select_src(&mut harness, state).await;
+
+    harness.send_key(KeyCode::Right);
+    harness.send_key(KeyCode::Down);
+    harness.send_key(KeyCode::Down);
+    harness.send_key(KeyCode::Right);
+    harness.run_until_idle().await;
+    assert_eq!(
+        state.selected().get_untracked().as_deref(),
+        Some("/src/components")
+    );
+    assert!(harness.terminal_view().await.contains("… components"));
+
+    harness.advance(LOAD_DELAY).await;
+    let view = harness.terminal_view().await;
+    assert!(view.contains("button_1.rs"));
+    assert!(view.contains("list_1.rs"));
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_invalidate_discards_stale_load() {
+    let mut harness = TestHarness::new_with_virtual_clock(40, 10).await;
+    let state = TreeViewState::new();
+    harness.mount((), move |_| app(state)).await;
+    select_src(&mut harness, state).await;
+
+    harness.send_key(KeyCode::Right);
+    harness.send_key(KeyCode::Down);
+    harness.send_key(KeyCode::Down);
+    harness.send_key(KeyCode::Right);
+    harness.advance(LOAD_DELAY / 2).await;
+
+    // Reload while the first load is still pending
+    harness.send_key(KeyCode::Char('r'));
+    harness.send_key(KeyCode::Right);
+    harness.run_until_idle().await;
+
+    // The first load finishes but its result is out of date
+    harness.advance(LOAD_DELAY * 3 / 4).await;
+    let view = harness.terminal_view().await;
+    assert!(!view.contains("button_1.rs"));
+    assert!(view.contains("… components"));
+
+    harness.advance(LOAD_DELAY / 2).await;
+    let view = harness.terminal_view().await;
+    assert!(view.contains("button_2.rs"));
+    assert!(!view.contains("button_1.rs"));
+
+    harness.exit().await;
+}
@@ -0,0 +1,29 @@
+use std::process::ExitCode;
+
+use rooibos::components::{VirtualList, VirtualListState};
+use rooibos::reactive::dom::Render;
+use rooibos::runtime::Runtime;
+use rooibos::runtime::error::RuntimeError;
+use rooibos::terminal::DefaultBackend;
+use rooibos::theme::Style;
+use rooibos::tui::widgets::ListItem;
+
+type Result = std::result::Result<ExitCode, RuntimeError>;
+
+#[rooibos::main]
+async fn main() -> Result {
+    let runtime = Runtime::initialize(DefaultBackend::auto().await?);
+    runtime.run(|_| app()).await
+}
+
+fn app() -> impl Render {
+    let state = VirtualListState::new().with_selected(Some(0));
+
+    VirtualList::new()
+        .state(state)
+        .highlight_style(Style::new().green())
+        .render(1_000_000, |i| ListItem::new(format!("Item {}", i + 1)))
+}
+
+#[cfg(test)]
+mod tests;
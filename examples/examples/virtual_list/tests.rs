@@ -0,0 +1,152 @@
+use rooibos::components::{VirtualList, VirtualListState};
+use rooibos::reactive::dom::events::KeyEventProps;
+use rooibos::reactive::dom::{Render, root};
+use rooibos::reactive::graph::traits::GetUntracked;
+use rooibos::reactive::{KeyCode, ScrollDirection};
+use rooibos::tester::TestHarness;
+use rooibos::tui::text::{Line, Text};
+use rooibos::tui::widgets::ListItem;
+
+use crate::app;
+
+async fn first_line<P: 'static>(harness: &TestHarness<P>) -> String {
+    let view = harness.terminal_view().await;
+    view.lines()
+        .next()
+        .unwrap_or_default()
+        .trim_end()
+        .to_string()
+}
+
+#[rooibos::test]
+async fn test_scroll() {
+    let mut harness = TestHarness::new(20, 10).await;
+    harness.mount((), |_| app()).await;
+
+    let list = harness.get_by_text(&root(), "Item 1").await;
+    harness.scroll(&list, ScrollDirection::Down, 3).await;
+    assert_eq!(first_line(&harness).await, "Item 4");
+
+    harness.scroll(&list, ScrollDirection::Up, 1).await;
+    assert_eq!(first_line(&harness).await, "Item 3");
+
+    harness.scroll(&list, ScrollDirection::Up, 5).await;
+    assert_eq!(first_line(&harness).await, "Item 1");
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_keyboard() {
+    let mut harness = TestHarness::new(20, 10).await;
+    harness.mount((), |_| app()).await;
+
+    let list = harness.get_by_text(&root(), "Item 1").await;
+    harness.click(&list).await;
+    harness.send_key(KeyCode::End);
+    harness
+        .wait_for(async |harness, _| harness.terminal_view().await.contains("Item 1000000"))
+        .await
+        .unwrap();
+    assert_eq!(first_line(&harness).await, "Item 999991");
+
+    harness.send_key(KeyCode::Home);
+    harness
+        .wait_for(async |harness, _| first_line(harness).await == "Item 1")
+        .await
+        .unwrap();
+
+    harness.exit().await;
+}
+
+fn tall_rows(state: VirtualListState) -> impl Render {
+    VirtualList::new().state(state).render(100, |i| {
+        ListItem::new(Text::from(vec![
+            Line::from(format!("Item {}", i + 1)),
+            Line::from("-"),
+        ]))
+    })
+}
+
+#[rooibos::test]
+async fn test_scroll_to_tall_rows() {
+    let mut harness = TestHarness::new(20, 10).await;
+    let state = VirtualListState::new().with_selected(Some(8));
+    harness.mount(state, tall_rows).await;
+
+    // Five rows fit in the view, so the selected row is at the bottom
+    assert_eq!(first_line(&harness).await, "Item 5");
+
+    // Scrolling keeps the selection even though the selected row is no longer visible
+    state.scroll_to(2);
+    harness
+        .wait_for(async |harness, _| first_line(harness).await == "Item 3")
+        .await
+        .unwrap();
+    assert_eq!(state.selected().get_untracked(), Some(8));
+
+    // The last rows fill the view instead of leaving empty space at the end
+    state.scroll_to(99);
+    harness
+        .wait_for(async |harness, _| first_line(harness).await == "Item 96")
+        .await
+        .unwrap();
+
+    harness.exit().await;
+}
+
+#[rooibos::test]
+async fn test_wheel_keeps_selection() {
+    let mut harness = TestHarness::new(20, 10).await;
+    let state = VirtualListState::new().with_selected(Some(0));
+    harness.mount(state, tall_rows).await;
+
+    let list = harness.get_by_text(&root(), "Item 1").await;
+    harness.scroll(&list, ScrollDirection::Down, 10).await;
+    assert_eq!(first_line(&harness).await, "Item 11");
+    assert_eq!(state.selected().get_untracked(), Some(0));
+
+    // Moving the selection brings it back into view
+    state.select_next();
+    harness
+        .wait_for(async |harness, _| first_line(harness).await == "Item 2")
+        .await
+        .unwrap();
+
+    harness.exit().await;
+}
+
+fn blocks_down(state: VirtualListState) -> impl Render {
+    VirtualList::new()
+        .state(state)
+        .on_key_down(|mut props: KeyEventProps| {
+            if props.event.code == KeyCode::Down {
+                props.handle.stop_propagation();
+            }
+        })
+        .render(100, |i| ListItem::new(format!("Item {}", i + 1)))
+}
+
+#[rooibos::test]
+async fn test_handler_stops_built_in_keys() {
+    let mut harness = TestHarness::new(20, 10).await;
+    let state = VirtualListState::new();
+    harness.mount(state, blocks_down).await;
+
+    let item = harness.get_position_of_text("Item 3").await;
+    harness.click_pos(item);
+    harness
+        .wait_for(async |_, _| state.selected().get_untracked() == Some(2))
+        .await
+        .unwrap();
+
+    // Down is handled by the app, so only Up moves the selection
+    harness.send_key(KeyCode::Down);
+    harness.send_key(KeyCode::Up);
+    harness
+        .wait_for(async |_, _| state.selected().get_untracked() == Some(1))
+        .await
+        .unwrap();
+
+    harness.exit().await;
+}